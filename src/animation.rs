@@ -0,0 +1,86 @@
+//! Generations of the cellular automata of some days, stepped through by `aoc animate`.
+
+/// State of a simulation after a number of generations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame {
+    pub generation: usize,
+    /// Number of live cells, e.g. occupied seats or black tiles.
+    pub live: usize,
+    /// Picture of the cells, one string per line.
+    pub rows: Vec<String>,
+    /// Whether the cells are hexagonal tiles in columns shifted by half a tile, blanks
+    /// standing between the tiles of neighboring columns rather than for cells of their own.
+    pub hexagonal: bool,
+}
+
+/// Simulation advancing one generation at a time.
+pub trait Simulation {
+    fn frame(&self) -> Frame;
+
+    /// Advances to the next generation, returning false if the simulation has ended instead.
+    fn step(&mut self) -> bool;
+}
+
+/// Single picture shown like a simulation, e.g. the final state of a puzzle.
+pub struct Still(pub Frame);
+
+impl Simulation for Still {
+    fn frame(&self) -> Frame {
+        self.0.clone()
+    }
+
+    fn step(&mut self) -> bool {
+        false
+    }
+}
+
+/// Frames of a simulation generated so far, allowing to step back to earlier generations.
+pub struct Animation {
+    simulation: Box<dyn Simulation>,
+    frames: Vec<Frame>,
+    current: usize,
+    ended: bool,
+}
+
+impl Animation {
+    pub fn new(simulation: Box<dyn Simulation>) -> Self {
+        let frames = vec![simulation.frame()];
+        Self {
+            simulation,
+            frames,
+            current: 0,
+            ended: false,
+        }
+    }
+
+    pub fn frame(&self) -> &Frame {
+        &self.frames[self.current]
+    }
+
+    /// True if the current frame is the last generation of the simulation.
+    pub fn at_end(&self) -> bool {
+        self.ended && self.current + 1 == self.frames.len()
+    }
+
+    /// Moves to the next generation, returning false at the end of the simulation.
+    pub fn forward(&mut self) -> bool {
+        if self.current + 1 == self.frames.len() {
+            if self.ended || !self.simulation.step() {
+                self.ended = true;
+                return false;
+            }
+            self.frames.push(self.simulation.frame());
+        }
+        self.current += 1;
+        true
+    }
+
+    /// Moves to the previous generation, returning false at the first one.
+    pub fn back(&mut self) -> bool {
+        if self.current == 0 {
+            return false;
+        }
+        self.current -= 1;
+        true
+    }
+}
@@ -0,0 +1,104 @@
+use num_bigint::BigInt;
+use std::convert::TryFrom;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Integer(i128),
+    Text(String),
+    Empty,
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Integer(value) => write!(f, "{}", value),
+            Value::Text(value) => write!(f, "{}", value),
+            Value::Empty => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Value {
+                fn from(value: $type) -> Self {
+                    Value::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u32, u64, usize, i32, i64);
+
+/// Integers too large for the machine integers of the answers are kept as their digits.
+impl From<BigInt> for Value {
+    fn from(value: BigInt) -> Self {
+        match i128::try_from(&value) {
+            Ok(value) => Value::Integer(value),
+            Err(_) => Value::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Text(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Text(value.to_owned())
+    }
+}
+
+/// Answer to one part of a puzzle along with a human-readable description and named
+/// auxiliary values found on the way, e.g. the entries multiplied for the answer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Answer {
+    value: Value,
+    description: String,
+    details: Vec<(String, Value)>,
+}
+
+impl Answer {
+    pub fn new(value: impl Into<Value>, description: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            description: description.into(),
+            details: Vec::new(),
+        }
+    }
+
+    /// Answer without a value, e.g. if the input has no solution.
+    pub fn empty(description: impl Into<String>) -> Self {
+        Self::new(Value::Empty, description)
+    }
+
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Adds an auxiliary value to the answer.
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.details.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn details(&self) -> &[(String, Value)] {
+        &self.details
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
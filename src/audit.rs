@@ -0,0 +1,31 @@
+//! Line by line reports of the records of an input breaking the rules of a part, for the days
+//! solved by counting valid records, listed by `aoc audit`.
+
+use crate::error::ParseError;
+use std::fmt;
+
+/// Record breaking a rule, e.g. a password not matching its policy.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violation {
+    pub line: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, {:?}: {}", self.line, self.text, self.reason)
+    }
+}
+
+/// Result of checking all records of an input against the rules of a part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Audit {
+    /// Rules checked, e.g. the name of a password policy.
+    pub rules: String,
+    /// Number of records checked, not counting malformed lines.
+    pub checked: usize,
+    pub violations: Vec<Violation>,
+    /// Lines which could not be checked at all.
+    pub malformed: Vec<ParseError>,
+}
@@ -0,0 +1,170 @@
+use crate::Part;
+use serde_json::{json, Value};
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Duration rounded to the most readable unit, e.g. `1.5ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{:.0}ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.1}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.1}ms", nanos / 1e6)
+    } else {
+        format!("{:.2}s", nanos / 1e9)
+    }
+}
+
+/// Step of solving a puzzle that is timed separately.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            "part1" => Ok(Phase::Part(Part::One)),
+            "part2" => Ok(Phase::Part(Part::Two)),
+            _ => Err(format!("invalid phase '{}'", s)),
+        }
+    }
+}
+
+/// Median and spread of repeated timings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+        Self {
+            median,
+            min: samples[0],
+            max: samples[samples.len() - 1],
+        }
+    }
+
+    /// Change of the median compared to a baseline, in percent.
+    pub fn change(&self, baseline: &Stats) -> f64 {
+        100.0 * (self.median.as_secs_f64() / baseline.median.as_secs_f64().max(1e-9) - 1.0)
+    }
+
+    /// Whether the median is more than `threshold` percent slower than the one of a baseline,
+    /// a change of exactly the threshold still being accepted.
+    pub fn slower_than(&self, baseline: &Stats, threshold: f64) -> bool {
+        // compared in whole nanoseconds, which are exact unlike fractions of seconds
+        let median = |stats: &Stats| stats.median.as_nanos() as f64;
+        median(self) * 100.0 > median(baseline) * (100.0 + threshold)
+    }
+
+    fn to_json(self) -> Value {
+        json!({
+            "median_ns": self.median.as_nanos() as u64,
+            "min_ns": self.min.as_nanos() as u64,
+            "max_ns": self.max.as_nanos() as u64,
+        })
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        let nanos = |key| value.get(key)?.as_u64().map(Duration::from_nanos);
+        Some(Self {
+            median: nanos("median_ns")?,
+            min: nanos("min_ns")?,
+            max: nanos("max_ns")?,
+        })
+    }
+}
+
+/// Timings of all phases of one day's puzzle.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Benchmark {
+    pub day: u32,
+    pub input: String,
+    /// Fingerprint of the contents of the input, identifying it wherever it is stored.
+    pub fingerprint: String,
+    pub phases: Vec<(Phase, Stats)>,
+}
+
+impl Benchmark {
+    pub fn stats(&self, phase: Phase) -> Option<Stats> {
+        self.phases
+            .iter()
+            .find(|(p, _)| *p == phase)
+            .map(|&(_, stats)| stats)
+    }
+
+    fn to_json(&self) -> Value {
+        let mut value = json!({
+            "day": self.day,
+            "input": self.input,
+            "fingerprint": self.fingerprint,
+        });
+        for (phase, stats) in &self.phases {
+            value[phase.to_string()] = stats.to_json();
+        }
+        value
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        let phases = ["parse", "part1", "part2"]
+            .iter()
+            .filter_map(|&key| Some((key.parse().ok()?, Stats::from_json(value.get(key)?)?)))
+            .collect();
+        Some(Self {
+            day: value.get("day")?.as_u64()? as u32,
+            input: value.get("input")?.as_str()?.to_owned(),
+            fingerprint: value.get("fingerprint")?.as_str()?.to_owned(),
+            phases,
+        })
+    }
+}
+
+/// Serializes benchmark results, e.g. to be used as a baseline later on.
+pub fn to_json(benchmarks: &[Benchmark], repeats: usize) -> String {
+    let value = json!({
+        "repeats": repeats,
+        "benchmarks": benchmarks.iter().map(Benchmark::to_json).collect::<Vec<_>>(),
+    });
+    serde_json::to_string_pretty(&value).unwrap() + "\n"
+}
+
+pub fn from_json(json: &str) -> Result<Vec<Benchmark>, String> {
+    let value: Value = serde_json::from_str(json).map_err(|err| err.to_string())?;
+    value
+        .get("benchmarks")
+        .and_then(Value::as_array)
+        .ok_or_else(|| "missing 'benchmarks' array".to_owned())?
+        .iter()
+        .map(|benchmark| {
+            Benchmark::from_json(benchmark)
+                .ok_or_else(|| format!("invalid benchmark entry {}", benchmark))
+        })
+        .collect()
+}
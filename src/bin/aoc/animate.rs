@@ -0,0 +1,178 @@
+use crate::args::{self, Args};
+use adventofcode_2020::animation::Animation;
+use adventofcode_2020::config::Config;
+use adventofcode_2020::days;
+use adventofcode_2020::parameters::Parameters;
+use adventofcode_2020::Part;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{cursor, execute, queue};
+use std::error::Error;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+const HELP: &str = "space play/pause  n/b step  arrows scroll  +/- speed  q quit";
+
+/// Terminal in raw mode showing the alternate screen, restored when dropped.
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, cursor::Hide)?;
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Animation being shown along with the state of the controls.
+struct Viewer {
+    title: String,
+    animation: Animation,
+    playing: bool,
+    delay: Duration,
+    /// Column and row of the frame shown in the top left corner.
+    scroll: (usize, usize),
+}
+
+impl Viewer {
+    fn draw(&mut self, out: &mut impl Write) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        let (width, height) = (width as usize, height as usize);
+        let frame = self.animation.frame();
+        let state = if self.animation.at_end() {
+            "ended".to_owned()
+        } else if self.playing {
+            format!("playing every {} ms", self.delay.as_millis())
+        } else {
+            "paused".to_owned()
+        };
+        let status = format!(
+            "{} | generation {} | live {} | {}",
+            self.title, frame.generation, frame.live, state
+        );
+
+        // keep as much of the frame in view as fits
+        let view_height = height.saturating_sub(2);
+        let frame_width = frame.rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        self.scroll.0 = self.scroll.0.min(frame_width.saturating_sub(width));
+        self.scroll.1 = self
+            .scroll
+            .1
+            .min(frame.rows.len().saturating_sub(view_height));
+
+        let lines = std::iter::once(status)
+            .chain(
+                frame
+                    .rows
+                    .iter()
+                    .skip(self.scroll.1)
+                    .map(|row| row.chars().skip(self.scroll.0).collect())
+                    .chain(std::iter::repeat(String::new()))
+                    .take(view_height),
+            )
+            .chain(std::iter::once(HELP.to_owned()));
+        for (y, line) in lines.enumerate().take(height) {
+            let line: String = line.chars().take(width).collect();
+            queue!(
+                out,
+                cursor::MoveTo(0, y as u16),
+                Clear(ClearType::CurrentLine),
+                Print(line)
+            )?;
+        }
+        out.flush()
+    }
+
+    /// Reacts to a key, returning false if the animation is to be closed.
+    fn handle(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Char(' ') => self.playing = !self.playing,
+            KeyCode::Char('n') | KeyCode::Char('.') => {
+                self.playing = false;
+                self.animation.forward();
+            }
+            KeyCode::Char('b') | KeyCode::Char(',') => {
+                self.playing = false;
+                self.animation.back();
+            }
+            KeyCode::Char('+') => self.delay = (self.delay / 2).max(Duration::from_millis(10)),
+            KeyCode::Char('-') => self.delay = (self.delay * 2).min(Duration::from_secs(5)),
+            KeyCode::Left | KeyCode::Char('h') => self.scroll.0 = self.scroll.0.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('l') => self.scroll.0 += 1,
+            KeyCode::Up | KeyCode::Char('k') => self.scroll.1 = self.scroll.1.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll.1 += 1,
+            _ => {}
+        }
+        true
+    }
+
+    fn run(&mut self) -> io::Result<()> {
+        let mut out = io::stdout();
+        let mut next_step = Instant::now() + self.delay;
+        loop {
+            self.draw(&mut out)?;
+            let timeout = if self.playing {
+                next_step.saturating_duration_since(Instant::now())
+            } else {
+                Duration::from_secs(60)
+            };
+            if event::poll(timeout)? {
+                match event::read()? {
+                    Event::Key(key) if key.kind == KeyEventKind::Press => {
+                        if !self.handle(key) {
+                            return Ok(());
+                        }
+                        next_step = Instant::now() + self.delay;
+                    }
+                    _ => {}
+                }
+            } else if self.playing {
+                self.playing = self.animation.forward();
+                next_step = Instant::now() + self.delay;
+            }
+        }
+    }
+}
+
+/// Steps through the generations of a day's cellular automaton in the terminal.
+pub fn animate(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let day: Option<u32> = args.parsed_value("--day")?;
+    let part: Option<Part> = args.parsed_value("--part")?;
+    let input = args.value("--input")?;
+    let delay: u64 = args.parsed_value("--delay")?.unwrap_or(200);
+    let config = args.value("--config")?.unwrap_or_else(Config::default_path);
+    let mut assignments = Vec::new();
+    while let Some(assignment) = args.value("--param")? {
+        assignments.push(assignment);
+    }
+    args.finish()?;
+
+    let day = day.ok_or_else(|| args::error("missing option '--day'"))?;
+    let mut parameters = Parameters::from_config(&Config::load(&config)?);
+    for assignment in &assignments {
+        parameters.assign(day, assignment).map_err(args::error)?;
+    }
+    let input = input.unwrap_or_else(|| days::default_input(day));
+    let (part, simulation) = days::simulation(day, &input, part, &parameters)?;
+
+    let mut viewer = Viewer {
+        title: format!("Day {:02} part {}", day, part),
+        animation: Animation::new(simulation),
+        playing: false,
+        delay: Duration::from_millis(delay.max(1)),
+        scroll: (0, 0),
+    };
+    let _screen = Screen::enter()?;
+    viewer.run()?;
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct ArgsError(String);
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ArgsError {}
+
+/// Command line arguments, consumed option by option.
+#[derive(Debug)]
+pub struct Args {
+    args: Vec<String>,
+}
+
+impl Args {
+    pub fn from_env() -> Self {
+        Self {
+            args: std::env::args().skip(1).collect(),
+        }
+    }
+
+    pub fn subcommand(&mut self) -> Option<String> {
+        match self.args.first() {
+            Some(arg) if !arg.starts_with('-') => Some(self.args.remove(0)),
+            _ => None,
+        }
+    }
+
+    pub fn flag(&mut self, name: &str) -> bool {
+        match self.args.iter().position(|arg| arg == name) {
+            Some(index) => {
+                self.args.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Counts the verbosity options `-v` and `-vv`, which may be repeated.
+    pub fn verbosity(&mut self) -> usize {
+        let mut verbosity = 0;
+        while let Some(index) = self.args.iter().position(|arg| arg == "-v" || arg == "-vv") {
+            verbosity += self.args.remove(index).len() - 1;
+        }
+        verbosity
+    }
+
+    /// Takes the value of an option given as `--name value` or `--name=value`.
+    pub fn value(&mut self, name: &str) -> Result<Option<String>, ArgsError> {
+        let prefix = format!("{}=", name);
+        for index in 0..self.args.len() {
+            if self.args[index] == name {
+                if index + 1 == self.args.len() {
+                    return Err(ArgsError(format!("missing value for option '{}'", name)));
+                }
+                self.args.remove(index);
+                return Ok(Some(self.args.remove(index)));
+            }
+            if let Some(value) = self.args[index].strip_prefix(&prefix) {
+                let value = value.to_owned();
+                self.args.remove(index);
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+
+    pub fn parsed_value<T>(&mut self, name: &str) -> Result<Option<T>, ArgsError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        match self.value(name)? {
+            Some(value) => value.parse().map(Some).map_err(|err| {
+                ArgsError(format!(
+                    "invalid value '{}' for option '{}': {}",
+                    value, name, err
+                ))
+            }),
+            None => Ok(None),
+        }
+    }
+
+    /// Fails if any arguments were left unconsumed.
+    pub fn finish(self) -> Result<(), ArgsError> {
+        match self.args.first() {
+            Some(arg) => Err(ArgsError(format!("unexpected argument '{}'", arg))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Takes the time budget per day given in seconds with `--timeout`.
+pub fn timeout(args: &mut Args) -> Result<Option<Duration>, ArgsError> {
+    let seconds = match args.parsed_value::<f64>("--timeout")? {
+        Some(seconds) => seconds,
+        None => return Ok(None),
+    };
+    match Duration::try_from_secs_f64(seconds) {
+        Ok(budget) if seconds > 0.0 => Ok(Some(budget)),
+        Err(_) if seconds > 0.0 => Err(ArgsError(format!(
+            "invalid value '{}' for option '--timeout': expected at most {} seconds",
+            seconds,
+            Duration::MAX.as_secs()
+        ))),
+        _ => Err(ArgsError(format!(
+            "invalid value '{}' for option '--timeout': expected a positive number of seconds",
+            seconds
+        ))),
+    }
+}
+
+pub fn error(message: impl Into<String>) -> ArgsError {
+    ArgsError(message.into())
+}
@@ -0,0 +1,55 @@
+use crate::args::{self, Args};
+use adventofcode_2020::config::Config;
+use adventofcode_2020::days;
+use adventofcode_2020::parameters::Parameters;
+use adventofcode_2020::Part;
+use std::error::Error;
+
+/// Lists the records of a day's input breaking the rules of its parts, and the malformed
+/// lines which could not be checked.
+pub fn audit(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let day: Option<u32> = args.parsed_value("--day")?;
+    let part: Option<Part> = args.parsed_value("--part")?;
+    let input = args.value("--input")?;
+    let config = args.value("--config")?.unwrap_or_else(Config::default_path);
+    let mut assignments = Vec::new();
+    while let Some(assignment) = args.value("--param")? {
+        assignments.push(assignment);
+    }
+    args.finish()?;
+
+    let day = day.ok_or_else(|| args::error("missing option '--day'"))?;
+    let mut parameters = Parameters::from_config(&Config::load(&config)?);
+    for assignment in &assignments {
+        parameters.assign(day, assignment).map_err(args::error)?;
+    }
+    let input = input.unwrap_or_else(|| days::default_input(day));
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let audits = days::audit(day, &input, &parts, &parameters)?;
+    for (part, audit) in &audits {
+        println!(
+            "Part {} ({}): {} of {} record(s) invalid",
+            part,
+            audit.rules,
+            audit.violations.len(),
+            audit.checked
+        );
+        for violation in &audit.violations {
+            println!("{}", violation);
+        }
+    }
+    // all parts read the same lines
+    if let Some((_, audit)) = audits
+        .first()
+        .filter(|(_, audit)| !audit.malformed.is_empty())
+    {
+        println!("\n{} malformed line(s)", audit.malformed.len());
+        for err in &audit.malformed {
+            println!("{}", err);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use crate::args::{self, Args};
+use adventofcode_2020::bench::{self, format_duration, Benchmark};
+use adventofcode_2020::days::{self, NUMBER_OF_DAYS};
+use adventofcode_2020::Part;
+use std::error::Error;
+use std::fs;
+
+const DEFAULT_REPEATS: usize = 5;
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Times parsing and both parts of the selected days, optionally comparing with a baseline.
+pub fn bench(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let day: Option<u32> = args.parsed_value("--day")?;
+    let part: Option<Part> = args.parsed_value("--part")?;
+    let input = args.value("--input")?;
+    let repeats = args.parsed_value("--repeat")?.unwrap_or(DEFAULT_REPEATS);
+    let output = args.value("--output")?;
+    let baseline = args.value("--baseline")?;
+    let threshold = args
+        .parsed_value("--threshold")?
+        .unwrap_or(DEFAULT_THRESHOLD);
+    args.finish()?;
+
+    if repeats == 0 {
+        return Err(args::error("option '--repeat' must be at least 1").into());
+    }
+    let days = match day {
+        Some(day) => vec![day],
+        None if input.is_some() => {
+            return Err(args::error("option '--input' requires '--day'").into())
+        }
+        None => (1..=NUMBER_OF_DAYS).collect(),
+    };
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let baseline = match baseline {
+        Some(path) => {
+            let json = fs::read_to_string(&path).map_err(|err| format!("{}: {}", path, err))?;
+            bench::from_json(&json).map_err(|err| format!("{}: {}", path, err))?
+        }
+        None => Vec::new(),
+    };
+
+    let mut benchmarks = Vec::new();
+    let (mut failed_days, mut slowdowns) = (0, 0);
+    let mut header = "Day  Phase   Median      Min         Max".to_owned();
+    if !baseline.is_empty() {
+        header.push_str("         Baseline    Change");
+    }
+    println!("{}", header);
+    for day in days {
+        let input = input.clone().unwrap_or_else(|| days::default_input(day));
+        let benchmark = match days::benchmark(day, &input, &parts, repeats) {
+            Ok(benchmark) => benchmark,
+            Err(err) => {
+                eprintln!("Day {:02} failed: {}", day, err);
+                failed_days += 1;
+                continue;
+            }
+        };
+        // the same input may be stored elsewhere when the baseline was recorded
+        let previous = baseline
+            .iter()
+            .find(|b| b.day == benchmark.day && b.fingerprint == benchmark.fingerprint);
+        for &(phase, stats) in &benchmark.phases {
+            let mut line = format!(
+                "{:02}   {:<6}  {:<10}  {:<10}  {:<10}",
+                day,
+                phase.to_string(),
+                format_duration(stats.median),
+                format_duration(stats.min),
+                format_duration(stats.max)
+            );
+            if let Some(previous) = previous.and_then(|b: &Benchmark| b.stats(phase)) {
+                line.push_str(&format!(
+                    "  {:<10}  {:+.1}%",
+                    format_duration(previous.median),
+                    stats.change(&previous)
+                ));
+                if stats.slower_than(&previous, threshold) {
+                    line.push_str("  SLOWER");
+                    slowdowns += 1;
+                }
+            }
+            println!("{}", line.trim_end());
+        }
+        benchmarks.push(benchmark);
+    }
+
+    if let Some(path) = output {
+        fs::write(&path, bench::to_json(&benchmarks, repeats))
+            .map_err(|err| format!("{}: {}", path, err))?;
+    }
+    if failed_days > 0 {
+        return Err(format!("{} day(s) failed", failed_days).into());
+    }
+    match slowdowns {
+        0 => Ok(()),
+        _ => Err(format!(
+            "{} phase(s) slowed down by more than {}%",
+            slowdowns, threshold
+        )
+        .into()),
+    }
+}
@@ -0,0 +1,48 @@
+use crate::args::{self, Args};
+use adventofcode_2020::days::{self, NUMBER_OF_DAYS};
+use std::error::Error;
+
+/// Runs the parsers and structural checks of the selected days, reporting every problem.
+pub fn check_input(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let day: Option<u32> = args.parsed_value("--day")?;
+    let input = args.value("--input")?;
+    args.finish()?;
+
+    let days = match day {
+        Some(day) => vec![day],
+        None if input.is_some() => {
+            return Err(args::error("option '--input' requires '--day'").into())
+        }
+        None => (1..=NUMBER_OF_DAYS).collect(),
+    };
+    let (mut problems, mut failed_inputs) = (0, 0);
+    for &day in &days {
+        let input = input.clone().unwrap_or_else(|| days::default_input(day));
+        match days::check_input(day, &input) {
+            Ok(errors) if errors.is_empty() => println!("Day {:02}: ok", day),
+            Ok(errors) => {
+                println!("Day {:02}: {} problem(s)", day, errors.len());
+                for err in &errors {
+                    println!("{}", err);
+                }
+                problems += errors.len();
+                failed_inputs += 1;
+            }
+            Err(err) => {
+                println!("Day {:02}: {}", day, err);
+                problems += 1;
+                failed_inputs += 1;
+            }
+        }
+    }
+    println!(
+        "\n{} problem(s) in {} of {} input(s)",
+        problems,
+        failed_inputs,
+        days.len()
+    );
+    match failed_inputs {
+        0 => Ok(()),
+        _ => Err(format!("{} input(s) with problems", failed_inputs).into()),
+    }
+}
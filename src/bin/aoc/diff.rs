@@ -0,0 +1,87 @@
+use crate::args::{self, Args};
+use adventofcode_2020::config::Config;
+use adventofcode_2020::days::{self, NUMBER_OF_DAYS};
+use adventofcode_2020::differential::{self, Divergence};
+use adventofcode_2020::generate;
+use adventofcode_2020::parameters::Parameters;
+use std::error::Error;
+use std::path::Path;
+
+/// Compares the solutions of the selected days with their variants on the real input and on
+/// generated inputs, printing the smallest input found for every disagreement.
+pub fn diff(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let day: Option<u32> = args.parsed_value("--day")?;
+    let seeds: u64 = args.parsed_value("--seeds")?.unwrap_or(10);
+    let config = args.value("--config")?.unwrap_or_else(Config::default_path);
+    let mut assignments = Vec::new();
+    while let Some(assignment) = args.value("--param")? {
+        assignments.push(assignment);
+    }
+    args.finish()?;
+
+    let mut parameters = Parameters::from_config(&Config::load(&config)?);
+    let selected = match day {
+        Some(day) => {
+            for assignment in &assignments {
+                parameters.assign(day, assignment).map_err(args::error)?;
+            }
+            vec![day]
+        }
+        None if !assignments.is_empty() => {
+            return Err(args::error("option '--param' requires '--day'").into())
+        }
+        None => (1..=NUMBER_OF_DAYS).collect(),
+    };
+    let day_given = day.is_some();
+    let mut disagreements = 0;
+    for day in selected {
+        let solution = days::lookup(day, &parameters)?;
+        if differential::parts(solution.as_ref()).is_empty() {
+            // only worth mentioning for a day asked for explicitly
+            if day_given {
+                println!("Day {:02}: no variants", day);
+            }
+            continue;
+        }
+        let mut inputs = Vec::new();
+        let filename = days::default_input(day);
+        if Path::new(&filename).exists() {
+            inputs.push((filename.clone(), days::read_input(&filename)?));
+        }
+        for seed in 0..seeds {
+            let generated = generate::generate(day, seed, None)?;
+            inputs.push((format!("seed {}", seed), generated.input));
+        }
+        let mut diverging = 0;
+        for (name, input) in &inputs {
+            let divergences = differential::compare(solution.as_ref(), input)
+                .map_err(|err| format!("{}: {}", name, err))?;
+            for divergence in &divergences {
+                print_divergence(day, name, divergence);
+            }
+            diverging += divergences.len();
+        }
+        if diverging == 0 {
+            println!("Day {:02}: {} input(s) agree", day, inputs.len());
+        }
+        disagreements += diverging;
+    }
+    match disagreements {
+        0 => Ok(()),
+        _ => Err(format!("{} disagreement(s) found", disagreements).into()),
+    }
+}
+
+fn print_divergence(day: u32, name: &str, divergence: &Divergence) {
+    println!(
+        "Day {:02}: part {} disagrees on {}, smallest input found:",
+        day, divergence.part, name
+    );
+    print!("{}", divergence.input);
+    for (implementation, outcome) in &divergence.outcomes {
+        match outcome {
+            Some(value) => println!("    {}: {}", implementation, value),
+            None => println!("    {}: panicked", implementation),
+        }
+    }
+}
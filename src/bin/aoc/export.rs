@@ -0,0 +1,72 @@
+use crate::args::{self, Args};
+use adventofcode_2020::animation::Animation;
+use adventofcode_2020::config::Config;
+use adventofcode_2020::days;
+use adventofcode_2020::parameters::Parameters;
+use adventofcode_2020::render::{self, Palette, Picture};
+use adventofcode_2020::Part;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Writes every generation of a day's simulation as PNG image and all of them as animated GIF.
+pub fn export(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let day: Option<u32> = args.parsed_value("--day")?;
+    let part: Option<Part> = args.parsed_value("--part")?;
+    let input = args.value("--input")?;
+    let output = args
+        .value("--output")?
+        .unwrap_or_else(|| "frames".to_owned());
+    let scale: usize = args.parsed_value("--scale")?.unwrap_or(4);
+    let palette: Palette = args.parsed_value("--palette")?.unwrap_or_default();
+    let delay: u64 = args.parsed_value("--delay")?.unwrap_or(200);
+    let config = args.value("--config")?.unwrap_or_else(Config::default_path);
+    let mut assignments = Vec::new();
+    while let Some(assignment) = args.value("--param")? {
+        assignments.push(assignment);
+    }
+    args.finish()?;
+
+    let day = day.ok_or_else(|| args::error("missing option '--day'"))?;
+    if scale == 0 {
+        return Err(args::error("option '--scale' must be at least 1").into());
+    }
+    let mut parameters = Parameters::from_config(&Config::load(&config)?);
+    for assignment in &assignments {
+        parameters.assign(day, assignment).map_err(args::error)?;
+    }
+    let input = input.unwrap_or_else(|| days::default_input(day));
+    let (part, simulation) = days::simulation(day, &input, part, &parameters)?;
+
+    let mut animation = Animation::new(simulation);
+    let mut frames = vec![animation.frame().clone()];
+    while animation.forward() {
+        frames.push(animation.frame().clone());
+    }
+    // all pictures get the size of the largest frame, as boards may grow
+    let size = render::size(&frames);
+    let pictures: Vec<_> = frames
+        .iter()
+        .map(|frame| Picture::draw(frame, &palette, scale, size))
+        .collect();
+
+    let directory = Path::new(&output);
+    fs::create_dir_all(directory).map_err(|err| format!("{}: {}", output, err))?;
+    let name = format!("day-{:02}-part-{}", day, part);
+    for (frame, picture) in frames.iter().zip(&pictures) {
+        let path = directory.join(format!("{}-{:04}.png", name, frame.generation));
+        picture.write_png(&palette, &path)?;
+    }
+    println!(
+        "Wrote {} image(s) to {}",
+        pictures.len(),
+        directory.display()
+    );
+    if pictures.len() > 1 {
+        let path = directory.join(format!("{}.gif", name));
+        render::write_gif(&pictures, &palette, Duration::from_millis(delay), &path)?;
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use crate::args::{self, Args};
+use adventofcode_2020::cache::InputCache;
+use adventofcode_2020::client::Client;
+use adventofcode_2020::config::Config;
+use adventofcode_2020::days::NUMBER_OF_DAYS;
+use std::error::Error;
+
+/// Downloads the input of a day into the cache of the configured account, unless already cached.
+pub fn fetch(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let day: Option<u32> = args.parsed_value("--day")?;
+    let path = args.value("--config")?.unwrap_or_else(Config::default_path);
+    args.finish()?;
+
+    let day = match day {
+        Some(day) if (1..=NUMBER_OF_DAYS).contains(&day) => day,
+        Some(day) => {
+            return Err(format!("invalid day {}, expected 1 to {}", day, NUMBER_OF_DAYS).into())
+        }
+        None => return Err(args::error("missing option '--day'").into()),
+    };
+    let config = Config::load(&path)?;
+    let client = Client::from_config(&config).map_err(|err| format!("{}: {}", path, err))?;
+    let cache = InputCache::for_account(&config, client.session());
+    let (input, fetched) = cache.fetch(day, &client)?;
+    match fetched {
+        true => println!("Day {:02}: fetched {}", day, input.display()),
+        false => println!("Day {:02}: cached {}", day, input.display()),
+    }
+    Ok(())
+}
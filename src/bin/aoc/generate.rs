@@ -0,0 +1,35 @@
+use crate::args::{self, Args};
+use adventofcode_2020::generate;
+use adventofcode_2020::Part;
+use std::error::Error;
+use std::fs;
+
+/// Writes a random input of a day generated from the seed, optionally reporting its known
+/// answers on stderr.
+pub fn generate(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let day: Option<u32> = args.parsed_value("--day")?;
+    let seed: Option<u64> = args.parsed_value("--seed")?;
+    let size: Option<usize> = args.parsed_value("--size")?;
+    let output = args.value("--output")?;
+    let answers = args.flag("--show-answers");
+    args.finish()?;
+
+    let day = day.ok_or_else(|| args::error("missing option '--day'"))?;
+    let seed = seed.ok_or_else(|| args::error("missing option '--seed'"))?;
+    let generated = generate::generate(day, seed, size)?;
+    match output {
+        Some(path) => {
+            fs::write(&path, &generated.input).map_err(|err| format!("{}: {}", path, err))?
+        }
+        None => print!("{}", generated.input),
+    }
+    if answers {
+        for part in Part::ALL.iter() {
+            match generated.answer(*part) {
+                Some(answer) => eprintln!("Part {}: {}", part, answer),
+                None => eprintln!("Part {}: unknown", part),
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,131 @@
+mod animate;
+mod args;
+mod audit;
+mod bench;
+mod check;
+mod diff;
+mod export;
+mod fetch;
+mod generate;
+mod run;
+mod submit;
+mod verify;
+
+use adventofcode_2020::trace::{self, Level};
+use args::{Args, ArgsError};
+use std::error::Error;
+use std::fs::File;
+
+const USAGE: &str = "\
+Usage:
+    aoc [-v | -vv] [--log <PATH>] <COMMAND> [<OPTIONS>]
+    aoc run --day <DAY> [--part <PART>] [--input <PATH>] [--format <FORMAT>]
+            [--param <NAME>=<VALUE>]... [--config <PATH>] [--timeout <SECONDS>]
+    aoc run --all [--part <PART>] [--jobs <N>] [--output <PATH>] [--format <FORMAT>]
+            [--config <PATH>] [--timeout <SECONDS>]
+    aoc verify [--day <DAY> [--input <PATH>]] [--answers <PATH>] [--record]
+               [--timeout <SECONDS>]
+    aoc check-input [--day <DAY> [--input <PATH>]]
+    aoc audit --day <DAY> [--part <PART>] [--input <PATH>]
+              [--param <NAME>=<VALUE>]... [--config <PATH>]
+    aoc fetch --day <DAY> [--config <PATH>]
+    aoc submit --day <DAY> --part <PART> [--input <PATH>] [--config <PATH>]
+    aoc bench [--day <DAY> [--input <PATH>]] [--part <PART>] [--repeat <N>]
+              [--output <PATH>] [--baseline <PATH> [--threshold <PERCENT>]]
+    aoc generate --day <DAY> --seed <SEED> [--size <N>] [--output <PATH>]
+                 [--show-answers]
+    aoc animate --day <DAY> [--part <PART>] [--input <PATH>] [--delay <MS>]
+                [--param <NAME>=<VALUE>]... [--config <PATH>]
+    aoc export --day <DAY> [--part <PART>] [--input <PATH>] [--output <PATH>]
+               [--scale <N>] [--palette <COLORS>] [--delay <MS>]
+               [--param <NAME>=<VALUE>]... [--config <PATH>]
+    aoc diff [--day <DAY> [--param <NAME>=<VALUE>]...] [--seeds <N>]
+             [--config <PATH>]
+
+Options:
+    -v, -vv           Trace the steps of the solvers on stderr, -v as one event
+                      per line, -vv like the walkthroughs in the puzzle texts
+    --log <PATH>      Write the trace to a file instead of stderr
+    --day <DAY>       Day of the puzzle to run (1-25)
+    --part <PART>     Run only the given part (1 or 2)
+    --input <PATH>    Puzzle input file, '-' reads from stdin
+                      [default: $AOC_INPUT_DIR/day-<DAY>.txt, falling back
+                      to inputs/day-<DAY>.txt]
+    --format <FORMAT> Print answers as 'text' or as 'json', one object per line
+                      and part [default: text]
+    --all             Run all days concurrently on their default inputs
+    --jobs <N>        Number of days run at the same time
+                      [default: number of available CPUs]
+    --timeout <SECONDS>
+                      Time budget per day, after which its unsolved parts are
+                      cancelled and reported as timed out
+    --answers <PATH>  File of confirmed answers [default: answers.txt]
+    --record          Ask to record missing or changed answers
+    --repeat <N>      Number of timed runs per day [default: 5]
+    --output <PATH>   Write the results of all days as JSON or CSV, depending
+                      on the extension, benchmark results as JSON or the
+                      generated input instead of printing it, or the exported
+                      images into the directory [default: frames]
+    --baseline <PATH> Compare with benchmark results written earlier
+    --threshold <PERCENT>
+                      Slowdown of the median flagged as regression [default: 10]
+    --config <PATH>   Configuration file holding the session token, the server
+                      and puzzle parameters in [day<DAY>] sections
+                      [default: $AOC_CONFIG, falling back to aoc.conf]
+    --param <NAME>=<VALUE>
+                      Change a puzzle parameter of the day, e.g. preamble=5 on
+                      day 9, overriding the configuration file
+    --seed <SEED>     Seed of the generated input, the same seed giving the
+                      same input
+    --size <N>        Size of the generated input, e.g. the number of passports
+                      on day 4 [default: depends on the day]
+    --show-answers    Print the answers known for the generated input on stderr
+    --delay <MS>      Time between two generations of the animation of days 11,
+                      17 and 24 while playing or in the GIF [default: 200]
+    --scale <N>       Pixels per cell of the exported images [default: 4]
+    --palette <COLORS>
+                      Colors of the cells of the exported images, e.g.
+                      '#=ffffff,.=000000,background=202020'
+    --seeds <N>       Number of generated inputs, besides the real one, on which
+                      the variants of a solution are compared [default: 10]
+";
+
+fn main() {
+    let mut args = Args::from_env();
+    if args.flag("--help") || args.flag("-h") {
+        print!("{}", USAGE);
+        return;
+    }
+    let result = set_up_trace(&mut args).and_then(|()| match args.subcommand().as_deref() {
+        Some("run") => run::run(args),
+        Some("verify") => verify::verify(args),
+        Some("check-input") => check::check_input(args),
+        Some("audit") => audit::audit(args),
+        Some("fetch") => fetch::fetch(args),
+        Some("submit") => submit::submit(args),
+        Some("bench") => bench::bench(args),
+        Some("generate") => generate::generate(args),
+        Some("diff") => diff::diff(args),
+        Some("animate") => animate::animate(args),
+        Some("export") => export::export(args),
+        Some(command) => Err(args::error(format!("unknown command '{}'", command)).into()),
+        None => Err(args::error("missing command").into()),
+    });
+    if let Err(err) = result {
+        eprintln!("Error: {}", err);
+        if err.is::<ArgsError>() {
+            eprint!("\n{}", USAGE);
+        }
+        std::process::exit(1);
+    }
+}
+
+/// Sets the level of the trace and where it goes from the options given.
+fn set_up_trace(args: &mut Args) -> Result<(), Box<dyn Error>> {
+    trace::set_level(Level::from_verbosity(args.verbosity()));
+    if let Some(path) = args.value("--log")? {
+        let file = File::create(&path).map_err(|err| format!("{}: {}", path, err))?;
+        trace::set_output(Box::new(file));
+    }
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use crate::args::{self, Args, ArgsError};
+use adventofcode_2020::answer::Answer;
+use adventofcode_2020::bench::format_duration;
+use adventofcode_2020::config::Config;
+use adventofcode_2020::days::{self, NUMBER_OF_DAYS};
+use adventofcode_2020::parameters::Parameters;
+use adventofcode_2020::summary::{self, Outcome};
+use adventofcode_2020::Part;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Output format of the answers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err("expected 'text' or 'json'".to_owned()),
+        }
+    }
+}
+
+fn failures(outcomes: &[Outcome]) -> usize {
+    outcomes
+        .iter()
+        .filter(|outcome| !outcome.status.is_solved())
+        .count()
+}
+
+fn print_answers(answers: &[(Part, Answer)]) {
+    for (part, answer) in answers {
+        println!("Part {}: {}", part, answer.description());
+    }
+}
+
+fn print_summary(outcomes: &[Outcome]) {
+    println!("Day  Part  Answer                          Time        Status");
+    for outcome in outcomes {
+        println!(
+            "{:>3}  {:>4}  {:<30}  {:<10}  {}",
+            outcome.day,
+            outcome.part.number(),
+            outcome.value().to_string(),
+            format_duration(outcome.time),
+            outcome.status
+        );
+    }
+    for outcome in outcomes {
+        if let Some(message) = outcome.status.message() {
+            eprintln!("Day {:02} part {}: {}", outcome.day, outcome.part, message);
+        }
+    }
+}
+
+/// Serialization of the summary table, chosen by the extension of the output file.
+fn summary_format(path: &str) -> Result<fn(&[Outcome]) -> String, ArgsError> {
+    match Path::new(path).extension().and_then(|e| e.to_str()) {
+        Some("json") => Ok(summary::to_json),
+        Some("csv") => Ok(summary::to_csv),
+        _ => Err(args::error(format!(
+            "cannot tell format of '{}', expected .json or .csv",
+            path
+        ))),
+    }
+}
+
+/// Solves all days concurrently, printing a summary table once all of them are done.
+fn run_all(
+    parts: &[Part],
+    jobs: usize,
+    output: Option<String>,
+    format: Format,
+    parameters: &Parameters,
+    budget: Option<Duration>,
+) -> Result<(), Box<dyn Error>> {
+    let output = match output {
+        Some(path) => Some((summary_format(&path)?, path)),
+        None => None,
+    };
+    let days: Vec<_> = (1..=NUMBER_OF_DAYS).collect();
+    let start = Instant::now();
+    let outcomes = days::solve_all(&days, parts, jobs, parameters, budget);
+    let elapsed = start.elapsed();
+    let failures = failures(&outcomes);
+    match format {
+        Format::Text => {
+            print_summary(&outcomes);
+            println!(
+                "\n{} part(s) solved, {} failed, total wall time {}",
+                outcomes.len() - failures,
+                failures,
+                format_duration(elapsed)
+            );
+        }
+        Format::Json => print!("{}", summary::to_json_lines(&outcomes)),
+    }
+    if let Some((format, path)) = output {
+        fs::write(&path, format(&outcomes)).map_err(|err| format!("{}: {}", path, err))?;
+    }
+    match failures {
+        0 => Ok(()),
+        _ => Err(format!("{} part(s) failed", failures).into()),
+    }
+}
+
+pub fn run(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let all = args.flag("--all");
+    let day: Option<u32> = args.parsed_value("--day")?;
+    let part: Option<Part> = args.parsed_value("--part")?;
+    let input = args.value("--input")?;
+    let jobs: Option<usize> = args.parsed_value("--jobs")?;
+    let output = args.value("--output")?;
+    let format = args.parsed_value("--format")?.unwrap_or(Format::Text);
+    let budget = args::timeout(&mut args)?;
+    let config = args.value("--config")?.unwrap_or_else(Config::default_path);
+    let mut assignments = Vec::new();
+    while let Some(assignment) = args.value("--param")? {
+        assignments.push(assignment);
+    }
+    args.finish()?;
+
+    // parameters from the configuration, overridden by those given for the day
+    let mut parameters = Parameters::from_config(&Config::load(&config)?);
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    match (all, day) {
+        (true, None) => {
+            if input.is_some() {
+                return Err(args::error("option '--input' cannot be combined with '--all'").into());
+            }
+            let jobs = match jobs {
+                Some(0) => return Err(args::error("option '--jobs' must be at least 1").into()),
+                Some(jobs) => jobs,
+                None => thread::available_parallelism().map_or(1, |n| n.get()),
+            };
+            if !assignments.is_empty() {
+                return Err(args::error("option '--param' requires '--day'").into());
+            }
+            run_all(&parts, jobs, output, format, &parameters, budget)
+        }
+        (false, Some(day)) => {
+            if jobs.is_some() || output.is_some() {
+                return Err(args::error("options '--jobs' and '--output' require '--all'").into());
+            }
+            for assignment in &assignments {
+                parameters.assign(day, assignment).map_err(args::error)?;
+            }
+            let input = input.unwrap_or_else(|| days::default_input(day));
+            match (format, budget) {
+                (Format::Text, None) => {
+                    print_answers(&days::solve(day, &input, &parts, &parameters)?)
+                }
+                (Format::Text, Some(_)) => {
+                    let outcomes = days::solve_isolated(day, &input, &parts, &parameters, budget);
+                    for outcome in &outcomes {
+                        match (&outcome.answer, outcome.status.message()) {
+                            (Some(answer), None) => {
+                                println!("Part {}: {}", outcome.part, answer.description())
+                            }
+                            (_, message) => {
+                                return Err(message.unwrap_or("no answer").into());
+                            }
+                        }
+                    }
+                }
+                (Format::Json, _) => {
+                    let outcomes = days::solve_isolated(day, &input, &parts, &parameters, budget);
+                    print!("{}", summary::to_json_lines(&outcomes));
+                    if let Some(message) = outcomes.iter().find_map(|o| o.status.message()) {
+                        return Err(message.into());
+                    }
+                }
+            }
+            Ok(())
+        }
+        _ => Err(args::error("expected either '--day <DAY>' or '--all'").into()),
+    }
+}
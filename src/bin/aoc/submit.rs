@@ -0,0 +1,86 @@
+use crate::args::{self, Args};
+use adventofcode_2020::answer::Value;
+use adventofcode_2020::cache::InputCache;
+use adventofcode_2020::client::Client;
+use adventofcode_2020::config::Config;
+use adventofcode_2020::days;
+use adventofcode_2020::parameters::Parameters;
+use adventofcode_2020::submission::{self, Submission, SubmissionLog, Verdict};
+use adventofcode_2020::Part;
+use std::error::Error;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Solves a part and submits its answer, unless the submission log tells it is pointless.
+pub fn submit(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let day: Option<u32> = args.parsed_value("--day")?;
+    let part: Option<Part> = args.parsed_value("--part")?;
+    let input = args.value("--input")?;
+    let path = args.value("--config")?.unwrap_or_else(Config::default_path);
+    args.finish()?;
+
+    let (day, part) = match (day, part) {
+        (Some(day), Some(part)) => (day, part),
+        _ => return Err(args::error("expected '--day <DAY>' and '--part <PART>'").into()),
+    };
+    let config = Config::load(&path)?;
+    let client = Client::from_config(&config).map_err(|err| format!("{}: {}", path, err))?;
+    let cache = InputCache::for_account(&config, client.session());
+    // the answer has to be computed from the input of the account it is submitted for
+    let input = match input {
+        Some(input) => input,
+        None if cache.path(day).is_file() => cache.path(day).to_string_lossy().into_owned(),
+        None => {
+            return Err(format!(
+                "{}: no cached input of day {} for this account, run 'aoc fetch --day {}' \
+                 or give '--input'",
+                cache.path(day).display(),
+                day,
+                day
+            )
+            .into())
+        }
+    };
+    let answer = match days::solve(day, &input, &[part], &Parameters::new())?
+        .remove(0)
+        .1
+        .value()
+    {
+        Value::Empty => {
+            return Err(format!("Day {:02} part {}: no answer to submit", day, part).into())
+        }
+        value => value.to_string(),
+    };
+
+    let log_path = cache.directory().join(submission::LOG_FILE);
+    let log_path = log_path.to_string_lossy();
+    let mut log = SubmissionLog::load(&log_path)?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    log.check(day, part, &answer, now)
+        .map_err(|reason| format!("not submitting {}: {}", answer, reason))?;
+
+    let response = client.submit(day, part, &answer)?;
+    let (verdict, wait) =
+        submission::parse_response(&response).ok_or("unrecognized response of the server")?;
+    log.record(Submission {
+        time: now,
+        day,
+        part,
+        verdict,
+        wait,
+        answer: answer.clone(),
+    });
+    fs::create_dir_all(cache.directory())
+        .map_err(|err| format!("{}: {}", cache.directory().display(), err))?;
+    log.save(&log_path)?;
+
+    print!("Day {:02} part {}: {} is {}", day, part, answer, verdict);
+    match wait {
+        Some(wait) => println!(", {}s to wait before the next submission", wait),
+        None => println!(),
+    }
+    match verdict {
+        Verdict::Correct => Ok(()),
+        verdict => Err(format!("answer {} is {}", answer, verdict).into()),
+    }
+}
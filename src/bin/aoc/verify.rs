@@ -0,0 +1,157 @@
+use crate::args::{self, Args};
+use adventofcode_2020::answer::{Answer, Value};
+use adventofcode_2020::days::{self, NUMBER_OF_DAYS};
+use adventofcode_2020::input;
+use adventofcode_2020::parameters::Parameters;
+use adventofcode_2020::registry::{self, Key, Registry, Status};
+use adventofcode_2020::summary;
+use adventofcode_2020::Part;
+use std::error::Error;
+use std::io::{self, BufRead, Write};
+use std::time::Duration;
+
+type Answers = Vec<(Part, Answer)>;
+
+fn confirm(question: &str) -> Result<bool, io::Error> {
+    print!("{} [y/N] ", question);
+    io::stdout().flush()?;
+    let mut reply = String::new();
+    io::stdin().lock().read_line(&mut reply)?;
+    Ok(matches!(reply.trim(), "y" | "Y" | "yes"))
+}
+
+/// Solves both parts of a day within the time budget, failing if either is not solved.
+fn solve_within(
+    day: u32,
+    input: &str,
+    budget: Option<Duration>,
+) -> Result<Answers, Box<dyn Error>> {
+    let outcomes = days::solve_isolated(day, input, &Part::ALL, &Parameters::new(), budget);
+    outcomes
+        .into_iter()
+        .map(|outcome| match (outcome.answer, outcome.status) {
+            (Some(answer), summary::Status::Solved) => Ok((outcome.part, answer)),
+            (_, status) => Err(format!(
+                "part {} {}: {}",
+                outcome.part,
+                status,
+                status.message().unwrap_or_default()
+            )
+            .into()),
+        })
+        .collect()
+}
+
+/// Reads an input and solves both parts of a day, within the time budget if one is given.
+fn solve_input(
+    day: u32,
+    input: &str,
+    budget: Option<Duration>,
+) -> Result<(String, Answers), Box<dyn Error>> {
+    let content = days::read_input(input)?;
+    let answers = match budget {
+        None => days::solve(day, input, &Part::ALL, &Parameters::new())?,
+        Some(_) => solve_within(day, input, budget)?,
+    };
+    Ok((content, answers))
+}
+
+/// Compares the answers of all solvers with the registry, optionally recording new ones.
+pub fn verify(mut args: Args) -> Result<(), Box<dyn Error>> {
+    let day: Option<u32> = args.parsed_value("--day")?;
+    let input = args.value("--input")?;
+    let path = args
+        .value("--answers")?
+        .unwrap_or_else(|| registry::DEFAULT_PATH.to_owned());
+    let record = args.flag("--record");
+    let budget = args::timeout(&mut args)?;
+    args.finish()?;
+
+    let days = match day {
+        Some(day) if (1..=NUMBER_OF_DAYS).contains(&day) => vec![day],
+        Some(day) => {
+            return Err(args::error(format!(
+                "invalid day {}, expected 1 to {}",
+                day, NUMBER_OF_DAYS
+            ))
+            .into())
+        }
+        None if input.is_some() => {
+            return Err(args::error("option '--input' requires '--day'").into())
+        }
+        None => (1..=NUMBER_OF_DAYS).collect(),
+    };
+    if input.as_deref() == Some(input::STDIN) {
+        return Err(args::error("answers can only be verified for input files").into());
+    }
+
+    let mut registry = Registry::load(&path)?;
+    let (mut matches, mut regressions, mut missing) = (0, 0, 0);
+    let mut recorded = 0;
+    println!("Day  Part  Status    Answer");
+    for day in days {
+        let input = input.clone().unwrap_or_else(|| days::default_input(day));
+        let (content, answers) = match solve_input(day, &input, budget) {
+            Ok(answers) => answers,
+            Err(err) => {
+                println!("{:02}      -  FAILED    {}", day, err);
+                regressions += 1;
+                continue;
+            }
+        };
+        for (part, answer) in answers {
+            let key = Key::new(day, part, &content);
+            let value = answer.value().to_string();
+            let status = registry.check(&key, &value);
+            let question = match &status {
+                Status::Match => {
+                    println!("{:02}   {:>4}  match     {}", day, part.number(), value);
+                    matches += 1;
+                    continue;
+                }
+                Status::Mismatch(expected) => {
+                    println!(
+                        "{:02}   {:>4}  MISMATCH  {} (expected {})",
+                        day,
+                        part.number(),
+                        value,
+                        expected
+                    );
+                    format!("Replace answer {} with {}?", expected, value)
+                }
+                Status::Missing if *answer.value() == Value::Empty => {
+                    println!("{:02}   {:>4}  none      {}", day, part.number(), value);
+                    continue;
+                }
+                Status::Missing => {
+                    println!("{:02}   {:>4}  missing   {}", day, part.number(), value);
+                    format!("Record answer {}?", value)
+                }
+            };
+            if record && confirm(&question)? {
+                registry.record(key, &value);
+                recorded += 1;
+            } else if let Status::Mismatch(_) = status {
+                regressions += 1;
+            } else {
+                missing += 1;
+            }
+        }
+    }
+    if recorded > 0 {
+        registry.save(&path)?;
+    }
+
+    println!();
+    println!(
+        "{} matching, {} regression(s), {} missing, {} recorded",
+        matches, regressions, missing, recorded
+    );
+    if missing > 0 && !record {
+        println!("Run with '--record' to confirm and record missing answers.");
+    }
+    match regressions {
+        0 => Ok(()),
+        _ => Err(format!("{} regression(s) found", regressions).into()),
+    }
+}
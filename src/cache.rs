@@ -0,0 +1,63 @@
+use crate::client::Client;
+use crate::config::Config;
+use crate::input;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory the inputs are cached in unless `cache_dir` is configured.
+pub const DEFAULT_DIRECTORY: &str = "cache";
+
+/// Downloaded puzzle inputs of one account, each fetched at most once.
+#[derive(Debug)]
+pub struct InputCache {
+    directory: PathBuf,
+}
+
+/// Stable name of an account's cache directory, without revealing the session token.
+fn account_id(session: &str) -> String {
+    input::fingerprint(session)
+}
+
+impl InputCache {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    /// Cache of the account the session token belongs to, named by `account` if configured.
+    pub fn for_account(config: &Config, session: &str) -> Self {
+        let root = config.get("cache_dir").unwrap_or(DEFAULT_DIRECTORY);
+        let account = match config.get("account") {
+            Some(account) => account.to_owned(),
+            None => account_id(session),
+        };
+        Self::new(Path::new(root).join(account))
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    pub fn path(&self, day: u32) -> PathBuf {
+        self.directory.join(format!("day-{:02}.txt", day))
+    }
+
+    /// Returns the cached input of a day, downloading it only if not cached yet.
+    /// The flag tells whether the input was downloaded.
+    pub fn fetch(&self, day: u32, client: &Client) -> Result<(PathBuf, bool), Box<dyn Error>> {
+        let path = self.path(day);
+        if path.is_file() {
+            return Ok((path, false));
+        }
+        let input = client.input(day)?;
+        let io_error = |err| format!("{}: {}", path.display(), err);
+        fs::create_dir_all(&self.directory).map_err(io_error)?;
+        // write to a temporary file first so that an interrupted download is never cached
+        let partial = path.with_extension("part");
+        fs::write(&partial, input).map_err(io_error)?;
+        fs::rename(&partial, &path).map_err(io_error)?;
+        Ok((path, true))
+    }
+}
@@ -0,0 +1,55 @@
+//! Cooperative cancellation of solvers running over their time budget. Solvers whose main
+//! loops may run long on unexpected inputs check `cancelled` and give up early.
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Flag shared between a runner and the thread of the solver it may cancel.
+#[derive(Clone, Debug, Default)]
+pub struct Token {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Token {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// Runs a function with the token checked by `cancelled` on the current thread.
+pub fn with_token<T>(token: Token, f: impl FnOnce() -> T) -> T {
+    let previous = CURRENT.with(|current| current.replace(Some(token)));
+    let result = f();
+    CURRENT.with(|current| *current.borrow_mut() = previous);
+    result
+}
+
+/// True if the solver running on the current thread is to stop, never without a token.
+pub fn cancelled() -> bool {
+    CURRENT.with(|current| current.borrow().as_ref().is_some_and(Token::is_cancelled))
+}
+
+/// Steps of a hot loop between two checks of `cancelled`, which would slow down loops doing
+/// little work per step if checked at every one.
+const STEPS_PER_CHECK: u64 = 1 << 16;
+
+/// Like `cancelled`, but only checks every few thousand steps of a hot loop.
+pub fn cancelled_at(step: u64) -> bool {
+    step.is_multiple_of(STEPS_PER_CHECK) && cancelled()
+}
+
+/// Description of the empty answer of a part whose solver gave up once cancelled.
+pub const CANCELLED: &str = "Cancelled.";
@@ -0,0 +1,130 @@
+use crate::config::Config;
+use crate::Part;
+use std::env;
+use std::error;
+use std::fmt;
+use std::time::Duration;
+
+/// Server used unless `base_url` is configured, e.g. to point at a local stand-in.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable giving the contact sent to the server if `contact` is not configured.
+pub const CONTACT_VARIABLE: &str = "AOC_CONTACT";
+
+const YEAR: u32 = 2020;
+const TOOL: &str = concat!(env!("CARGO_PKG_NAME"), " ", env!("CARGO_PKG_VERSION"));
+
+/// User agent naming the tool and, if given, whom to contact about its requests.
+fn user_agent(contact: Option<&str>) -> String {
+    match contact {
+        Some(contact) => format!(
+            "github.com/HenningHolmDE/adventofcode-2020 by {} ({})",
+            contact, TOOL
+        ),
+        None => format!("github.com/HenningHolmDE/adventofcode-2020 ({})", TOOL),
+    }
+}
+
+/// Error talking to the puzzle server.
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Status(u16, String),
+    Transport(String),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(f, "no 'session' token configured"),
+            ClientError::Status(status, body) => {
+                write!(f, "server responded with status {}", status)?;
+                match body.lines().next().map(str::trim) {
+                    Some(line) if !line.is_empty() => write!(f, ": {}", line),
+                    _ => Ok(()),
+                }
+            }
+            ClientError::Transport(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl error::Error for ClientError {}
+
+fn read_response(
+    url: &str,
+    result: Result<ureq::Response, ureq::Error>,
+) -> Result<String, ClientError> {
+    match result {
+        Ok(response) => response
+            .into_string()
+            .map_err(|err| ClientError::Transport(format!("{}: {}", url, err))),
+        Err(ureq::Error::Status(status, response)) => Err(ClientError::Status(
+            status,
+            response.into_string().unwrap_or_default(),
+        )),
+        Err(err) => Err(ClientError::Transport(err.to_string())),
+    }
+}
+
+/// Client of the puzzle server, authenticated by the session cookie of an account.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    /// Creates a client sending the contact, e.g. an email address, along with its requests.
+    pub fn new(base_url: &str, session: &str, contact: Option<&str>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(&user_agent(contact))
+                .build(),
+        }
+    }
+
+    /// Creates a client from the `session`, `base_url` and `contact` settings, the contact
+    /// being taken from `$AOC_CONTACT` if not configured and left out if not set at all.
+    pub fn from_config(config: &Config) -> Result<Self, ClientError> {
+        let session = config.get("session").ok_or(ClientError::MissingSession)?;
+        let base_url = config.get("base_url").unwrap_or(DEFAULT_BASE_URL);
+        let contact = match config.get("contact") {
+            Some(contact) => Some(contact.to_owned()),
+            None => env::var(CONTACT_VARIABLE).ok(),
+        };
+        let contact = contact.filter(|contact| !contact.trim().is_empty());
+        Ok(Self::new(base_url, session, contact.as_deref()))
+    }
+
+    pub fn session(&self) -> &str {
+        &self.session
+    }
+
+    /// Downloads the puzzle input of a day.
+    pub fn input(&self, day: u32) -> Result<String, ClientError> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+        let request = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session));
+        read_response(&url, request.call())
+    }
+
+    /// Submits the answer to a part, returning the server's response page.
+    pub fn submit(&self, day: u32, part: Part, answer: &str) -> Result<String, ClientError> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, YEAR, day);
+        let request = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session));
+        let level = part.to_string();
+        read_response(
+            &url,
+            request.send_form(&[("level", &level), ("answer", answer)]),
+        )
+    }
+}
@@ -0,0 +1,80 @@
+use crate::error::{Error, ParseError};
+use crate::input;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+
+/// File the configuration is read from unless another one is given.
+pub const DEFAULT_PATH: &str = "aoc.conf";
+
+/// Environment variable overriding the location of the configuration file.
+pub const CONFIG_VARIABLE: &str = "AOC_CONFIG";
+
+/// Settings given as `key = value` lines, `#` starting a comment line. Keys following a
+/// `[section]` line are stored as `section.key`.
+#[derive(Debug, Default)]
+pub struct Config {
+    values: BTreeMap<String, String>,
+}
+
+impl Config {
+    /// Location of the configuration file, taken from `$AOC_CONFIG` if set.
+    pub fn default_path() -> String {
+        env::var(CONFIG_VARIABLE).unwrap_or_else(|_| DEFAULT_PATH.to_owned())
+    }
+
+    /// Loads the configuration from a file, a missing file yielding an empty configuration.
+    pub fn load(path: &str) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content).map_err(|err| Error::Parse(err.in_file(path))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(Error::Io(path.to_owned(), err)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, ParseError> {
+        let mut config = Self::default();
+        let mut section = None;
+        for line in input::lines(content) {
+            let text = line.text.trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = text.strip_prefix('[') {
+                let name = name
+                    .strip_suffix(']')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .ok_or_else(|| line.error("expected '[section]'"))?;
+                section = Some(name);
+                continue;
+            }
+            let (key, value) = line.split_once("=")?;
+            let key = key.trim();
+            if key.is_empty() {
+                return Err(line.error("missing key"));
+            }
+            let value = value.trim().trim_matches('"');
+            let full_key = match section {
+                Some(section) => format!("{}.{}", section, key),
+                None => key.to_owned(),
+            };
+            if config.values.insert(full_key, value.to_owned()).is_some() {
+                return Err(line.error_at(key, format!("duplicate key '{}'", key)));
+            }
+        }
+        Ok(config)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    /// All settings ordered by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+}
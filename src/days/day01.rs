@@ -0,0 +1,367 @@
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::input::{self, Line};
+use crate::parameters::Parameter;
+use crate::solution::{Solution, Variant};
+use crate::Part;
+use num_bigint::BigInt;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::ops::ControlFlow;
+
+const TARGET: i64 = 2020;
+
+fn parse_entry(line: Line) -> Result<i64, ParseError> {
+    line.parse(line.text, "entry")
+}
+
+fn parse_numbers(input: &str) -> Result<Vec<i64>, ParseError> {
+    input::parse_lines(input, parse_entry)
+}
+
+/// Finds every combination of `k` distinct entries summing to the target, each given by its
+/// indices in ascending order. The combinations are ordered by their indices.
+pub fn find_k_sum(numbers: &[i64], k: usize, target: i64) -> Vec<Vec<usize>> {
+    let mut combinations = vec![];
+    let _ = visit_k_sum(numbers, k, target, &mut |combination| {
+        combinations.push(combination);
+        ControlFlow::Continue(())
+    });
+    combinations.sort_unstable();
+    combinations
+}
+
+/// Finds a combination of `k` distinct entries summing to the target like `find_k_sum`, but
+/// stops searching at the first one found. For up to three entries, this is the first one of
+/// `find_k_sum`, which the variants of the parts find as well.
+pub fn first_k_sum(numbers: &[i64], k: usize, target: i64) -> Option<Vec<usize>> {
+    let mut first = None;
+    let _ = visit_k_sum(numbers, k, target, &mut |combination| {
+        first = Some(combination);
+        ControlFlow::Break(())
+    });
+    first
+}
+
+/// Calls `visit` for the combinations of `k` distinct entries summing to the target until it
+/// breaks.
+fn visit_k_sum(
+    numbers: &[i64],
+    k: usize,
+    target: i64,
+    visit: &mut impl FnMut(Vec<usize>) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    // shifting all entries by the smallest negative one keeps the partial sums increasing
+    let offset = numbers.iter().cloned().min().unwrap_or(0).min(0);
+    let shifted: Vec<u64> = numbers
+        .iter()
+        .map(|&number| (i128::from(number) - i128::from(offset)) as u64)
+        .collect();
+    let target = match i128::from(target) - k as i128 * i128::from(offset) {
+        target if target < 0 => return ControlFlow::Continue(()),
+        target => target as u128,
+    };
+    match k {
+        0 if target == 0 => visit(vec![]),
+        0 => ControlFlow::Continue(()),
+        1 => (0..shifted.len())
+            .filter(|&i| u128::from(shifted[i]) == target)
+            .try_for_each(|i| visit(vec![i])),
+        2 | 3 => {
+            let positions = positions(&shifted);
+            let mut prefix = vec![];
+            find_with_pairs(&shifted, &positions, k, target, 0, &mut prefix, visit)
+        }
+        _ => meet_in_the_middle(&shifted, k, target, visit),
+    }
+}
+
+/// Ascending indices of each value among the entries.
+fn positions(numbers: &[u64]) -> HashMap<u64, Vec<usize>> {
+    let mut positions: HashMap<u64, Vec<usize>> = HashMap::new();
+    for (i, &number) in numbers.iter().enumerate() {
+        positions.entry(number).or_default().push(i);
+    }
+    positions
+}
+
+/// Chooses entries from `start` on until two are left, which are found by looking up the
+/// complement of the first of them.
+fn find_with_pairs(
+    numbers: &[u64],
+    positions: &HashMap<u64, Vec<usize>>,
+    k: usize,
+    rest: u128,
+    start: usize,
+    prefix: &mut Vec<usize>,
+    visit: &mut impl FnMut(Vec<usize>) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    for i in start..numbers.len() {
+        let rest = match rest.checked_sub(u128::from(numbers[i])) {
+            Some(rest) => rest,
+            None => continue,
+        };
+        prefix.push(i);
+        if k > 2 {
+            find_with_pairs(numbers, positions, k - 1, rest, i + 1, prefix, visit)?;
+        } else if let Some(matches) = u64::try_from(rest)
+            .ok()
+            .and_then(|rest| positions.get(&rest))
+        {
+            let after = matches.partition_point(|&j| j <= i);
+            for &j in &matches[after..] {
+                let mut combination = prefix.clone();
+                combination.push(j);
+                visit(combination)?;
+            }
+        }
+        prefix.pop();
+    }
+    ControlFlow::Continue(())
+}
+
+/// Calls `visit` for every combination of `k` entries with ascending indices whose sum does
+/// not exceed `limit`, until it breaks.
+fn combinations_within(
+    numbers: &[u64],
+    k: usize,
+    limit: u128,
+    visit: &mut impl FnMut(&[usize], u128) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    fn extend(
+        numbers: &[u64],
+        k: usize,
+        limit: u128,
+        sum: u128,
+        combination: &mut Vec<usize>,
+        visit: &mut impl FnMut(&[usize], u128) -> ControlFlow<()>,
+    ) -> ControlFlow<()> {
+        if combination.len() == k {
+            return visit(combination, sum);
+        }
+        let start = combination.last().map_or(0, |&last| last + 1);
+        for i in start..numbers.len() {
+            let sum = sum + u128::from(numbers[i]);
+            if sum <= limit {
+                combination.push(i);
+                extend(numbers, k, limit, sum, combination, visit)?;
+                combination.pop();
+            }
+        }
+        ControlFlow::Continue(())
+    }
+    extend(numbers, k, limit, 0, &mut Vec::with_capacity(k), visit)
+}
+
+/// Splits each combination into its lower and upper half of indices. The lower halves are
+/// grouped by their sum, and each upper half is joined with those of the complementary sum
+/// ending before it starts.
+fn meet_in_the_middle(
+    numbers: &[u64],
+    k: usize,
+    target: u128,
+    visit: &mut impl FnMut(Vec<usize>) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    let lower_size = k / 2;
+    let mut lower: HashMap<u128, Vec<Vec<usize>>> = HashMap::new();
+    let _ = combinations_within(numbers, lower_size, target, &mut |combination, sum| {
+        lower.entry(sum).or_default().push(combination.to_vec());
+        ControlFlow::Continue(())
+    });
+    combinations_within(
+        numbers,
+        k - lower_size,
+        target,
+        &mut |upper, sum| match lower.get(&(target - sum)) {
+            Some(halves) => halves
+                .iter()
+                .filter(|half| half[lower_size - 1] < upper[0])
+                .try_for_each(|half| visit([half.as_slice(), upper].concat())),
+            None => ControlFlow::Continue(()),
+        },
+    )
+}
+
+/// Index of the last occurrence of each value among the entries.
+fn last_indices(numbers: &[i64]) -> HashMap<i64, usize> {
+    numbers
+        .iter()
+        .enumerate()
+        .map(|(i, &number)| (number, i))
+        .collect()
+}
+
+/// Exact product of the entries, which may not fit any machine integer.
+fn product(entries: &[i64]) -> BigInt {
+    entries.iter().map(|&entry| BigInt::from(entry)).product()
+}
+
+/// Answer multiplying the entries found, which are also given as auxiliary values along with
+/// the product as decimal text, the same whether or not it fits a machine integer.
+fn product_answer(entries: &[i64]) -> Answer {
+    let product = product(entries);
+    let exact = product.to_string();
+    let factors: Vec<_> = entries.iter().map(|entry| entry.to_string()).collect();
+    let description = format!("{} = {}", factors.join(" * "), exact);
+    let mut answer = Answer::new(product, description);
+    for (i, &entry) in entries.iter().enumerate() {
+        answer = answer.with(format!("entry{}", i + 1), entry);
+    }
+    answer.with("product", exact)
+}
+
+/// Number of entries as written in the descriptions of answers.
+fn count_name(k: usize) -> String {
+    match k {
+        1 => "one".to_owned(),
+        2 => "two".to_owned(),
+        3 => "three".to_owned(),
+        4 => "four".to_owned(),
+        _ => k.to_string(),
+    }
+}
+
+pub struct Day01 {
+    target: i64,
+    entries_part1: usize,
+    entries_part2: usize,
+}
+
+impl Default for Day01 {
+    fn default() -> Self {
+        Self {
+            target: TARGET,
+            entries_part1: 2,
+            entries_part2: 3,
+        }
+    }
+}
+
+impl Solution for Day01 {
+    type Input = Vec<i64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_numbers(input)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_lines(input, parse_entry)
+    }
+
+    fn parameters(&mut self) -> Vec<(&'static str, &mut dyn Parameter)> {
+        vec![
+            ("target", &mut self.target),
+            ("part1_entries", &mut self.entries_part1),
+            ("part2_entries", &mut self.entries_part2),
+        ]
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> Answer {
+        self.multiply_entries(numbers, self.entries_part1)
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> Answer {
+        self.multiply_entries(numbers, self.entries_part2)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self>> {
+        vec![
+            Variant {
+                name: "nested_loops",
+                part: Part::One,
+                solve: |day, numbers| day.part1_nested_loops(numbers),
+            },
+            Variant {
+                name: "hash_set",
+                part: Part::One,
+                solve: |day, numbers| day.part1_hash_set(numbers),
+            },
+            Variant {
+                name: "nested_loops",
+                part: Part::Two,
+                solve: |day, numbers| day.part2_nested_loops(numbers),
+            },
+            Variant {
+                name: "hash_set",
+                part: Part::Two,
+                solve: |day, numbers| day.part2_hash_set(numbers),
+            },
+        ]
+    }
+}
+
+impl Day01 {
+    /// Multiplies the entries of the first combination found of the given number of entries
+    /// summing to the target.
+    fn multiply_entries(&self, numbers: &[i64], k: usize) -> Answer {
+        match first_k_sum(numbers, k, self.target) {
+            Some(combination) => {
+                let entries: Vec<_> = combination.iter().map(|&i| numbers[i]).collect();
+                product_answer(&entries)
+            }
+            None => Answer::empty(format!(
+                "No {} entries sum to {}.",
+                count_name(k),
+                self.target
+            )),
+        }
+    }
+
+    /// Tries all pairs of entries, limited to two entries.
+    fn part1_nested_loops(&self, numbers: &[i64]) -> Answer {
+        let target = i128::from(self.target);
+        for (i, &number1) in numbers.iter().enumerate() {
+            for &number2 in &numbers[i + 1..] {
+                if i128::from(number1) + i128::from(number2) == target {
+                    return product_answer(&[number1, number2]);
+                }
+            }
+        }
+        Answer::empty(format!("No two entries sum to {}.", self.target))
+    }
+
+    /// Tries all triples of entries, limited to three entries.
+    fn part2_nested_loops(&self, numbers: &[i64]) -> Answer {
+        let target = i128::from(self.target);
+        for (i, &number1) in numbers.iter().enumerate() {
+            for (j, &number2) in numbers[i + 1..].iter().enumerate() {
+                for &number3 in &numbers[i + j + 2..] {
+                    let sum = i128::from(number1) + i128::from(number2) + i128::from(number3);
+                    if sum == target {
+                        return product_answer(&[number1, number2, number3]);
+                    }
+                }
+            }
+        }
+        Answer::empty(format!("No three entries sum to {}.", self.target))
+    }
+
+    /// Finds the pair by looking up the complement of each entry among the entries after it,
+    /// which are known by the last index of each value.
+    fn part1_hash_set(&self, numbers: &[i64]) -> Answer {
+        let last = last_indices(numbers);
+        for (i, &number1) in numbers.iter().enumerate() {
+            let complement = i64::try_from(i128::from(self.target) - i128::from(number1)).ok();
+            if let Some(number2) = complement.filter(|number2| last.get(number2) > Some(&i)) {
+                return product_answer(&[number1, number2]);
+            }
+        }
+        Answer::empty(format!("No two entries sum to {}.", self.target))
+    }
+
+    /// Finds the triple by looking up the complement of each pair among the entries after it,
+    /// which are known by the last index of each value.
+    fn part2_hash_set(&self, numbers: &[i64]) -> Answer {
+        let last = last_indices(numbers);
+        for (i, &number1) in numbers.iter().enumerate() {
+            for (j, &number2) in numbers.iter().enumerate().skip(i + 1) {
+                let rest = i128::from(self.target) - i128::from(number1) - i128::from(number2);
+                let complement = i64::try_from(rest).ok();
+                if let Some(number3) = complement.filter(|number3| last.get(number3) > Some(&j)) {
+                    return product_answer(&[number1, number2, number3]);
+                }
+            }
+        }
+        Answer::empty(format!("No three entries sum to {}.", self.target))
+    }
+}
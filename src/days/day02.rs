@@ -0,0 +1,380 @@
+use crate::answer::Answer;
+use crate::audit::{Audit, Violation};
+use crate::error::ParseError;
+use crate::input::{self, Line};
+use crate::parameters::Parameter;
+use crate::solution::Solution;
+use crate::Part;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+/// Characters a rule is about, written as one or more characters like `a` or `aeiou`, or as a
+/// class like `[a-z0-9]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Characters {
+    text: String,
+    ranges: Vec<RangeInclusive<char>>,
+}
+
+impl Characters {
+    pub fn contains(&self, c: char) -> bool {
+        self.ranges.iter().any(|range| range.contains(&c))
+    }
+
+    fn parse(line: Line, text: &str) -> Result<Self, ParseError> {
+        let class = text
+            .strip_prefix('[')
+            .and_then(|class| class.strip_suffix(']'))
+            .filter(|class| !class.is_empty());
+        let chars: Vec<char> = class.unwrap_or(text).chars().collect();
+        if chars.is_empty() {
+            return Err(line.error_at(text, "missing policy characters"));
+        }
+        let mut ranges = vec![];
+        let mut i = 0;
+        while i < chars.len() {
+            // a '-' between two characters of a class spans the characters in between
+            if class.is_some() && i + 2 < chars.len() && chars[i + 1] == '-' {
+                if chars[i] > chars[i + 2] {
+                    return Err(line.error_at(
+                        text,
+                        format!("invalid character range '{}-{}'", chars[i], chars[i + 2]),
+                    ));
+                }
+                ranges.push(chars[i]..=chars[i + 2]);
+                i += 3;
+            } else {
+                ranges.push(chars[i]..=chars[i]);
+                i += 1;
+            }
+        }
+        Ok(Self {
+            text: text.to_owned(),
+            ranges,
+        })
+    }
+}
+
+/// Characters as written in the rule, quoted.
+impl fmt::Display for Characters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "'{}'", self.text)
+    }
+}
+
+/// Numbers and characters given with a password, which the policies interpret differently.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rule {
+    pub first: usize,
+    pub second: usize,
+    pub characters: Characters,
+}
+
+/// Interpretation of the rules of the password database.
+pub trait PasswordPolicy: Sync {
+    fn name(&self) -> &'static str;
+
+    /// Explains why the password breaks its rule, if it does.
+    fn violation(&self, rule: &Rule, password: &str) -> Option<String>;
+
+    fn is_valid(&self, rule: &Rule, password: &str) -> bool {
+        self.violation(rule, password).is_none()
+    }
+}
+
+fn times(count: usize) -> String {
+    match count {
+        1 => "1 time".to_owned(),
+        _ => format!("{} times", count),
+    }
+}
+
+/// The policy characters appear at least `first` and at most `second` times.
+struct Count;
+
+impl PasswordPolicy for Count {
+    fn name(&self) -> &'static str {
+        "count"
+    }
+
+    fn violation(&self, rule: &Rule, password: &str) -> Option<String> {
+        let count = password
+            .chars()
+            .filter(|&c| rule.characters.contains(c))
+            .count();
+        match rule.first <= count && count <= rule.second {
+            true => None,
+            false => Some(format!(
+                "{} appears {}, expected {}–{}",
+                rule.characters,
+                times(count),
+                rule.first,
+                rule.second
+            )),
+        }
+    }
+}
+
+/// Exactly one of the positions `first` and `second` holds a policy character.
+struct Positions;
+
+impl PasswordPolicy for Positions {
+    fn name(&self) -> &'static str {
+        "positions"
+    }
+
+    fn violation(&self, rule: &Rule, password: &str) -> Option<String> {
+        let at = |position: usize| {
+            password
+                .chars()
+                .nth(position - 1)
+                .is_some_and(|c| rule.characters.contains(c))
+        };
+        match (at(rule.first), at(rule.second)) {
+            (true, true) => Some(format!(
+                "both positions {} and {} contain {}",
+                rule.first, rule.second, rule.characters
+            )),
+            (false, false) => Some(format!(
+                "neither position {} nor {} contains {}",
+                rule.first, rule.second, rule.characters
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// No policy character appears at the positions from `first` to `second`.
+struct Forbidden;
+
+impl PasswordPolicy for Forbidden {
+    fn name(&self) -> &'static str {
+        "forbidden"
+    }
+
+    fn violation(&self, rule: &Rule, password: &str) -> Option<String> {
+        let (index, c) = password
+            .chars()
+            .enumerate()
+            .skip(rule.first - 1)
+            .take((rule.second + 1).saturating_sub(rule.first))
+            .find(|&(_, c)| rule.characters.contains(c))?;
+        Some(format!(
+            "position {} contains '{}', forbidden at positions {}–{}",
+            index + 1,
+            c,
+            rule.first,
+            rule.second
+        ))
+    }
+}
+
+/// The password is at least `first` and at most `second` characters long.
+struct Length;
+
+impl PasswordPolicy for Length {
+    fn name(&self) -> &'static str {
+        "length"
+    }
+
+    fn violation(&self, rule: &Rule, password: &str) -> Option<String> {
+        let length = password.chars().count();
+        match rule.first <= length && length <= rule.second {
+            true => None,
+            false => Some(format!(
+                "password has {} characters, expected {}–{}",
+                length, rule.first, rule.second
+            )),
+        }
+    }
+}
+
+const BUILT_IN: [&dyn PasswordPolicy; 4] = [&Count, &Positions, &Forbidden, &Length];
+
+lazy_static! {
+    /// Policies known by name, the built-in ones followed by the registered ones.
+    static ref POLICIES: RwLock<Vec<&'static dyn PasswordPolicy>> =
+        RwLock::new(BUILT_IN.to_vec());
+}
+
+/// Policy chosen by its name, e.g. as a puzzle parameter.
+#[derive(Clone, Copy)]
+pub struct Policy(&'static dyn PasswordPolicy);
+
+impl Policy {
+    /// Makes a policy known by its name next to the built-in ones, so that it can be chosen
+    /// like them. Fails if a policy of the same name is known already.
+    pub fn register(policy: &'static dyn PasswordPolicy) -> Result<(), String> {
+        let mut policies = POLICIES.write().unwrap();
+        if policies.iter().any(|known| known.name() == policy.name()) {
+            return Err(format!("policy '{}' is known already", policy.name()));
+        }
+        policies.push(policy);
+        Ok(())
+    }
+}
+
+impl fmt::Display for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.name())
+    }
+}
+
+impl FromStr for Policy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let policies = POLICIES.read().unwrap();
+        match policies.iter().find(|policy| policy.name() == s) {
+            Some(&policy) => Ok(Policy(policy)),
+            None => {
+                let names: Vec<_> = policies.iter().map(|policy| policy.name()).collect();
+                Err(format!(
+                    "unknown policy '{}', expected one of {}",
+                    s,
+                    names.join(", ")
+                ))
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PasswordWithPolicy {
+    password: String,
+    rule: Rule,
+}
+
+impl PasswordWithPolicy {
+    fn new(line: Line) -> Result<PasswordWithPolicy, ParseError> {
+        let (rule, password) = line.split_once(": ")?;
+        let (range, characters) = rule
+            .split_once(' ')
+            .ok_or_else(|| line.error("expected policy '<first>-<second> <characters>'"))?;
+        let (first, second) = range
+            .split_once('-')
+            .ok_or_else(|| line.error_at(range, "missing '-' in policy range"))?;
+        let first = parse_position(line, first)?;
+        let second = parse_position(line, second)?;
+        line.check_chars(characters, "policy character", |c| !c.is_whitespace())?;
+        let characters = Characters::parse(line, characters)?;
+        if password.is_empty() {
+            return Err(line.error_at(password, "missing password"));
+        }
+        line.check_chars(password, "password character", |c| !c.is_whitespace())?;
+        Ok(PasswordWithPolicy {
+            password: password.to_owned(),
+            rule: Rule {
+                first,
+                second,
+                characters,
+            },
+        })
+    }
+
+    fn is_valid(&self, policy: Policy) -> bool {
+        policy.0.is_valid(&self.rule, &self.password)
+    }
+}
+
+fn parse_position(line: Line, position: &str) -> Result<usize, ParseError> {
+    let value = line.parse(position, "position")?;
+    if value == 0 {
+        return Err(line.error_at(position, "positions start at 1"));
+    }
+    Ok(value)
+}
+
+fn parse_passwords(input: &str) -> Result<Vec<PasswordWithPolicy>, ParseError> {
+    input::parse_lines(input, PasswordWithPolicy::new)
+}
+
+pub struct Day02 {
+    policy_part1: Policy,
+    policy_part2: Policy,
+}
+
+impl Default for Day02 {
+    fn default() -> Self {
+        Self {
+            policy_part1: Policy(&Count),
+            policy_part2: Policy(&Positions),
+        }
+    }
+}
+
+impl Day02 {
+    fn count_valid(&self, passwords: &[PasswordWithPolicy], policy: Policy) -> Answer {
+        let number_of_valid_passwords = passwords.iter().filter(|p| p.is_valid(policy)).count();
+        Answer::new(
+            number_of_valid_passwords,
+            format!(
+                "{} of {} passwords are valid according to the {} policy.",
+                number_of_valid_passwords,
+                passwords.len(),
+                policy,
+            ),
+        )
+        .with("passwords", passwords.len())
+    }
+}
+
+impl Solution for Day02 {
+    type Input = Vec<PasswordWithPolicy>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_passwords(input)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_lines(input, PasswordWithPolicy::new)
+    }
+
+    fn parameters(&mut self) -> Vec<(&'static str, &mut dyn Parameter)> {
+        vec![
+            ("part1_policy", &mut self.policy_part1),
+            ("part2_policy", &mut self.policy_part2),
+        ]
+    }
+
+    fn part1(&self, passwords: &Self::Input) -> Answer {
+        self.count_valid(passwords, self.policy_part1)
+    }
+
+    fn part2(&self, passwords: &Self::Input) -> Answer {
+        self.count_valid(passwords, self.policy_part2)
+    }
+
+    fn audit(&self, part: Part, input: &str) -> Option<Audit> {
+        let policy = match part {
+            Part::One => self.policy_part1,
+            Part::Two => self.policy_part2,
+        };
+        let mut audit = Audit {
+            rules: format!("{} policy", policy),
+            checked: 0,
+            violations: vec![],
+            malformed: vec![],
+        };
+        for line in input::lines(input) {
+            let password = match PasswordWithPolicy::new(line) {
+                Ok(password) => password,
+                Err(err) => {
+                    audit.malformed.push(err);
+                    continue;
+                }
+            };
+            audit.checked += 1;
+            if let Some(reason) = policy.0.violation(&password.rule, &password.password) {
+                audit.violations.push(Violation {
+                    line: line.number,
+                    text: line.text.to_owned(),
+                    reason,
+                });
+            }
+        }
+        Some(audit)
+    }
+}
@@ -0,0 +1,382 @@
+use crate::animation::{Frame, Simulation, Still};
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::parameters::Parameter;
+use crate::solution::Solution;
+use crate::trace::{self, Level};
+use crate::Part;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct MapType {
+    trees: Grid<bool>,
+}
+
+impl MapType {
+    fn height(&self) -> usize {
+        self.trees.height()
+    }
+
+    /// Column of the map the given column of the endlessly repeating pattern lies in.
+    fn column(&self, x: i64) -> usize {
+        x.rem_euclid(self.trees.width() as i64) as usize
+    }
+
+    fn is_tree_at(&self, y: usize, x: i64) -> bool {
+        *self.trees.get_wrapping((self.column(x), y))
+    }
+
+    /// Positions visited going down the slope from the top left, one per step.
+    fn path<'a>(&self, slope: &'a Slope) -> impl Iterator<Item = (usize, i64)> + 'a {
+        (0..self.height().div_ceil(slope.down))
+            .map(move |i| (i * slope.down, i as i64 * slope.right))
+    }
+
+    fn trees_for_slope(&self, slope: &Slope) -> usize {
+        self.path(slope)
+            .filter(|&(y, x)| self.is_tree_at(y, x))
+            .count()
+    }
+
+    /// Map repeated as far to the left and right as the path goes, which is marked with 'O'
+    /// on open squares and 'X' on trees as in the puzzle text.
+    fn render(&self, slope: &Slope) -> Vec<String> {
+        let width = self.trees.width() as i64;
+        // column of the path in each row it passes
+        let mut path = vec![None; self.height()];
+        for (y, x) in self.path(slope) {
+            path[y] = Some(x);
+        }
+        // whole repetitions of the pattern covering the path
+        let repetitions = path.iter().flatten().map(|&x| x.div_euclid(width));
+        let left = repetitions.clone().min().unwrap_or(0).min(0);
+        let right = repetitions.max().unwrap_or(0).max(0);
+        (0..self.height())
+            .map(|y| {
+                (left * width..(right + 1) * width)
+                    .map(|x| match (path[y] == Some(x), self.is_tree_at(y, x)) {
+                        (true, true) => 'X',
+                        (true, false) => 'O',
+                        (false, true) => '#',
+                        (false, false) => '.',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+fn parse_tree(c: char) -> Option<bool> {
+    match c {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    }
+}
+
+fn parse_map(input: &str) -> Result<MapType, ParseError> {
+    let trees = Grid::parse(input, parse_tree)?;
+    Ok(MapType { trees })
+}
+
+/// Steps to the right, negative ones going left, and down taken at a time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Slope {
+    right: i64,
+    down: usize,
+}
+
+impl Slope {
+    fn new(right: i64, down: usize) -> Self {
+        Self { right, down }
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.right, self.down)
+    }
+}
+
+/// Slope written as `<right>,<down>`, e.g. `-1,2`.
+impl FromStr for Slope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid slope '{}', expected <RIGHT>,<DOWN>", s);
+        let (right, down) = s.split_once(',').ok_or_else(invalid)?;
+        let right = right.trim().parse().map_err(|_| invalid())?;
+        match down.trim().parse() {
+            Ok(down) if down > 0 => Ok(Self::new(right, down)),
+            _ => Err(format!(
+                "invalid slope '{}', expected to go down at least 1",
+                s
+            )),
+        }
+    }
+}
+
+/// Slopes separated by spaces, e.g. `1,1 3,1`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Slopes(Vec<Slope>);
+
+impl fmt::Display for Slopes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let slopes: Vec<_> = self.0.iter().map(Slope::to_string).collect();
+        write!(f, "{}", slopes.join(" "))
+    }
+}
+
+impl FromStr for Slopes {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let slopes = s
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        match slopes.is_empty() {
+            true => Err("missing slopes".to_owned()),
+            false => Ok(Self(slopes)),
+        }
+    }
+}
+
+/// Largest steps tried by the search for the slope with the fewest trees, `off` to multiply
+/// the trees of the given slopes instead.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Search(Option<Slope>);
+
+impl Search {
+    /// Slopes going at most the bound to the left or right and down.
+    fn slopes(bound: Slope) -> impl Iterator<Item = Slope> {
+        let right = bound.right.abs();
+        (1..=bound.down)
+            .flat_map(move |down| (-right..=right).map(move |right| Slope::new(right, down)))
+    }
+}
+
+impl fmt::Display for Search {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(bound) => write!(f, "{}", bound),
+            None => write!(f, "off"),
+        }
+    }
+}
+
+impl FromStr for Search {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "off" {
+            return Ok(Self(None));
+        }
+        match s.parse::<Slope>()? {
+            bound if bound.right >= 1 => Ok(Self(Some(bound))),
+            _ => Err(format!(
+                "invalid bound '{}', expected to go right at least 1",
+                s
+            )),
+        }
+    }
+}
+
+/// Slopes sharing the fewest trees of all slopes searched, in the order they were tried.
+struct Fewest {
+    trees: usize,
+    slopes: Vec<Slope>,
+}
+
+impl Fewest {
+    /// Slope given as the answer and shown for the part, the first one tried.
+    fn best(&self) -> Slope {
+        self.slopes[0]
+    }
+}
+
+pub struct Day03 {
+    slope_part1: Slope,
+    slopes_part2: Slopes,
+    search: Search,
+}
+
+impl Default for Day03 {
+    fn default() -> Self {
+        Self {
+            slope_part1: Slope::new(3, 1),
+            slopes_part2: Slopes(vec![
+                Slope::new(1, 1),
+                Slope::new(3, 1),
+                Slope::new(5, 1),
+                Slope::new(7, 1),
+                Slope::new(1, 2),
+            ]),
+            search: Search(None),
+        }
+    }
+}
+
+impl Day03 {
+    /// Slopes with the fewest trees of all slopes within the bound.
+    fn fewest_trees(&self, map: &MapType, bound: Slope) -> Fewest {
+        let mut fewest = Fewest {
+            trees: usize::MAX,
+            slopes: vec![],
+        };
+        for slope in Search::slopes(bound) {
+            let trees = map.trees_for_slope(&slope);
+            trace::event(
+                "day03",
+                "slope",
+                &[
+                    ("right", &slope.right),
+                    ("down", &slope.down),
+                    ("trees", &trees),
+                ],
+            );
+            if trees < fewest.trees {
+                fewest = Fewest {
+                    trees,
+                    slopes: vec![slope],
+                };
+            } else if trees == fewest.trees {
+                fewest.slopes.push(slope);
+            }
+        }
+        fewest
+    }
+
+    fn search(&self, map: &MapType, bound: Slope) -> Answer {
+        let fewest = self.fewest_trees(map, bound);
+        for slope in &fewest.slopes {
+            walkthrough(map, slope);
+        }
+        let names: Vec<_> = fewest
+            .slopes
+            .iter()
+            .map(|slope| format!("\"{}\"", Description(slope)))
+            .collect();
+        let best = fewest.best();
+        Answer::new(
+            fewest.trees,
+            format!(
+                "Fewest trees, {}, on slopes {}",
+                fewest.trees,
+                names.join(", ")
+            ),
+        )
+        .with("right", best.right)
+        .with("down", best.down)
+        .with("slopes", fewest.slopes.len())
+    }
+
+    /// Slope whose path is shown for a part, along with the trees on it.
+    fn shown_slope(&self, part: Part, map: &MapType) -> Option<(Slope, usize)> {
+        match (part, self.search.0) {
+            (Part::One, _) => Some((self.slope_part1, map.trees_for_slope(&self.slope_part1))),
+            (Part::Two, Some(bound)) => {
+                let fewest = self.fewest_trees(map, bound);
+                Some((fewest.best(), fewest.trees))
+            }
+            (Part::Two, None) => None,
+        }
+    }
+}
+
+/// Slope as worded in the puzzle text.
+struct Description<'a>(&'a Slope);
+
+impl fmt::Display for Description<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0.right {
+            right if right < 0 => write!(f, "Left {}, down {}.", -right, self.0.down),
+            right => write!(f, "Right {}, down {}.", right, self.0.down),
+        }
+    }
+}
+
+fn walkthrough(map: &MapType, slope: &Slope) {
+    if trace::enabled(Level::Walkthrough) {
+        trace::walkthrough(format_args!(
+            "{}\n\n{}\n",
+            Description(slope),
+            map.render(slope).join("\n")
+        ));
+    }
+}
+
+impl Solution for Day03 {
+    type Input = MapType;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        Grid::check(input, parse_tree)
+    }
+
+    fn parameters(&mut self) -> Vec<(&'static str, &mut dyn Parameter)> {
+        vec![
+            ("part1_slope", &mut self.slope_part1),
+            ("part2_slopes", &mut self.slopes_part2),
+            ("part2_search", &mut self.search),
+        ]
+    }
+
+    fn part1(&self, map: &Self::Input) -> Answer {
+        let slope = self.slope_part1;
+        let trees_for_slope = map.trees_for_slope(&slope);
+        walkthrough(map, &slope);
+        Answer::new(
+            trees_for_slope,
+            format!(
+                "Number of trees in path of slope \"{}\": {}",
+                Description(&slope),
+                trees_for_slope
+            ),
+        )
+        .with("right", slope.right)
+        .with("down", slope.down)
+    }
+
+    fn part2(&self, map: &Self::Input) -> Answer {
+        if let Some(bound) = self.search.0 {
+            return self.search(map, bound);
+        }
+        let slopes = &self.slopes_part2.0;
+        let trees: Vec<_> = slopes
+            .iter()
+            .map(|slope| {
+                walkthrough(map, slope);
+                map.trees_for_slope(slope)
+            })
+            .collect();
+        let cumulative_product: usize = trees.iter().product();
+        slopes.iter().zip(trees).fold(
+            Answer::new(
+                cumulative_product,
+                format!("Cumulative product of trees: {}", cumulative_product),
+            ),
+            |answer, (slope, trees)| {
+                answer.with(
+                    format!("trees_right_{}_down_{}", slope.right, slope.down),
+                    trees,
+                )
+            },
+        )
+    }
+
+    fn simulation(&self, part: Part, map: &Self::Input) -> Option<Box<dyn Simulation>> {
+        let (slope, trees) = self.shown_slope(part, map)?;
+        Some(Box::new(Still(Frame {
+            generation: 0,
+            live: trees,
+            rows: map.render(&slope),
+            hexagonal: false,
+        })))
+    }
+}
@@ -0,0 +1,183 @@
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::input::{self, Line};
+use crate::solution::Solution;
+use regex::Regex;
+
+#[derive(Debug, Default)]
+pub struct Passport {
+    birth_year: Option<String>,
+    issue_year: Option<String>,
+    expiration_year: Option<String>,
+    height: Option<String>,
+    hair_color: Option<String>,
+    eye_color: Option<String>,
+    passport_id: Option<String>,
+    country_id: Option<String>,
+}
+
+impl Passport {
+    fn is_valid1(&self) -> bool {
+        self.birth_year.is_some()
+            && self.issue_year.is_some()
+            && self.expiration_year.is_some()
+            && self.height.is_some()
+            && self.hair_color.is_some()
+            && self.eye_color.is_some()
+            && self.passport_id.is_some()
+    }
+    fn is_valid2(&self) -> bool {
+        fn year_valid(year: &Option<String>, minimum: u32, maximum: u32) -> bool {
+            if let Some(year) = year {
+                if let Ok(year) = year.parse::<u32>() {
+                    year >= minimum && year <= maximum
+                } else {
+                    false
+                }
+            } else {
+                false
+            }
+        }
+        let height_valid = if let Some(height) = &self.height {
+            lazy_static! {
+                static ref RE: Regex = Regex::new(r"^(\d+)(\w+)$").unwrap();
+            }
+            if let Some(cap) = RE.captures(height) {
+                match (cap[1].parse::<u32>(), &cap[2]) {
+                    (Ok(number), "cm") => (150..=193).contains(&number),
+                    (Ok(number), "in") => (59..=76).contains(&number),
+                    _ => false,
+                }
+            } else {
+                false
+            }
+        } else {
+            false
+        };
+        let hair_color_valid = if let Some(hair_color) = &self.hair_color {
+            lazy_static! {
+                static ref RE: Regex = Regex::new(r"^#[0-9a-f]{6}$").unwrap();
+            }
+            RE.is_match(hair_color)
+        } else {
+            false
+        };
+        let eye_color_valid = if let Some(eye_color) = &self.eye_color {
+            matches!(
+                eye_color.as_ref(),
+                "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth"
+            )
+        } else {
+            false
+        };
+        let passport_id_valid = if let Some(passport_id) = &self.passport_id {
+            lazy_static! {
+                static ref RE: Regex = Regex::new(r"^\d{9}$").unwrap();
+            }
+            RE.is_match(passport_id)
+        } else {
+            false
+        };
+        year_valid(&self.birth_year, 1920, 2002)
+            && year_valid(&self.issue_year, 2010, 2020)
+            && year_valid(&self.expiration_year, 2020, 2030)
+            && height_valid
+            && hair_color_valid
+            && eye_color_valid
+            && passport_id_valid
+    }
+}
+
+#[derive(Debug)]
+struct PassportBuilder {
+    passport: Passport,
+}
+
+impl PassportBuilder {
+    fn new() -> Self {
+        Self {
+            passport: Default::default(),
+        }
+    }
+
+    fn add_data(&mut self, line: Line) -> Result<(), ParseError> {
+        for field in line.text.split_whitespace() {
+            let (key, value) = field
+                .split_once(':')
+                .ok_or_else(|| line.error_at(field, format!("missing ':' in field '{}'", field)))?;
+            if value.is_empty() {
+                return Err(line.error_at(field, format!("missing value for field '{}'", key)));
+            }
+            let string_option = Some(value.to_owned());
+            match key {
+                "byr" => self.passport.birth_year = string_option,
+                "iyr" => self.passport.issue_year = string_option,
+                "eyr" => self.passport.expiration_year = string_option,
+                "hgt" => self.passport.height = string_option,
+                "hcl" => self.passport.hair_color = string_option,
+                "ecl" => self.passport.eye_color = string_option,
+                "pid" => self.passport.passport_id = string_option,
+                "cid" => self.passport.country_id = string_option,
+                _ => return Err(line.error_at(key, format!("unknown field '{}'", key))),
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Passport {
+        self.passport
+    }
+}
+
+fn read_passports(input: &str, errors: &mut Vec<ParseError>) -> Vec<Passport> {
+    input::blocks(input)
+        .into_iter()
+        .map(|block| {
+            let mut builder = PassportBuilder::new();
+            for line in block {
+                input::record(errors, builder.add_data(line));
+            }
+            builder.finish()
+        })
+        .collect()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Passport>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input::parse_with(input, read_passports)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_with(input, read_passports)
+    }
+
+    fn part1(&self, passports: &Self::Input) -> Answer {
+        let number_of_valid_passports = passports.iter().filter(|p| p.is_valid1()).count();
+        Answer::new(
+            number_of_valid_passports,
+            format!(
+                "{} of {} passports are valid according to first validation scheme.",
+                number_of_valid_passports,
+                passports.len(),
+            ),
+        )
+        .with("passports", passports.len())
+    }
+
+    fn part2(&self, passports: &Self::Input) -> Answer {
+        let number_of_valid_passports = passports.iter().filter(|p| p.is_valid2()).count();
+        Answer::new(
+            number_of_valid_passports,
+            format!(
+                "{} of {} passports are valid according to second validation scheme.",
+                number_of_valid_passports,
+                passports.len(),
+            ),
+        )
+        .with("passports", passports.len())
+    }
+}
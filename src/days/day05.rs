@@ -0,0 +1,93 @@
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::input::{self, Line};
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub struct BoardingPass {
+    seat_id: u32,
+}
+
+impl BoardingPass {
+    fn new(line: Line) -> Result<Self, ParseError> {
+        if line.text.chars().count() != 10 {
+            return Err(line.error(format!(
+                "expected 10 characters, found {}",
+                line.text.chars().count()
+            )));
+        }
+        let mut seat_id = 0;
+        for (index, c) in line.text.char_indices() {
+            let bit = match (index, c) {
+                (0..=6, 'B') | (7..=9, 'R') => 1,
+                (0..=6, 'F') | (7..=9, 'L') => 0,
+                (0..=6, _) => {
+                    return Err(line.error_at(
+                        &line.text[index..],
+                        format!("invalid row character '{}', expected F or B", c),
+                    ))
+                }
+                _ => {
+                    return Err(line.error_at(
+                        &line.text[index..],
+                        format!("invalid column character '{}', expected L or R", c),
+                    ))
+                }
+            };
+            seat_id = seat_id << 1 | bit;
+        }
+        Ok(Self { seat_id })
+    }
+    fn seat_id(&self) -> u32 {
+        self.seat_id
+    }
+}
+
+fn parse_boarding_passes(input: &str) -> Result<Vec<BoardingPass>, ParseError> {
+    input::parse_lines(input, BoardingPass::new)
+}
+
+fn highest_seat_id(boarding_passes: &[BoardingPass]) -> Option<u32> {
+    boarding_passes.iter().map(|b| b.seat_id()).max()
+}
+
+fn seat_id_found(boarding_passes: &[BoardingPass], seat_id: u32) -> bool {
+    boarding_passes.iter().any(|b| b.seat_id() == seat_id)
+}
+
+fn find_my_seat(boarding_passes: &[BoardingPass]) -> Option<u32> {
+    (1..highest_seat_id(boarding_passes)?).find(|&seat_id| {
+        !seat_id_found(boarding_passes, seat_id)
+            && seat_id_found(boarding_passes, seat_id - 1)
+            && seat_id_found(boarding_passes, seat_id + 1)
+    })
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<BoardingPass>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_boarding_passes(input)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_lines(input, BoardingPass::new)
+    }
+
+    fn part1(&self, boarding_passes: &Self::Input) -> Answer {
+        match highest_seat_id(boarding_passes) {
+            Some(seat_id) => Answer::new(seat_id, format!("Highest seat ID: {}", seat_id)),
+            None => Answer::empty("No boarding passes found."),
+        }
+    }
+
+    fn part2(&self, boarding_passes: &Self::Input) -> Answer {
+        match find_my_seat(boarding_passes) {
+            Some(seat_id) => Answer::new(seat_id, format!("My seat ID: {}", seat_id))
+                .with("highest_seat_id", highest_seat_id(boarding_passes).unwrap()),
+            None => Answer::empty("No free seat found."),
+        }
+    }
+}
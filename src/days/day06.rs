@@ -0,0 +1,100 @@
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::input;
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub struct Group {
+    answers: Vec<String>,
+}
+
+impl Group {
+    fn new() -> Self {
+        Group {
+            answers: Vec::new(),
+        }
+    }
+    fn add_answers(&mut self, answers: &str) {
+        self.answers.push(answers.to_string());
+    }
+    fn number_of_questions_anyone(&self) -> usize {
+        let mut questions = Vec::new();
+        for answer in self.answers.iter() {
+            for question in answer.chars() {
+                if !questions.contains(&question) {
+                    questions.push(question);
+                }
+            }
+        }
+        questions.len()
+    }
+    fn number_of_questions_everyone(&self) -> usize {
+        let mut questions = Vec::new();
+        for (i, answer) in self.answers.iter().enumerate() {
+            let mut questions_new = Vec::new();
+            for question in answer.chars() {
+                if i == 0 || questions.contains(&question) {
+                    questions_new.push(question);
+                }
+            }
+            questions = questions_new;
+        }
+        questions.len()
+    }
+}
+
+fn read_groups(input: &str, errors: &mut Vec<ParseError>) -> Vec<Group> {
+    input::blocks(input)
+        .into_iter()
+        .map(|block| {
+            let mut group = Group::new();
+            for line in block {
+                let valid = line.check_chars(line.text, "question", |c| c.is_ascii_lowercase());
+                if input::record(errors, valid).is_some() {
+                    group.add_answers(line.text);
+                }
+            }
+            group
+        })
+        .collect()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Group>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input::parse_with(input, read_groups)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_with(input, read_groups)
+    }
+
+    fn part1(&self, groups: &Self::Input) -> Answer {
+        let number_of_questions: usize =
+            groups.iter().map(|g| g.number_of_questions_anyone()).sum();
+        Answer::new(
+            number_of_questions,
+            format!(
+                "Number of questions ANYONE in a group answered: {}",
+                number_of_questions
+            ),
+        )
+    }
+
+    fn part2(&self, groups: &Self::Input) -> Answer {
+        let number_of_questions: usize = groups
+            .iter()
+            .map(|g| g.number_of_questions_everyone())
+            .sum();
+        Answer::new(
+            number_of_questions,
+            format!(
+                "Number of questions EVERYONE in a group answered: {}",
+                number_of_questions
+            ),
+        )
+    }
+}
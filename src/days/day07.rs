@@ -0,0 +1,178 @@
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::ParseError;
+use crate::input::{self, Line};
+use crate::parameters::Parameter;
+use crate::solution::Solution;
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+struct Content {
+    bag_type: String,
+    amount: u32,
+}
+
+impl Content {
+    fn new(line: Line, content: &str) -> Result<Self, ParseError> {
+        lazy_static! {
+            static ref RE: Regex =
+                Regex::new(r"^(?P<amount>[0-9]+) (?P<bag_type>.*) bags?$").unwrap();
+        }
+        let captures = RE.captures(content).ok_or_else(|| {
+            line.error_at(
+                content,
+                format!("expected '<amount> <bag type> bag(s)', found '{}'", content),
+            )
+        })?;
+        Ok(Self {
+            bag_type: captures["bag_type"].to_owned(),
+            amount: line.parse(captures.name("amount").unwrap().as_str(), "amount")?,
+        })
+    }
+    fn bag_type(&self) -> String {
+        self.bag_type.clone()
+    }
+    fn amount(&self) -> u32 {
+        self.amount
+    }
+}
+
+#[derive(Debug)]
+pub struct Rule {
+    bag_type: String,
+    contents: Vec<Content>,
+}
+
+impl Rule {
+    fn new(line: Line) -> Result<Self, ParseError> {
+        lazy_static! {
+            static ref RE: Regex =
+                Regex::new(r"^(?P<bag_type>.*) bags contain (?P<contents>.*)\.$").unwrap();
+        }
+        let captures = RE
+            .captures(line.text)
+            .ok_or_else(|| line.error("expected '<bag type> bags contain <contents>.'"))?;
+        let bag_type = captures["bag_type"].to_owned();
+        let contents = captures.name("contents").unwrap().as_str();
+        let contents = if contents == "no other bags" {
+            Vec::new()
+        } else {
+            contents
+                .split(", ")
+                .map(|content| Content::new(line, content))
+                .collect::<Result<_, _>>()?
+        };
+        Ok(Self { bag_type, contents })
+    }
+    fn bag_type(&self) -> String {
+        self.bag_type.clone()
+    }
+    fn contents(&self) -> Vec<Content> {
+        self.contents.clone()
+    }
+    fn contains(&self, bag_type: &str) -> bool {
+        self.contents
+            .iter()
+            .any(|content| content.bag_type == bag_type)
+    }
+}
+
+fn parse_rules(input: &str) -> Result<Vec<Rule>, ParseError> {
+    input::parse_lines(input, Rule::new)
+}
+
+fn find_containers(rules: &[Rule], bag_type: &str) -> Vec<String> {
+    // bags found but not searched for yet, as cyclic rules would make recursion endless
+    let mut containers: Vec<String> = Vec::new();
+    let mut unsearched = vec![bag_type.to_owned()];
+    while let Some(bag_type) = unsearched.pop() {
+        for rule in rules.iter().filter(|rule| rule.contains(&bag_type)) {
+            let container = rule.bag_type();
+            if !containers.contains(&container) {
+                containers.push(container.clone());
+                unsearched.push(container);
+            }
+        }
+    }
+    containers.retain(|container| container != bag_type);
+    containers
+}
+
+/// Counts the bags inside a bag, `None` if it would contain itself, i.e. infinitely many
+/// bags, or if solving was cancelled.
+fn count_contents(rules: &[Rule], bag_type: &str, outer_bags: &mut Vec<String>) -> Option<u32> {
+    if cancel::cancelled() || outer_bags.iter().any(|outer| outer == bag_type) {
+        return None;
+    }
+    outer_bags.push(bag_type.to_owned());
+    let mut count = 0;
+    for rule in rules.iter().filter(|rule| rule.bag_type() == bag_type) {
+        for content in rule.contents() {
+            let inside = count_contents(rules, &content.bag_type(), outer_bags)?;
+            count += content.amount() * (1 + inside);
+        }
+    }
+    outer_bags.pop();
+    Some(count)
+}
+
+const BAG_TYPE: &str = "shiny gold";
+
+pub struct Day07 {
+    bag_type: String,
+}
+
+impl Default for Day07 {
+    fn default() -> Self {
+        Self {
+            bag_type: BAG_TYPE.to_owned(),
+        }
+    }
+}
+
+impl Solution for Day07 {
+    type Input = Vec<Rule>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_rules(input)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_lines(input, Rule::new)
+    }
+
+    fn parameters(&mut self) -> Vec<(&'static str, &mut dyn Parameter)> {
+        vec![("bag", &mut self.bag_type)]
+    }
+
+    fn part1(&self, rules: &Self::Input) -> Answer {
+        let number_of_containers = find_containers(rules, &self.bag_type).len();
+        Answer::new(
+            number_of_containers,
+            format!(
+                "Number of valid bags for a {} bag: {}",
+                self.bag_type, number_of_containers
+            ),
+        )
+    }
+
+    fn part2(&self, rules: &Self::Input) -> Answer {
+        let number_of_contents = match count_contents(rules, &self.bag_type, &mut Vec::new()) {
+            Some(number_of_contents) => number_of_contents,
+            None if cancel::cancelled() => return Answer::empty(cancel::CANCELLED),
+            None => {
+                return Answer::empty(format!(
+                    "A {} bag contains itself, infinitely many bags inside.",
+                    self.bag_type
+                ))
+            }
+        };
+        Answer::new(
+            number_of_contents,
+            format!(
+                "Number of bag inside a {} bag: {}",
+                self.bag_type, number_of_contents
+            ),
+        )
+    }
+}
@@ -0,0 +1,196 @@
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::input::{self, Line};
+use crate::solution::Solution;
+
+#[derive(Debug, Clone)]
+pub enum Command {
+    Acc(i32),
+    Jmp(i32),
+    Nop(i32),
+}
+
+impl Command {
+    fn new(line: Line) -> Result<Self, ParseError> {
+        let (command, argument) = line.split_once(" ")?;
+        let argument = line.parse(argument, "argument")?;
+        match command {
+            "acc" => Ok(Self::Acc(argument)),
+            "jmp" => Ok(Self::Jmp(argument)),
+            "nop" => Ok(Self::Nop(argument)),
+            _ => Err(line.error_at(command, format!("unknown operation '{}'", command))),
+        }
+    }
+}
+
+pub type Program = Vec<Command>;
+
+#[derive(Debug, PartialEq)]
+enum ExecuteResult {
+    EndlessLoop,
+    Finished,
+    /// The instruction at the index jumped elsewhere than right after the last instruction.
+    JumpedOutside(usize),
+}
+
+#[derive(Debug)]
+struct Interpreter {
+    program: Program,
+    accumulator: i32,
+    program_counter: usize,
+    trace: Vec<usize>,
+}
+
+impl Interpreter {
+    pub fn new(program: Program) -> Self {
+        Self {
+            program,
+            accumulator: 0,
+            program_counter: 0,
+            trace: Vec::new(),
+        }
+    }
+    pub fn accumulator(&self) -> i32 {
+        self.accumulator
+    }
+    pub fn execute(&mut self) -> ExecuteResult {
+        while !self.trace.contains(&self.program_counter) {
+            if self.program_counter == self.program.len() {
+                return ExecuteResult::Finished;
+            }
+            self.trace.push(self.program_counter);
+            if self.step().is_none() {
+                return ExecuteResult::JumpedOutside(self.program_counter);
+            }
+        }
+        ExecuteResult::EndlessLoop
+    }
+
+    /// Executes the current instruction, failing when it leaves the program.
+    fn step(&mut self) -> Option<()> {
+        let command = self.fetch()?;
+        if let Command::Acc(argument) = command {
+            self.accumulator += argument;
+        }
+        let program_counter = match command {
+            Command::Jmp(offset) => {
+                if offset.is_negative() {
+                    self.program_counter
+                        .checked_sub(offset.unsigned_abs() as usize)?
+                } else {
+                    self.program_counter.checked_add(offset as usize)?
+                }
+            }
+            _ => self.program_counter + 1,
+        };
+        if program_counter > self.program.len() {
+            return None;
+        }
+        self.program_counter = program_counter;
+        Some(())
+    }
+
+    fn fetch(&self) -> Option<Command> {
+        self.program.get(self.program_counter).cloned()
+    }
+}
+
+/// Reads the instructions, rejecting jumps which would leave the program other than right
+/// after its last instruction.
+fn read_program(input: &str, errors: &mut Vec<ParseError>) -> Program {
+    let lines: Vec<_> = input::lines(input).collect();
+    if lines.is_empty() {
+        errors.push(input::end_of_input(input, "missing instructions"));
+    }
+    let mut program = Vec::with_capacity(lines.len());
+    for (index, &line) in lines.iter().enumerate() {
+        if let Some(command) = input::record(errors, Command::new(line)) {
+            if let Command::Jmp(offset) = command {
+                let target = index as i64 + offset as i64;
+                if target < 0 || target > lines.len() as i64 {
+                    errors.push(line.error(format!(
+                        "jump of {:+} from instruction {} leaves the program ending after instruction {}",
+                        offset,
+                        index + 1,
+                        lines.len()
+                    )));
+                }
+            }
+            program.push(command);
+        }
+    }
+    program
+}
+
+fn modify_command(command: Command) -> Command {
+    match command {
+        Command::Acc(_) => command,
+        Command::Jmp(param) => Command::Nop(param),
+        Command::Nop(param) => Command::Jmp(param),
+    }
+}
+
+fn modify_program(program: Program, index: usize) -> Option<Program> {
+    if let Command::Acc(_) = program[index] {
+        None // there is no sense in executing an unchanged version
+    } else {
+        Some(
+            program
+                .into_iter()
+                .enumerate()
+                .map(|(i, cmd)| if i == index { modify_command(cmd) } else { cmd })
+                .collect(),
+        )
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Program;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input::parse_with(input, read_program)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_with(input, read_program)
+    }
+
+    fn part1(&self, program: &Self::Input) -> Answer {
+        let mut interpreter = Interpreter::new(program.clone());
+        if let ExecuteResult::JumpedOutside(index) = interpreter.execute() {
+            return Answer::empty(format!(
+                "Instruction {} jumped outside the program.",
+                index + 1
+            ));
+        }
+        Answer::new(
+            interpreter.accumulator(),
+            format!(
+                "Accumulator value when entering endless loop: {}",
+                interpreter.accumulator()
+            ),
+        )
+    }
+
+    fn part2(&self, program: &Self::Input) -> Answer {
+        // try to modify every instruction of the program
+        for instruction_index in 0..program.len() {
+            if let Some(program) = modify_program(program.clone(), instruction_index) {
+                let mut interpreter = Interpreter::new(program);
+                if interpreter.execute() == ExecuteResult::Finished {
+                    return Answer::new(
+                        interpreter.accumulator(),
+                        format!(
+                            "Program finished! Final accumulator value: {}",
+                            interpreter.accumulator()
+                        ),
+                    )
+                    .with("modified_instruction", instruction_index);
+                }
+            }
+        }
+        Answer::empty("No modified program finished.")
+    }
+}
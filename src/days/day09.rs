@@ -0,0 +1,205 @@
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::input::{self, Line};
+use crate::parameters::Parameter;
+use crate::solution::{Solution, Variant};
+use crate::Part;
+use std::collections::HashMap;
+
+const PREAMBLE_LENGTH: usize = 25;
+
+fn parse_number(line: Line) -> Result<u64, ParseError> {
+    line.parse(line.text, "number")
+}
+
+fn parse_numbers(input: &str) -> Result<Vec<u64>, ParseError> {
+    input::parse_lines(input, parse_number)
+}
+
+fn number_valid(previous_numbers: &[u64], number: &u64) -> bool {
+    previous_numbers
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| previous_numbers[i + 1..].iter().map(move |b| (a, b)))
+        .any(|(a, b)| a + b == *number)
+}
+
+fn find_invalid_index(numbers: &[u64], preamble_length: usize) -> Option<usize> {
+    for (i, number) in numbers.iter().enumerate().skip(preamble_length) {
+        let previous_numbers = &numbers[i - preamble_length..i];
+        if !number_valid(previous_numbers, number) {
+            return Some(i);
+        }
+    }
+    None
+}
+
+fn find_contiguous_set(numbers: &[u64], index_p1: usize) -> Option<&[u64]> {
+    let invalid_number = numbers[index_p1];
+    // - 1: at least two numbers
+    for start in 0..index_p1.saturating_sub(1) {
+        let mut sum = 0;
+        for current in start..index_p1 {
+            sum += numbers[current];
+            if sum == invalid_number {
+                return Some(&numbers[start..=current]);
+            }
+        }
+    }
+    None
+}
+
+/// Same as `find_invalid_index`, but counting the numbers in the window to look up the
+/// complement of each instead of trying all pairs.
+fn find_invalid_index_counting(numbers: &[u64], preamble_length: usize) -> Option<usize> {
+    let mut window: HashMap<u64, usize> = HashMap::new();
+    for number in numbers.iter().take(preamble_length) {
+        *window.entry(*number).or_default() += 1;
+    }
+    for (i, &number) in numbers.iter().enumerate().skip(preamble_length) {
+        let valid = window.keys().any(|&a| match number.checked_sub(a) {
+            Some(b) if b == a => window[&a] > 1,
+            Some(b) => window.contains_key(&b),
+            None => false,
+        });
+        if !valid {
+            return Some(i);
+        }
+        let leaving = numbers[i - preamble_length];
+        if let Some(count) = window.get_mut(&leaving) {
+            *count -= 1;
+            if *count == 0 {
+                window.remove(&leaving);
+            }
+        }
+        *window.entry(number).or_default() += 1;
+    }
+    None
+}
+
+/// Same as `find_contiguous_set`, but searching the prefix sums for the end of the set.
+fn find_contiguous_set_prefix_sums(numbers: &[u64], index_p1: usize) -> Option<&[u64]> {
+    let invalid_number = numbers[index_p1];
+    let mut prefix_sums = vec![0];
+    for number in &numbers[..index_p1] {
+        prefix_sums.push(prefix_sums[prefix_sums.len() - 1] + number);
+    }
+    for start in 0..index_p1.saturating_sub(1) {
+        let wanted = prefix_sums[start] + invalid_number;
+        let later = &prefix_sums[start + 1..];
+        let end = start + 1 + later.partition_point(|&sum| sum < wanted);
+        if end < prefix_sums.len() && prefix_sums[end] == wanted {
+            return Some(&numbers[start..end]);
+        }
+    }
+    None
+}
+
+fn sum_of_extremes(contiguous_set: &[u64]) -> u64 {
+    let smallest = contiguous_set.iter().min().unwrap();
+    let largest = contiguous_set.iter().max().unwrap();
+    smallest + largest
+}
+
+pub struct Day09 {
+    preamble_length: usize,
+}
+
+impl Default for Day09 {
+    fn default() -> Self {
+        Self {
+            preamble_length: PREAMBLE_LENGTH,
+        }
+    }
+}
+
+impl Solution for Day09 {
+    type Input = Vec<u64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_numbers(input)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_lines(input, parse_number)
+    }
+
+    fn parameters(&mut self) -> Vec<(&'static str, &mut dyn Parameter)> {
+        vec![("preamble", &mut self.preamble_length)]
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> Answer {
+        match find_invalid_index(numbers, self.preamble_length) {
+            Some(index_p1) => Answer::new(
+                numbers[index_p1],
+                format!(
+                    "Invalid number: {} not sum of previous {} numbers.",
+                    numbers[index_p1], self.preamble_length
+                ),
+            )
+            .with("index", index_p1),
+            None => Answer::empty("No invalid number found."),
+        }
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> Answer {
+        let contiguous_set = find_invalid_index(numbers, self.preamble_length)
+            .and_then(|index_p1| find_contiguous_set(numbers, index_p1));
+        let contiguous_set = match contiguous_set {
+            Some(contiguous_set) => contiguous_set,
+            None => return Answer::empty("No contiguous set found."),
+        };
+        let mut numbers_of_set = Vec::from(contiguous_set);
+        numbers_of_set.sort();
+        let smallest = numbers_of_set[0];
+        let largest = numbers_of_set[numbers_of_set.len() - 1];
+        Answer::new(
+            smallest + largest,
+            format!(
+                "Sum of smallest ({}) and largest ({}) number of contiguous set: {}",
+                smallest,
+                largest,
+                smallest + largest
+            ),
+        )
+        .with("smallest", smallest)
+        .with("largest", largest)
+        .with("set_length", contiguous_set.len())
+    }
+
+    fn variants(&self) -> Vec<Variant<Self>> {
+        vec![
+            Variant {
+                name: "counting",
+                part: Part::One,
+                solve: |day, numbers| day.part1_counting(numbers),
+            },
+            Variant {
+                name: "prefix_sums",
+                part: Part::Two,
+                solve: |day, numbers| day.part2_prefix_sums(numbers),
+            },
+        ]
+    }
+}
+
+impl Day09 {
+    fn part1_counting(&self, numbers: &[u64]) -> Answer {
+        match find_invalid_index_counting(numbers, self.preamble_length) {
+            Some(index_p1) => Answer::new(numbers[index_p1], "Invalid number"),
+            None => Answer::empty("No invalid number found."),
+        }
+    }
+
+    fn part2_prefix_sums(&self, numbers: &[u64]) -> Answer {
+        let contiguous_set = find_invalid_index(numbers, self.preamble_length)
+            .and_then(|index_p1| find_contiguous_set_prefix_sums(numbers, index_p1));
+        match contiguous_set {
+            Some(contiguous_set) => {
+                let sum = sum_of_extremes(contiguous_set);
+                Answer::new(sum, format!("Sum of smallest and largest number: {}", sum))
+            }
+            None => Answer::empty("No contiguous set found."),
+        }
+    }
+}
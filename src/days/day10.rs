@@ -0,0 +1,109 @@
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::input::{self, Line};
+use crate::parameters::Parameter;
+use crate::solution::Solution;
+use std::collections::HashMap;
+
+const MAXIMUM_DIFFERENCE: u32 = 3;
+
+fn parse_number(line: Line) -> Result<u32, ParseError> {
+    line.parse(line.text, "number")
+}
+
+fn parse_numbers(input: &str) -> Result<Vec<u32>, ParseError> {
+    input::parse_lines(input, parse_number)
+}
+
+fn numbers_to_jolts(mut numbers: Vec<u32>, maximum_difference: u32) -> Vec<u32> {
+    numbers.push(0);
+    numbers.sort();
+    numbers.push(numbers[numbers.len() - 1] + maximum_difference);
+    numbers
+}
+
+fn number_of_valid_arrangements(
+    start_value: u32,
+    jolts: &[u32],
+    maximum_difference: u32,
+    cache: &mut HashMap<u32, u64>,
+) -> u64 {
+    if cache.contains_key(&start_value) {
+        return cache[&start_value];
+    }
+    let mut result = 0;
+    for (i, jolt) in jolts.iter().enumerate() {
+        if *jolt > start_value + maximum_difference {
+            break;
+        }
+        if i == jolts.len() - 1 {
+            result += 1;
+        } else {
+            result +=
+                number_of_valid_arrangements(*jolt, &jolts[i + 1..], maximum_difference, cache);
+        }
+    }
+    cache.insert(start_value, result);
+    result
+}
+
+pub struct Day10 {
+    maximum_difference: u32,
+}
+
+impl Default for Day10 {
+    fn default() -> Self {
+        Self {
+            maximum_difference: MAXIMUM_DIFFERENCE,
+        }
+    }
+}
+
+impl Solution for Day10 {
+    type Input = Vec<u32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_numbers(input).map(|numbers| numbers_to_jolts(numbers, self.maximum_difference))
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_lines(input, parse_number)
+    }
+
+    fn parameters(&mut self) -> Vec<(&'static str, &mut dyn Parameter)> {
+        vec![("maximum_difference", &mut self.maximum_difference)]
+    }
+
+    fn part1(&self, jolts: &Self::Input) -> Answer {
+        let differences = jolts
+            .windows(2)
+            .map(|window| window[1] - window[0])
+            .collect::<Vec<u32>>();
+        let diff_of_1 = differences.iter().filter(|&d| *d == 1).count();
+        let diff_of_3 = differences.iter().filter(|&d| *d == 3).count();
+        Answer::new(
+            diff_of_1 * diff_of_3,
+            format!(
+                "There {} differences of 1 jolt and {} differences of 3 jolts, the product of these is: {}",
+                diff_of_1,
+                diff_of_3,
+                diff_of_1 * diff_of_3
+            ),
+        )
+        .with("differences_of_1", diff_of_1)
+        .with("differences_of_3", diff_of_3)
+    }
+
+    fn part2(&self, jolts: &Self::Input) -> Answer {
+        let number_of_arrangements = number_of_valid_arrangements(
+            0,
+            &jolts[1..],
+            self.maximum_difference,
+            &mut HashMap::new(),
+        );
+        Answer::new(
+            number_of_arrangements,
+            format!("Number of valid arrangements: {}", number_of_arrangements),
+        )
+    }
+}
@@ -0,0 +1,204 @@
+use crate::animation::{Frame, Simulation};
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::ParseError;
+use crate::grid::{Direction, Grid, Position, ALL_DIRECTIONS};
+use crate::solution::Solution;
+use crate::trace::{self, Level};
+use crate::Part;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Seat {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum GridMode {
+    Adjacency,
+    Visibility,
+}
+
+#[derive(Debug, Clone)]
+pub struct Layout {
+    mode: GridMode,
+    seats: Grid<Seat>,
+}
+
+impl Layout {
+    fn new(seats: Grid<Seat>) -> Self {
+        Self {
+            mode: GridMode::Adjacency,
+            seats,
+        }
+    }
+
+    fn set_mode(&mut self, mode: GridMode) {
+        self.mode = mode;
+    }
+
+    fn next_round(&mut self) -> bool {
+        let occupied_threshold = match self.mode {
+            GridMode::Adjacency => 4,
+            GridMode::Visibility => 5,
+        };
+        let seats = Grid::from_fn(
+            self.seats.width(),
+            self.seats.height(),
+            |position| match self.seats[position] {
+                Seat::Empty if self.number_of_occupied_adjacent(position) == 0 => Seat::Occupied,
+                Seat::Occupied
+                    if self.number_of_occupied_adjacent(position) >= occupied_threshold =>
+                {
+                    Seat::Empty
+                }
+                seat => seat,
+            },
+        );
+        let result = seats != self.seats;
+        self.seats = seats;
+        result
+    }
+
+    /// Seats drawn as in the puzzle text.
+    fn render(&self) -> String {
+        self.seats.render(|&seat| match seat {
+            Seat::Floor => '.',
+            Seat::Empty => 'L',
+            Seat::Occupied => '#',
+        })
+    }
+
+    fn occupied_seats(&self) -> usize {
+        self.seats
+            .iter()
+            .filter(|&&seat| seat == Seat::Occupied)
+            .count()
+    }
+
+    fn number_of_occupied_adjacent(&self, position: Position) -> usize {
+        ALL_DIRECTIONS
+            .iter()
+            .filter(|&&direction| self.check_occupied_direction(position, direction))
+            .count()
+    }
+
+    fn check_occupied_direction(&self, position: Position, direction: Direction) -> bool {
+        let seat = match self.mode {
+            GridMode::Adjacency => self
+                .seats
+                .step(position, direction)
+                .map(|position| self.seats[position]),
+            GridMode::Visibility => self
+                .seats
+                .ray(position, direction)
+                .map(|position| self.seats[position])
+                .find(|&seat| seat != Seat::Floor),
+        };
+        seat == Some(Seat::Occupied)
+    }
+}
+
+/// Rounds of seating people, ending once no seat changes anymore.
+struct SeatingSimulation {
+    layout: Layout,
+    round: usize,
+}
+
+impl Simulation for SeatingSimulation {
+    fn frame(&self) -> Frame {
+        let picture = self.layout.render();
+        Frame {
+            generation: self.round,
+            live: self.layout.occupied_seats(),
+            rows: picture.lines().map(str::to_owned).collect(),
+            hexagonal: false,
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        let changed = self.layout.next_round();
+        if changed {
+            self.round += 1;
+        }
+        changed
+    }
+}
+
+fn parse_seat(c: char) -> Option<Seat> {
+    match c {
+        '.' => Some(Seat::Floor),
+        'L' => Some(Seat::Empty),
+        '#' => Some(Seat::Occupied),
+        _ => None,
+    }
+}
+
+fn parse_layout(input: &str) -> Result<Layout, ParseError> {
+    let seats = Grid::parse(input, parse_seat)?;
+    Ok(Layout::new(seats))
+}
+
+fn run_simulation(layout: &mut Layout) -> Answer {
+    let mut round = 0;
+    trace::walkthrough(layout.render());
+    while layout.next_round() {
+        if cancel::cancelled() {
+            return Answer::empty(cancel::CANCELLED);
+        }
+        round += 1;
+        trace::event(
+            "day11",
+            "round",
+            &[("round", &round), ("occupied", &layout.occupied_seats())],
+        );
+        if trace::enabled(Level::Walkthrough) {
+            trace::walkthrough(layout.render());
+        }
+    }
+    Answer::new(
+        layout.occupied_seats(),
+        format!(
+            "Number of occupied seats after round {}: {}",
+            round,
+            layout.occupied_seats()
+        ),
+    )
+    .with("rounds", round)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Layout;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_layout(input)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        Grid::check(input, parse_seat)
+    }
+
+    fn part1(&self, layout: &Self::Input) -> Answer {
+        let mut layout = layout.clone();
+        layout.set_mode(GridMode::Adjacency);
+        run_simulation(&mut layout)
+    }
+
+    fn part2(&self, layout: &Self::Input) -> Answer {
+        let mut layout = layout.clone();
+        layout.set_mode(GridMode::Visibility);
+        run_simulation(&mut layout)
+    }
+
+    fn simulation(&self, part: Part, layout: &Self::Input) -> Option<Box<dyn Simulation>> {
+        let mut layout = layout.clone();
+        layout.set_mode(match part {
+            Part::One => GridMode::Adjacency,
+            Part::Two => GridMode::Visibility,
+        });
+        Some(Box::new(SeatingSimulation { layout, round: 0 }))
+    }
+}
@@ -0,0 +1,120 @@
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::input;
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub struct Notes {
+    timestamp: u32,
+    ids: Vec<Option<u32>>,
+}
+
+fn parse_notes(input: &str) -> Result<Notes, ParseError> {
+    let mut lines = input::lines(input);
+    let line = lines
+        .next()
+        .ok_or_else(|| input::end_of_input(input, "missing earliest timestamp"))?;
+    let timestamp = line.parse(line.text, "timestamp")?;
+    let line = lines
+        .next()
+        .ok_or_else(|| input::end_of_input(input, "missing bus IDs"))?;
+    let ids = line
+        .text
+        .split(',')
+        .map(|id| match id {
+            "x" => Ok(None),
+            _ => match line.parse(id, "bus ID")? {
+                0 => Err(line.error_at(id, "bus ID must not be 0")),
+                id => Ok(Some(id)),
+            },
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if ids.iter().all(Option::is_none) {
+        return Err(line.error("no bus in service"));
+    }
+    if let Some(line) = lines.find(|line| !line.text.is_empty()) {
+        return Err(line.error("unexpected line after bus IDs"));
+    }
+    Ok(Notes { timestamp, ids })
+}
+
+fn find_earliest_bus(notes: &Notes) -> Answer {
+    let timestamp = notes.timestamp;
+    let ids = notes.ids.iter().flatten().copied();
+    let (id, wait_time) = {
+        let mut minimum_id = None;
+        let mut minimum_wait_time = None;
+        for id in ids {
+            let wait_time = id - (timestamp % id);
+            if let Some(old_minimum_wait_time) = minimum_wait_time {
+                if wait_time < old_minimum_wait_time {
+                    minimum_wait_time = Some(wait_time);
+                    minimum_id = Some(id);
+                }
+            } else {
+                minimum_wait_time = Some(wait_time);
+                minimum_id = Some(id);
+            }
+        }
+        (minimum_id.unwrap(), minimum_wait_time.unwrap())
+    };
+    Answer::new(
+        id * wait_time,
+        format!(
+            "Bus ID {} departs after waiting {} minutes, product: {}",
+            id,
+            wait_time,
+            id * wait_time
+        ),
+    )
+    .with("bus_id", id)
+    .with("wait_time", wait_time)
+}
+
+#[derive(Debug)]
+struct IndexedId {
+    index: usize,
+    id: u32,
+}
+
+fn find_position_timestamp(time: u64, lcm: u64, remaining_ids: &[IndexedId]) -> u64 {
+    if remaining_ids.is_empty() {
+        return time;
+    }
+    // Valid timestamps for the previous buses repeat with the LCM of all previous IDs
+    // Advance time until a valid timestamp for the current bus is found and extend LCM.
+    let mut time = time;
+    while !(time + remaining_ids[0].index as u64).is_multiple_of(remaining_ids[0].id as u64) {
+        time += lcm;
+    }
+    let lcm = num_integer::lcm(lcm, remaining_ids[0].id as u64);
+    find_position_timestamp(time, lcm, &remaining_ids[1..])
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Notes;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_notes(input)
+    }
+
+    fn part1(&self, notes: &Self::Input) -> Answer {
+        find_earliest_bus(notes)
+    }
+
+    fn part2(&self, notes: &Self::Input) -> Answer {
+        let indexed_ids: Vec<IndexedId> = notes
+            .ids
+            .iter()
+            .enumerate()
+            .filter_map(|(index, id)| id.map(|id| IndexedId { index, id }))
+            .collect();
+        let timestamp = find_position_timestamp(0, 1, &indexed_ids);
+        Answer::new(
+            timestamp,
+            format!("Timestamp with offset departure: {}", timestamp),
+        )
+    }
+}
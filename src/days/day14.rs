@@ -0,0 +1,176 @@
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::input::{self, Line};
+use crate::solution::Solution;
+use regex::Regex;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub enum Command {
+    Mask(String),
+    Mem((u64, u64)),
+}
+
+impl Command {
+    fn new(line: Line) -> Result<Self, ParseError> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^mem\[(\d+)\] = (\d+)$").unwrap();
+        }
+        let (target, argument) = line.split_once(" = ")?;
+        if target == "mask" {
+            if argument.len() != 36 {
+                return Err(line.error_at(
+                    argument,
+                    format!("mask has {} bits, expected 36", argument.chars().count()),
+                ));
+            }
+            line.check_chars(argument, "mask bit", |c| matches!(c, '0' | '1' | 'X'))?;
+            Ok(Self::Mask(argument.to_owned()))
+        } else if let Some(captures) = RE.captures(line.text) {
+            let address = line.parse(captures.get(1).unwrap().as_str(), "address")?;
+            let value = line.parse(captures.get(2).unwrap().as_str(), "value")?;
+            Ok(Self::Mem((address, value)))
+        } else {
+            Err(line.error("expected 'mask = <mask>' or 'mem[<address>] = <value>'"))
+        }
+    }
+}
+
+fn parse_commands(input: &str) -> Result<Vec<Command>, ParseError> {
+    input::parse_lines(input, Command::new)
+}
+
+#[derive(Debug)]
+struct ValueMask {
+    and_mask: u64,
+    or_mask: u64,
+}
+
+impl ValueMask {
+    fn default() -> Self {
+        Self {
+            and_mask: u64::MAX,
+            or_mask: 0,
+        }
+    }
+    fn new(mask: &str) -> Self {
+        let mut and_mask = u64::MAX;
+        let mut or_mask = 0;
+        for (i, c) in mask.chars().rev().enumerate() {
+            match c {
+                '0' => and_mask &= !(1 << i),
+                '1' => or_mask |= 1 << i,
+                _ => (),
+            }
+        }
+        Self { and_mask, or_mask }
+    }
+    fn apply(&self, value: u64) -> u64 {
+        (value & self.and_mask) | self.or_mask
+    }
+}
+
+fn run_program_v1(commands: &[Command]) -> u64 {
+    let mut mask = ValueMask::default();
+    let mut memory = HashMap::new();
+    for command in commands {
+        match command {
+            Command::Mask(m) => {
+                mask = ValueMask::new(m);
+            }
+            Command::Mem((addr, val)) => {
+                memory.insert(addr, mask.apply(*val));
+            }
+        }
+    }
+    memory.values().sum()
+}
+
+#[derive(Debug)]
+struct AddressMask {
+    and_mask: u64,
+    or_mask: u64,
+    offsets: Vec<u64>,
+}
+
+impl AddressMask {
+    fn default() -> Self {
+        Self {
+            and_mask: u64::MAX,
+            or_mask: 0,
+            offsets: Vec::new(),
+        }
+    }
+    fn new(mask: &str) -> Self {
+        let mut and_mask = 0;
+        let mut or_mask = 0;
+        let mut floating_bits = Vec::new();
+        for (i, c) in mask.chars().rev().enumerate() {
+            match c {
+                '0' => and_mask |= 1 << i,
+                '1' => or_mask |= 1 << i,
+                _ => floating_bits.push(i),
+            }
+        }
+        let mut offsets = vec![0];
+        for floating_bit in floating_bits {
+            for i in 0..offsets.len() {
+                offsets.push(offsets[i] + (1 << floating_bit));
+            }
+        }
+        Self {
+            and_mask,
+            or_mask,
+            offsets,
+        }
+    }
+    fn apply(&self, address: u64) -> Vec<u64> {
+        let base_address = (address & self.and_mask) | self.or_mask;
+        self.offsets
+            .iter()
+            .map(|offset| base_address + offset)
+            .collect()
+    }
+}
+
+fn run_program_v2(commands: &[Command]) -> u64 {
+    let mut mask = AddressMask::default();
+    let mut memory = HashMap::new();
+    for command in commands {
+        match command {
+            Command::Mask(m) => {
+                mask = AddressMask::new(m);
+            }
+            Command::Mem((addr, val)) => {
+                for address in mask.apply(*addr) {
+                    memory.insert(address, *val);
+                }
+            }
+        }
+    }
+    memory.values().sum()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Command>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_commands(input)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_lines(input, Command::new)
+    }
+
+    fn part1(&self, commands: &Self::Input) -> Answer {
+        let sum = run_program_v1(commands);
+        Answer::new(sum, format!("Sum of memory: {}", sum))
+    }
+
+    fn part2(&self, commands: &Self::Input) -> Answer {
+        let sum = run_program_v2(commands);
+        Answer::new(sum, format!("Sum of memory: {}", sum))
+    }
+}
@@ -0,0 +1,79 @@
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::ParseError;
+use crate::input;
+use crate::solution::Solution;
+use crate::trace;
+use std::collections::HashMap;
+
+/// Number spoken in the last round, `None` if solving was cancelled before.
+fn calculate_number_for_round(starting_numbers: &[u32], last_round: u32) -> Option<u32> {
+    let mut round = 0;
+    let mut history = HashMap::new();
+    for number in starting_numbers {
+        round += 1;
+        history.insert(*number, round);
+    }
+    let mut number = starting_numbers[starting_numbers.len() - 1];
+    while round < last_round {
+        if cancel::cancelled_at(round.into()) {
+            return None;
+        }
+        number = match history.insert(number, round) {
+            Some(spoken_in_round) => round - spoken_in_round,
+            None => 0,
+        };
+        round += 1;
+        if round.is_multiple_of(trace::STEPS_PER_PROGRESS) {
+            trace::event(
+                "day15",
+                "progress",
+                &[("round", &round), ("number", &number)],
+            );
+        }
+    }
+    Some(number)
+}
+
+fn parse_starting_numbers(input: &str) -> Result<Vec<u32>, ParseError> {
+    let mut lines = input::lines(input).filter(|line| !line.text.trim().is_empty());
+    let line = lines
+        .next()
+        .ok_or_else(|| input::end_of_input(input, "missing starting numbers"))?;
+    if let Some(line) = lines.next() {
+        return Err(line.error("unexpected line after starting numbers"));
+    }
+    line.text
+        .trim()
+        .split(',')
+        .map(|number| line.parse(number, "starting number"))
+        .collect()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<u32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_starting_numbers(input)
+    }
+
+    fn part1(&self, starting_numbers: &Self::Input) -> Answer {
+        let round = 2020;
+        let number = match calculate_number_for_round(starting_numbers, round) {
+            Some(number) => number,
+            None => return Answer::empty(cancel::CANCELLED),
+        };
+        Answer::new(number, format!("Round {}: {}", round, number)).with("round", round)
+    }
+
+    fn part2(&self, starting_numbers: &Self::Input) -> Answer {
+        let round = 30000000;
+        let number = match calculate_number_for_round(starting_numbers, round) {
+            Some(number) => number,
+            None => return Answer::empty(cancel::CANCELLED),
+        };
+        Answer::new(number, format!("Round {}: {}", round, number)).with("round", round)
+    }
+}
@@ -0,0 +1,228 @@
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::ParseError;
+use crate::input::{self, Line};
+use crate::solution::Solution;
+use regex::Regex;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Range {
+    minimum: u32,
+    maximum: u32,
+}
+
+impl Range {
+    fn is_valid(&self, value: u32) -> bool {
+        value >= self.minimum && value <= self.maximum
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Rule {
+    name: String,
+    ranges: Vec<Range>,
+}
+
+impl Rule {
+    fn is_valid(&self, value: u32) -> bool {
+        self.ranges.iter().any(|range| range.is_valid(value))
+    }
+}
+
+#[derive(Debug)]
+pub struct Data {
+    rules: Vec<Rule>,
+    your_ticket: Vec<u32>,
+    nearby_tickets: Vec<Vec<u32>>,
+}
+
+fn parse_rule(line: Line) -> Result<Rule, ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(r"^([^:]+): (\d+)-(\d+) or (\d+)-(\d+)$").unwrap();
+    }
+    let captures = RE
+        .captures(line.text)
+        .ok_or_else(|| line.error("expected '<field>: <min>-<max> or <min>-<max>'"))?;
+    let bound = |index| line.parse(captures.get(index).unwrap().as_str(), "bound");
+    let name = captures[1].to_owned();
+    let ranges = vec![
+        Range {
+            minimum: bound(2)?,
+            maximum: bound(3)?,
+        },
+        Range {
+            minimum: bound(4)?,
+            maximum: bound(5)?,
+        },
+    ];
+    Ok(Rule { name, ranges })
+}
+
+fn parse_ticket(line: Line, number_of_fields: usize) -> Result<Vec<u32>, ParseError> {
+    let ticket = line
+        .text
+        .split(',')
+        .map(|value| line.parse(value, "value"))
+        .collect::<Result<Vec<_>, _>>()?;
+    if ticket.len() != number_of_fields {
+        return Err(line.error(format!(
+            "ticket has {} values, expected {}",
+            ticket.len(),
+            number_of_fields
+        )));
+    }
+    Ok(ticket)
+}
+
+/// Lines of a section following its header, recording an error if it is missing.
+fn read_section<'a>(
+    input: &str,
+    block: Option<Vec<Line<'a>>>,
+    header: &str,
+    errors: &mut Vec<ParseError>,
+) -> Option<(Line<'a>, Vec<Line<'a>>)> {
+    match block {
+        Some(block) => {
+            if block[0].text != header {
+                errors.push(block[0].error(format!("expected '{}'", header)));
+            }
+            Some((block[0], block[1..].to_vec()))
+        }
+        None => {
+            errors.push(input::end_of_input(
+                input,
+                format!("missing '{}' section", header),
+            ));
+            None
+        }
+    }
+}
+
+fn read_data(input: &str, errors: &mut Vec<ParseError>) -> Data {
+    let mut blocks = input::blocks(input).into_iter();
+    let rule_lines = blocks.next().unwrap_or_else(|| {
+        errors.push(input::end_of_input(input, "missing rules"));
+        Vec::new()
+    });
+    let number_of_fields = rule_lines.len();
+    let rules = rule_lines
+        .into_iter()
+        .filter_map(|line| input::record(errors, parse_rule(line)))
+        .collect();
+    let your_ticket = match read_section(input, blocks.next(), "your ticket:", errors) {
+        Some((header, lines)) => {
+            if lines.is_empty() {
+                errors.push(header.error("missing ticket"));
+            }
+            if let Some(line) = lines.get(1) {
+                errors.push(line.error("unexpected second ticket"));
+            }
+            lines
+                .first()
+                .and_then(|&line| input::record(errors, parse_ticket(line, number_of_fields)))
+        }
+        None => None,
+    };
+    let nearby_tickets = read_section(input, blocks.next(), "nearby tickets:", errors)
+        .map(|(_, lines)| lines)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|line| input::record(errors, parse_ticket(line, number_of_fields)))
+        .collect();
+    if let Some(block) = blocks.next() {
+        errors.push(block[0].error("unexpected line after nearby tickets"));
+    }
+    Data {
+        rules,
+        your_ticket: your_ticket.unwrap_or_default(),
+        nearby_tickets,
+    }
+}
+
+fn is_valid(rules: &[Rule], value: u32) -> bool {
+    rules.iter().any(|rule| rule.is_valid(value))
+}
+
+/// Maps the rule names to the fields of the tickets, or explains why some rules could not be
+/// mapped.
+fn map_fields(data: &Data) -> Result<HashMap<String, usize>, &'static str> {
+    let valid_tickets: Vec<_> = data
+        .nearby_tickets
+        .iter()
+        .filter(|ticket| ticket.iter().all(|&value| is_valid(&data.rules, value)))
+        .cloned()
+        .collect();
+
+    let mut field_mapping = HashMap::new();
+    let number_of_fields = data.rules.len();
+    let mut remaining_rules = data.rules.clone();
+    let mut remaining_fields: Vec<_> = (0..number_of_fields).collect();
+    while field_mapping.len() < number_of_fields {
+        if cancel::cancelled() {
+            return Err("Cancelled before all fields were mapped.");
+        }
+        let mapped = field_mapping.len();
+        for field in remaining_fields.clone() {
+            let field_values: Vec<_> = valid_tickets.iter().map(|ticket| ticket[field]).collect();
+            let valid_rules: Vec<_> = remaining_rules
+                .iter()
+                .filter(|rule| field_values.iter().all(|&value| rule.is_valid(value)))
+                .collect();
+            if valid_rules.len() == 1 {
+                let rule = valid_rules[0].clone();
+                field_mapping.insert(rule.name.to_owned(), field);
+                remaining_rules.remove(remaining_rules.iter().position(|r| *r == rule).unwrap());
+                remaining_fields.remove(remaining_fields.iter().position(|f| *f == field).unwrap());
+            }
+        }
+        if field_mapping.len() == mapped {
+            return Err("Ambiguous rules, no remaining field matches exactly one rule.");
+        }
+    }
+    Ok(field_mapping)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Data;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input::parse_with(input, read_data)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_with(input, read_data)
+    }
+
+    fn part1(&self, data: &Self::Input) -> Answer {
+        let invalid_values_sum: u32 = data
+            .nearby_tickets
+            .iter()
+            .flatten()
+            .filter(|&&value| !is_valid(&data.rules, value))
+            .sum();
+        Answer::new(
+            invalid_values_sum,
+            format!("Sum of invalid values: {}", invalid_values_sum),
+        )
+    }
+
+    fn part2(&self, data: &Self::Input) -> Answer {
+        let field_mapping = match map_fields(data) {
+            Ok(field_mapping) => field_mapping,
+            Err(reason) => return Answer::empty(reason),
+        };
+        let departure_product: u64 = field_mapping
+            .iter()
+            .filter(|(k, _)| k.starts_with("departure"))
+            .map(|(_, v)| v)
+            .map(|&f| data.your_ticket[f] as u64)
+            .product();
+        Answer::new(
+            departure_product,
+            format!("Product of departure fields: {}", departure_product),
+        )
+    }
+}
@@ -0,0 +1,398 @@
+use crate::animation::{Frame, Simulation};
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::parameters::Parameter;
+use crate::solution::Solution;
+use crate::trace::{self, Level};
+use crate::Part;
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::Range;
+
+const NUMBER_OF_CYCLES: u32 = 6;
+
+#[derive(Clone, Debug)]
+enum Dimensions {
+    Three,
+    Four,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Coordinate {
+    x: i32,
+    y: i32,
+    z: i32,
+    w: i32,
+}
+
+impl Coordinate {
+    fn new(x: i32, y: i32, z: i32, w: i32) -> Self {
+        Self { x, y, z, w }
+    }
+    fn neighbor_extent(&self) -> Extent {
+        Extent {
+            x: Range {
+                start: self.x - 1,
+                end: self.x + 2,
+            },
+            y: Range {
+                start: self.y - 1,
+                end: self.y + 2,
+            },
+            z: Range {
+                start: self.z - 1,
+                end: self.z + 2,
+            },
+            w: Range {
+                start: self.w - 1,
+                end: self.w + 2,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Extent {
+    x: Range<i32>,
+    y: Range<i32>,
+    z: Range<i32>,
+    w: Range<i32>,
+}
+
+impl Extent {
+    fn new_empty() -> Self {
+        // initialize with empty ranges
+        Self {
+            x: Range { start: 0, end: 0 },
+            y: Range { start: 0, end: 0 },
+            z: Range { start: 0, end: 0 },
+            w: Range { start: 0, end: 0 },
+        }
+    }
+
+    fn include_coord(&self, coord: &Coordinate) -> Extent {
+        if self.x.is_empty() {
+            // set range to coordinate
+            Extent {
+                x: Range {
+                    start: coord.x,
+                    end: coord.x + 1,
+                },
+                y: Range {
+                    start: coord.y,
+                    end: coord.y + 1,
+                },
+                z: Range {
+                    start: coord.z,
+                    end: coord.z + 1,
+                },
+                w: Range {
+                    start: coord.w,
+                    end: coord.w + 1,
+                },
+            }
+        } else {
+            // extend range to include coordinate
+            Extent {
+                x: Range {
+                    start: i32::min(self.x.start, coord.x),
+                    end: i32::max(self.x.end, coord.x + 1),
+                },
+                y: Range {
+                    start: i32::min(self.y.start, coord.y),
+                    end: i32::max(self.y.end, coord.y + 1),
+                },
+                z: Range {
+                    start: i32::min(self.z.start, coord.z),
+                    end: i32::max(self.z.end, coord.z + 1),
+                },
+                w: Range {
+                    start: i32::min(self.w.start, coord.w),
+                    end: i32::max(self.w.end, coord.w + 1),
+                },
+            }
+        }
+    }
+    fn extend(&self, amount: i32, dimensions: &Dimensions) -> Extent {
+        // extend in x,y,z and optionally w direction
+        let w = match dimensions {
+            Dimensions::Three => self.w.clone(),
+            Dimensions::Four => Range {
+                start: self.w.start - amount,
+                end: self.w.end + amount,
+            },
+        };
+        Extent {
+            x: Range {
+                start: self.x.start - amount,
+                end: self.x.end + amount,
+            },
+            y: Range {
+                start: self.y.start - amount,
+                end: self.y.end + amount,
+            },
+            z: Range {
+                start: self.z.start - amount,
+                end: self.z.end + amount,
+            },
+            w,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct State {
+    active_cells: HashSet<Coordinate>,
+    dimensions: Dimensions,
+}
+
+impl State {
+    fn new(slice: &Grid<bool>) -> Self {
+        let active_cells = slice
+            .positions()
+            .filter(|&position| slice[position])
+            .map(|(x, y)| Coordinate::new(x as i32, y as i32, 0, 0))
+            .collect();
+        Self {
+            active_cells,
+            dimensions: Dimensions::Three,
+        }
+    }
+    fn extent(&self) -> Extent {
+        self.active_cells
+            .iter()
+            .fold(Extent::new_empty(), |extent, cell| {
+                extent.include_coord(cell)
+            })
+    }
+    fn number_of_active_cells(&self) -> usize {
+        self.active_cells.len()
+    }
+    /// Runs the given number of cycles, false if cancelled before.
+    fn run_simulation(&mut self, number_of_cycles: u32, dimensions: &Dimensions) -> bool {
+        self.dimensions = dimensions.clone();
+        trace::walkthrough(format_args!("Before any cycles:\n\n{}", self));
+        for cycle in 1..=number_of_cycles {
+            if cancel::cancelled() {
+                return false;
+            }
+            self.simulation_step();
+            trace::event(
+                "day17",
+                "cycle",
+                &[
+                    ("cycle", &cycle),
+                    ("active", &self.number_of_active_cells()),
+                ],
+            );
+            if trace::enabled(Level::Walkthrough) {
+                let plural = if cycle == 1 { "" } else { "s" };
+                trace::walkthrough(format_args!("After {} cycle{}:\n\n{}", cycle, plural, self));
+            }
+        }
+        true
+    }
+    fn simulation_step(&mut self) {
+        let mut next_active_cells = HashSet::new();
+        let possible_extent = self.extent().extend(1, &self.dimensions);
+        for w in possible_extent.w {
+            for z in possible_extent.z.clone() {
+                for y in possible_extent.y.clone() {
+                    for x in possible_extent.x.clone() {
+                        let coord = Coordinate::new(x, y, z, w);
+                        let num_active_neighbors = self.number_of_active_neighbors(&coord);
+                        let next_active = if self.is_active(&coord) {
+                            (2..=3).contains(&num_active_neighbors)
+                        } else {
+                            num_active_neighbors == 3
+                        };
+                        if next_active {
+                            next_active_cells.insert(coord);
+                        }
+                    }
+                }
+            }
+        }
+        self.active_cells = next_active_cells;
+    }
+    fn is_active(&self, coord: &Coordinate) -> bool {
+        self.active_cells.contains(coord)
+    }
+    fn number_of_active_neighbors(&self, coord: &Coordinate) -> u8 {
+        let neighbor_extent = coord.neighbor_extent();
+        let mut result = 0;
+        for w in neighbor_extent.w {
+            for z in neighbor_extent.z.clone() {
+                for y in neighbor_extent.y.clone() {
+                    for x in neighbor_extent.x.clone() {
+                        let neighbor = Coordinate::new(x, y, z, w);
+                        if neighbor != *coord && self.is_active(&neighbor) {
+                            result += 1;
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+impl State {
+    fn slice(&self, extent: &Extent, z: i32, w: i32) -> Grid<bool> {
+        Grid::from_fn(extent.x.len(), extent.y.len(), |(x, y)| {
+            let x = extent.x.start + x as i32;
+            let y = extent.y.start + y as i32;
+            self.is_active(&Coordinate::new(x, y, z, w))
+        })
+    }
+}
+
+impl std::fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let extent = self.extent();
+        for w in extent.w.clone() {
+            for z in extent.z.clone() {
+                match self.dimensions {
+                    Dimensions::Three => writeln!(f, "z={}", z)?,
+                    Dimensions::Four => writeln!(f, "z={}, w={}", z, w)?,
+                }
+                let slice = self.slice(&extent, z, w);
+                writeln!(
+                    f,
+                    "{}",
+                    slice.render(|&active| if active { '#' } else { '.' })
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Boot process of the pocket dimension, ending after the configured number of cycles.
+struct CubeSimulation {
+    state: State,
+    cycle: u32,
+    number_of_cycles: u32,
+}
+
+impl Simulation for CubeSimulation {
+    fn frame(&self) -> Frame {
+        let picture = self.state.to_string();
+        Frame {
+            generation: self.cycle as usize,
+            live: self.state.number_of_active_cells(),
+            rows: picture.trim_end().lines().map(str::to_owned).collect(),
+            hexagonal: false,
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if self.cycle == self.number_of_cycles {
+            return false;
+        }
+        self.state.simulation_step();
+        self.cycle += 1;
+        true
+    }
+}
+
+fn parse_cube(c: char) -> Option<bool> {
+    match c {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    }
+}
+
+fn parse_state(input: &str) -> Result<State, ParseError> {
+    let slice = Grid::parse(input, parse_cube)?;
+    Ok(State::new(&slice))
+}
+
+/// Active cells after the given number of cycles, `None` if cancelled before.
+fn run_simulation(
+    initial_state: &State,
+    number_of_cycles: u32,
+    dimensions: &Dimensions,
+) -> Option<usize> {
+    let mut state = initial_state.clone();
+    match state.run_simulation(number_of_cycles, dimensions) {
+        true => Some(state.number_of_active_cells()),
+        false => None,
+    }
+}
+
+pub struct Day17 {
+    number_of_cycles: u32,
+}
+
+impl Default for Day17 {
+    fn default() -> Self {
+        Self {
+            number_of_cycles: NUMBER_OF_CYCLES,
+        }
+    }
+}
+
+impl Solution for Day17 {
+    type Input = State;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_state(input)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        Grid::check(input, parse_cube)
+    }
+
+    fn parameters(&mut self) -> Vec<(&'static str, &mut dyn Parameter)> {
+        vec![("cycles", &mut self.number_of_cycles)]
+    }
+
+    fn part1(&self, initial_state: &Self::Input) -> Answer {
+        let number_of_active_cells =
+            match run_simulation(initial_state, self.number_of_cycles, &Dimensions::Three) {
+                Some(number_of_active_cells) => number_of_active_cells,
+                None => return Answer::empty(cancel::CANCELLED),
+            };
+        Answer::new(
+            number_of_active_cells,
+            format!(
+                "Number of active cells after {} cycle(s): {}",
+                self.number_of_cycles, number_of_active_cells
+            ),
+        )
+        .with("cycles", self.number_of_cycles)
+    }
+
+    fn part2(&self, initial_state: &Self::Input) -> Answer {
+        let number_of_active_cells =
+            match run_simulation(initial_state, self.number_of_cycles, &Dimensions::Four) {
+                Some(number_of_active_cells) => number_of_active_cells,
+                None => return Answer::empty(cancel::CANCELLED),
+            };
+        Answer::new(
+            number_of_active_cells,
+            format!(
+                "Number of active cells after {} cycle(s): {}",
+                self.number_of_cycles, number_of_active_cells
+            ),
+        )
+        .with("cycles", self.number_of_cycles)
+    }
+
+    fn simulation(&self, part: Part, initial_state: &Self::Input) -> Option<Box<dyn Simulation>> {
+        let mut state = initial_state.clone();
+        state.dimensions = match part {
+            Part::One => Dimensions::Three,
+            Part::Two => Dimensions::Four,
+        };
+        Some(Box::new(CubeSimulation {
+            state,
+            cycle: 0,
+            number_of_cycles: self.number_of_cycles,
+        }))
+    }
+}
@@ -0,0 +1,205 @@
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::input::{self, Line};
+use crate::solution::Solution;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(u32),
+    Plus,
+    Times,
+    LeftParenthesis,
+    RightParenthesis,
+}
+
+fn tokenize(line: Line) -> Result<Vec<Token>, ParseError> {
+    let input = line.text;
+    let mut start_of_number = None;
+    let mut tokens = Vec::new();
+    for (i, c) in input.char_indices() {
+        if let Some(start) = start_of_number {
+            match c {
+                '0'..='9' => continue,
+                _ => {
+                    tokens.push((
+                        start,
+                        Token::Number(line.parse(&input[start..i], "number")?),
+                    ));
+                    start_of_number = None;
+                }
+            }
+        }
+        match c {
+            '0'..='9' => start_of_number = Some(i),
+            '+' => tokens.push((i, Token::Plus)),
+            '*' => tokens.push((i, Token::Times)),
+            '(' => tokens.push((i, Token::LeftParenthesis)),
+            ')' => tokens.push((i, Token::RightParenthesis)),
+            ' ' => (),
+            _ => return Err(line.error_at(&input[i..], format!("invalid character '{}'", c))),
+        }
+    }
+    if let Some(start) = start_of_number {
+        tokens.push((start, Token::Number(line.parse(&input[start..], "number")?)));
+    }
+    check_syntax(line, &tokens)?;
+    Ok(tokens.into_iter().map(|(_, token)| token).collect())
+}
+
+fn check_syntax(line: Line, tokens: &[(usize, Token)]) -> Result<(), ParseError> {
+    let mut expect_operand = true;
+    let mut depth = 0;
+    for (i, token) in tokens {
+        let expected = match token {
+            Token::Number(_) if expect_operand => None,
+            Token::LeftParenthesis if expect_operand => {
+                depth += 1;
+                None
+            }
+            Token::Plus | Token::Times if !expect_operand => None,
+            Token::RightParenthesis if !expect_operand && depth > 0 => {
+                depth -= 1;
+                None
+            }
+            Token::RightParenthesis if !expect_operand => Some("unmatched ')'"),
+            _ if expect_operand => Some("expected number or '('"),
+            _ => Some("expected operator or ')'"),
+        };
+        if let Some(message) = expected {
+            return Err(line.error_at(&line.text[*i..], message));
+        }
+        expect_operand = matches!(token, Token::Plus | Token::Times | Token::LeftParenthesis);
+    }
+    if expect_operand {
+        Err(line.error_at(
+            &line.text[line.text.len()..],
+            "unexpected end of expression",
+        ))
+    } else if depth > 0 {
+        Err(line.error_at(&line.text[line.text.len()..], "missing ')'"))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct Expression(Vec<Token>);
+
+impl Expression {
+    fn new(line: Line) -> Result<Self, ParseError> {
+        tokenize(line).map(Self)
+    }
+
+    fn evaluate1(&self) -> u64 {
+        self.evaluate1_inner(&mut self.0.iter().rev().cloned().collect())
+    }
+    fn evaluate1_inner(&self, tokens: &mut Vec<Token>) -> u64 {
+        let token = tokens.pop().expect("Unexpected end of token stream");
+        let mut acc = match token {
+            Token::Number(number) => number as u64,
+            Token::LeftParenthesis => self.evaluate1_inner(tokens),
+            token => panic!("Unexpected token: {:?}", token),
+        };
+        while let Some(operator) = tokens.pop() {
+            assert!(
+                operator == Token::Plus || operator == Token::Times,
+                "Unexpected operator token: {:?}",
+                operator
+            );
+            let token = tokens.pop().expect("Unexpected end of token stream");
+            let operand = match token {
+                Token::Number(number) => number as u64,
+                Token::LeftParenthesis => self.evaluate1_inner(tokens),
+                token => panic!("Unexpected token: {:?}", token),
+            };
+            match operator {
+                Token::Plus => acc += operand as u64,
+                Token::Times => acc *= operand as u64,
+                _ => unreachable!(),
+            }
+            if tokens.last() == Some(&Token::RightParenthesis) {
+                tokens.pop();
+                break;
+            }
+        }
+        acc
+    }
+
+    fn evaluate2(&self) -> u64 {
+        self.evaluate2_product(&mut self.0.iter().rev().cloned().collect())
+    }
+    fn evaluate2_product(&self, tokens: &mut Vec<Token>) -> u64 {
+        let mut acc = self.evaluate2_sum(tokens);
+        while !tokens.is_empty() && tokens.last() != Some(&Token::RightParenthesis) {
+            assert_eq!(
+                tokens.pop().expect("Unexpected end of token stream"),
+                Token::Times,
+                "Expected operator token Times",
+            );
+            acc *= self.evaluate2_sum(tokens);
+        }
+        acc
+    }
+    fn evaluate2_sum(&self, tokens: &mut Vec<Token>) -> u64 {
+        let token = tokens.pop().expect("Unexpected end of token stream");
+        let mut acc = match token {
+            Token::Number(number) => number as u64,
+            Token::LeftParenthesis => {
+                let result = self.evaluate2_product(tokens);
+                assert_eq!(tokens.pop(), Some(Token::RightParenthesis));
+                result
+            }
+            token => panic!("Unexpected token: {:?}", token),
+        };
+        while !tokens.is_empty()
+            && tokens.last() != Some(&Token::Times)
+            && tokens.last() != Some(&Token::RightParenthesis)
+        {
+            assert_eq!(
+                tokens.pop().expect("Unexpected end of token stream"),
+                Token::Plus,
+                "Expected operator token Plus",
+            );
+            let token = tokens.pop().expect("Unexpected end of token stream");
+            let operand = match token {
+                Token::Number(number) => number as u64,
+                Token::LeftParenthesis => {
+                    let result = self.evaluate2_product(tokens);
+                    assert_eq!(tokens.pop(), Some(Token::RightParenthesis));
+                    result
+                }
+                token => panic!("Unexpected token: {:?}", token),
+            };
+            acc += operand as u64;
+        }
+        acc
+    }
+}
+
+fn parse_expressions(input: &str) -> Result<Vec<Expression>, ParseError> {
+    input::parse_lines(input, Expression::new)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Expression>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_expressions(input)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_lines(input, Expression::new)
+    }
+
+    fn part1(&self, expressions: &Self::Input) -> Answer {
+        let sum: u64 = expressions.iter().map(|e| e.evaluate1()).sum();
+        Answer::new(sum, format!("Sum of resulting values: {}", sum))
+    }
+
+    fn part2(&self, expressions: &Self::Input) -> Answer {
+        let sum: u64 = expressions.iter().map(|e| e.evaluate2()).sum();
+        Answer::new(sum, format!("Sum of resulting values: {}", sum))
+    }
+}
@@ -0,0 +1,166 @@
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::input::{self, Line};
+use crate::solution::Solution;
+use regex::Regex;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Rule {
+    Letter(char),
+    Sequences(Vec<Vec<u32>>),
+}
+
+impl Rule {
+    fn new(line: Line) -> Result<(u32, Self), ParseError> {
+        lazy_static! {
+            static ref RE_LETTER: Regex = Regex::new(r#"^"(?P<letter>\w)"$"#).unwrap();
+        }
+        let (id, pattern) = line.split_once(": ")?;
+        let id = line.parse(id, "rule ID")?;
+        let rule = if let Some(letter) = RE_LETTER.captures(pattern).and_then(|c| c.name("letter"))
+        {
+            Self::Letter(letter.as_str().chars().next().unwrap())
+        } else {
+            Self::Sequences(
+                pattern
+                    .split('|')
+                    .map(|sequence| {
+                        let sequence = sequence.trim();
+                        if sequence.is_empty() {
+                            return Err(line.error_at(pattern, "empty rule sequence"));
+                        }
+                        sequence
+                            .split(' ')
+                            .map(|id| line.parse(id, "rule ID"))
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .collect::<Result<_, _>>()?,
+            )
+        };
+        Ok((id, rule))
+    }
+
+    fn try_match<'a>(&self, rules: &Rules, input: &'a str) -> Option<HashSet<&'a str>> {
+        match self {
+            Self::Letter(letter) => {
+                if input.starts_with(*letter) {
+                    Some(vec![&input[1..]].into_iter().collect())
+                } else {
+                    None
+                }
+            }
+            Self::Sequences(sequences) => {
+                let mut result = HashSet::new();
+                for sequence in sequences {
+                    let mut remaining: HashSet<_> = vec![input].into_iter().collect();
+                    for id in sequence {
+                        let mut new_remaining = HashSet::new();
+                        for remaining_str in remaining {
+                            if let Some(rem) =
+                                rules.0.get(id).unwrap().try_match(rules, remaining_str)
+                            {
+                                new_remaining.extend(rem);
+                            }
+                        }
+                        remaining = new_remaining;
+                    }
+                    if remaining.contains("") {
+                        // short circuit for match
+                        return Some(vec![""].into_iter().collect());
+                    }
+                    result.extend(remaining);
+                }
+                match result.is_empty() {
+                    false => Some(result),
+                    true => None,
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Rules(HashMap<u32, Rule>);
+
+impl Rules {
+    fn new() -> Self {
+        Self(HashMap::new())
+    }
+    fn insert(&mut self, k: u32, rule: Rule) {
+        self.0.insert(k, rule);
+    }
+    fn check_messages(&self, messages: &[String]) -> usize {
+        messages
+            .iter()
+            .map(|m| self.0.get(&0).unwrap().try_match(self, m))
+            .filter(|res| res.is_some() && res.as_ref().unwrap().contains(""))
+            .count()
+    }
+}
+
+fn parse_data(input: &str) -> Result<(Rules, Vec<String>), ParseError> {
+    let mut blocks = input::blocks(input).into_iter();
+    let definitions = blocks
+        .next()
+        .ok_or_else(|| input::end_of_input(input, "missing rules"))?
+        .into_iter()
+        .map(|line| Rule::new(line).map(|(id, rule)| (line, id, rule)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let ids: HashSet<_> = definitions.iter().map(|(_, id, _)| *id).collect();
+    if !ids.contains(&0) {
+        return Err(input::end_of_input(input, "missing rule 0"));
+    }
+    let mut rules = Rules::new();
+    for (line, id, rule) in definitions {
+        if let Rule::Sequences(sequences) = &rule {
+            if let Some(undefined) = sequences.iter().flatten().find(|id| !ids.contains(id)) {
+                return Err(line.error(format!("reference to undefined rule {}", undefined)));
+            }
+        }
+        rules.insert(id, rule);
+    }
+    let messages = blocks
+        .next()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|line| line.text.trim().to_owned())
+        .collect();
+    if let Some(block) = blocks.next() {
+        return Err(block[0].error("unexpected line after messages"));
+    }
+    Ok((rules, messages))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Rules, Vec<String>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_data(input)
+    }
+
+    fn part1(&self, (rules, messages): &Self::Input) -> Answer {
+        let number_of_matches = rules.check_messages(messages);
+        Answer::new(
+            number_of_matches,
+            format!("Number of messages matching rule 0: {}", number_of_matches),
+        )
+    }
+
+    fn part2(&self, (rules, messages): &Self::Input) -> Answer {
+        if !rules.0.contains_key(&42) || !rules.0.contains_key(&31) {
+            return Answer::empty("Rules 42 and 31 are required for replacing rules 8 and 11.");
+        }
+        let mut rules = rules.clone();
+        rules.insert(8, Rule::Sequences(vec![vec![42], vec![42, 8]]));
+        rules.insert(11, Rule::Sequences(vec![vec![42, 31], vec![42, 11, 31]]));
+        let number_of_matches = rules.check_messages(messages);
+        Answer::new(
+            number_of_matches,
+            format!("Number of messages matching rule 0: {}", number_of_matches),
+        )
+    }
+}
@@ -0,0 +1,497 @@
+use crate::animation::{Frame, Simulation, Still};
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::input::{self, Line};
+use crate::solution::Solution;
+use crate::trace::{self, Level};
+use crate::Part;
+use std::fmt::Write;
+
+const TOP: usize = 0;
+const LEFT: usize = 1;
+const BOTTOM: usize = 2;
+const RIGHT: usize = 3;
+const MIRRORED: usize = 4;
+
+const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+#[derive(Clone, Debug)]
+struct Image {
+    image: Grid<char>,
+}
+
+impl Image {
+    fn new(image: Grid<char>) -> Self {
+        Self { image }
+    }
+
+    fn from_grid(grid: &[Vec<Tile>]) -> Self {
+        let size = grid[0][0].image.width();
+        Self::new(Grid::from_fn(
+            grid[0].len() * size,
+            grid.len() * size,
+            |(x, y)| grid[y / size][x / size].image.image[(x % size, y % size)],
+        ))
+    }
+
+    fn height(&self) -> usize {
+        self.image.height()
+    }
+
+    fn width(&self) -> usize {
+        self.image.width()
+    }
+
+    fn rotate90(&mut self) {
+        self.image = self.image.rotated_left();
+    }
+
+    fn mirror_vert(&mut self) {
+        self.image = self.image.flipped_vertically();
+    }
+
+    fn mirror_hor(&mut self) {
+        self.image = self.image.flipped_horizontally();
+    }
+
+    fn set(&mut self, x: usize, y: usize, c: char) {
+        self.image[(x, y)] = c;
+    }
+
+    fn get(&self, x: usize, y: usize) -> char {
+        self.image[(x, y)]
+    }
+
+    fn count(&self, needle: char) -> usize {
+        self.image.iter().filter(|&&c| c == needle).count()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Tile {
+    id: u32,
+    edges: Vec<u32>,
+    image: Image,
+}
+
+impl Tile {
+    fn new(block: &[Line]) -> Result<Self, ParseError> {
+        let header = block[0];
+        let id = header
+            .text
+            .trim()
+            .strip_prefix("Tile ")
+            .and_then(|id| id.strip_suffix(':'))
+            .ok_or_else(|| header.error("expected 'Tile <id>:'"))?;
+        let id = header.parse(id, "tile ID")?;
+        let size = block.len() - 1;
+        if !(3..=32).contains(&size) {
+            return Err(header.error(format!("tile has {} rows, expected 3 to 32", size)));
+        }
+        let rows = block[1..].iter().map(|row| Line {
+            number: row.number,
+            text: row.text.trim(),
+        });
+        let pixels = Grid::parse_lines(rows, |c| match c {
+            '.' | '#' => Some(c),
+            _ => None,
+        })?;
+        if pixels.width() != size {
+            return Err(block[1].error(format!(
+                "row has width {}, expected {}",
+                pixels.width(),
+                size
+            )));
+        }
+        Ok(Self::from_pixels(id, &pixels))
+    }
+
+    fn from_pixels(id: u32, pixels: &Grid<char>) -> Self {
+        let last = pixels.width() - 1;
+        let edges = [
+            pixels.row(0).iter().collect::<Vec<_>>(), // top
+            pixels.column(0).collect(),               // left
+            pixels.row(last).iter().collect(),        // bottom
+            pixels.column(last).collect(),            // right
+            pixels.row(0).iter().rev().collect(),     // top mirrored
+            pixels.column(0).rev().collect(),         // left mirrored
+            pixels.row(last).iter().rev().collect(),  // bottom mirrored
+            pixels.column(last).rev().collect(),      // right mirrored
+        ];
+        // replace edges by binary integer representations
+        let edges = edges
+            .iter()
+            .map(|edge| {
+                edge.iter()
+                    .fold(0, |acc, &&c| acc << 1 | if c == '#' { 1 } else { 0 })
+            })
+            .collect();
+        let image = Image::new(pixels.section((1, 1), last - 1, last - 1));
+        Self { id, edges, image }
+    }
+
+    fn align_top_left(&mut self, edges: &[u32]) {
+        // as first tile will always fit without mirroring, only rotation has to be aligned
+        let top = edges.contains(&self.edges[TOP]);
+        let left = edges.contains(&self.edges[LEFT]);
+        let bottom = edges.contains(&self.edges[BOTTOM]);
+        let right = edges.contains(&self.edges[RIGHT]);
+        match (top, left, bottom, right) {
+            (true, true, false, false) => (),
+            (true, false, false, true) => self.rotate90(1),
+            (false, false, true, true) => self.rotate90(2),
+            (false, true, true, false) => self.rotate90(3),
+            _ => unreachable!(),
+        }
+    }
+
+    fn align_left(&mut self, edge: u32) {
+        let edge_index = self.edges.iter().position(|&id| id == edge).unwrap();
+        match edge_index % 4 {
+            TOP => self.rotate90(1),
+            LEFT => (),
+            BOTTOM => self.rotate90(3),
+            RIGHT => self.rotate90(2),
+            _ => unreachable!(),
+        };
+        if self.edges[LEFT] != edge {
+            self.mirror_vert();
+        }
+    }
+
+    fn align_top(&mut self, edge: u32) {
+        let edge_index = self.edges.iter().position(|&id| id == edge).unwrap();
+        match edge_index % MIRRORED {
+            TOP => (),
+            LEFT => self.rotate90(3),
+            BOTTOM => self.rotate90(2),
+            RIGHT => self.rotate90(1),
+            _ => unreachable!(),
+        };
+        if self.edges[TOP] != edge {
+            self.mirror_hor();
+        }
+    }
+
+    fn rotate90(&mut self, amount: u32) {
+        for _ in 0..amount {
+            self.edges = vec![
+                self.edges[RIGHT],             // top
+                self.edges[MIRRORED + TOP],    // left
+                self.edges[LEFT],              // bottom
+                self.edges[MIRRORED + BOTTOM], // right
+                self.edges[MIRRORED + RIGHT],  // top (mirrored)
+                self.edges[TOP],               // left (mirrored)
+                self.edges[MIRRORED + LEFT],   // bottom (mirrored)
+                self.edges[BOTTOM],            // right (mirrored)
+            ];
+            self.image.rotate90();
+        }
+    }
+
+    fn mirror_vert(&mut self) {
+        self.edges = vec![
+            self.edges[BOTTOM],            // top
+            self.edges[MIRRORED + LEFT],   // left
+            self.edges[TOP],               // bottom
+            self.edges[MIRRORED + RIGHT],  // right
+            self.edges[MIRRORED + BOTTOM], // top (mirrored)
+            self.edges[LEFT],              // left (mirrored)
+            self.edges[MIRRORED + TOP],    // bottom (mirrored)
+            self.edges[RIGHT],             // right (mirrored)
+        ];
+        self.image.mirror_vert();
+    }
+
+    fn mirror_hor(&mut self) {
+        self.edges = vec![
+            self.edges[MIRRORED + TOP],    // top
+            self.edges[RIGHT],             // left
+            self.edges[MIRRORED + BOTTOM], // bottom
+            self.edges[LEFT],              // right
+            self.edges[TOP],               // top (mirrored)
+            self.edges[MIRRORED + RIGHT],  // left (mirrored)
+            self.edges[BOTTOM],            // bottom (mirrored)
+            self.edges[MIRRORED + LEFT],   // right (mirrored)
+        ];
+        self.image.mirror_hor();
+    }
+
+    fn right_edge(&self) -> u32 {
+        self.edges[RIGHT]
+    }
+    fn bottom_edge(&self) -> u32 {
+        self.edges[BOTTOM]
+    }
+}
+
+fn read_tiles(input: &str, errors: &mut Vec<ParseError>) -> Vec<Tile> {
+    let blocks = input::blocks(input);
+    let tiles: Vec<_> = blocks
+        .iter()
+        .filter_map(|block| input::record(errors, Tile::new(block)).map(|tile| (block, tile)))
+        .collect();
+    if let Some((_, first)) = tiles.first() {
+        for (block, tile) in &tiles {
+            if tile.image.height() != first.image.height() {
+                errors.push(block[0].error(format!(
+                    "tile has {} rows, expected {} like the first tile",
+                    block.len() - 1,
+                    first.image.height() + 2
+                )));
+            }
+        }
+    }
+    let side = (blocks.len() as f64).sqrt().round() as usize;
+    if blocks.is_empty() || side * side != blocks.len() {
+        errors.push(input::end_of_input(
+            input,
+            format!("{} tiles cannot form a square image", blocks.len()),
+        ));
+    }
+    tiles.into_iter().map(|(_, tile)| tile).collect()
+}
+
+fn find_top_left_tile(tiles: &mut Vec<Tile>) -> Tile {
+    let all_edge_ids: Vec<_> = tiles.iter().flat_map(|t| t.edges.iter().cloned()).collect();
+    let mut all_edge_ids = all_edge_ids;
+    all_edge_ids.sort();
+    let mut tile_index = 0;
+    let mut unique_edges = Vec::new();
+    for (index, tile) in tiles.iter().enumerate() {
+        unique_edges = tile
+            .edges
+            .iter()
+            .map(|edge_id| {
+                (
+                    *edge_id,
+                    all_edge_ids.iter().filter(|id| *id == edge_id).count(),
+                )
+            })
+            .filter(|(_, c)| *c == 1)
+            .map(|(id, _)| id)
+            .collect();
+        if unique_edges.len() == 4 {
+            // 4: two unique edges with their mirrored versions
+            tile_index = index;
+            break;
+        }
+    }
+    let mut tile = tiles.remove(tile_index);
+    tile.align_top_left(&unique_edges);
+    tile
+}
+
+fn try_find_right_tile(tiles: &mut Vec<Tile>, grid: &[Vec<Tile>]) -> Option<Tile> {
+    let left_edge = grid.iter().last()?.iter().last()?.right_edge();
+    let tile_index = tiles
+        .iter()
+        .enumerate()
+        .find(|(_, tile)| tile.edges.contains(&left_edge))
+        .map(|(index, _)| index);
+    let tile = tile_index.map(|index| tiles.remove(index));
+    match tile {
+        Some(mut tile) => {
+            tile.align_left(left_edge);
+            Some(tile)
+        }
+        None => None,
+    }
+}
+
+fn find_bottom_tile(tiles: &mut Vec<Tile>, grid: &[Vec<Tile>]) -> Tile {
+    let top_edge = grid
+        .iter()
+        .last()
+        .unwrap()
+        .iter()
+        .next()
+        .unwrap()
+        .bottom_edge();
+    let tile_index = tiles
+        .iter()
+        .enumerate()
+        .find(|(_, tile)| tile.edges.contains(&top_edge))
+        .map(|(index, _)| index);
+    let tile_index = tile_index.unwrap();
+    let mut tile = tiles.remove(tile_index);
+    tile.align_top(top_edge);
+    tile
+}
+
+fn replace_sea_monsters(image: &mut Image) -> u32 {
+    let sea_monster_iter = SEA_MONSTER.iter().enumerate().flat_map(|(y, row)| {
+        row.chars()
+            .enumerate()
+            .filter(|(_, c)| *c == '#')
+            .map(move |(x, _)| (x, y))
+    });
+    let mut number_of_sea_monsters = 0;
+    for y_offset in 0..image.height() - SEA_MONSTER.len() + 1 {
+        for x_offset in 0..image.width() - SEA_MONSTER[0].len() + 1 {
+            let sea_monster_offset_iter = sea_monster_iter
+                .clone()
+                .map(|(x, y)| (x + x_offset, y + y_offset));
+            if sea_monster_offset_iter
+                .clone()
+                .all(|(x, y)| image.get(x, y) != '.')
+            {
+                sea_monster_offset_iter.for_each(|(x, y)| image.set(x, y, 'O'));
+                number_of_sea_monsters += 1;
+            }
+        }
+    }
+    number_of_sea_monsters
+}
+
+fn find_sea_monsters(image: &mut Image) -> u32 {
+    for _ in 0..2 {
+        for _ in 0..4 {
+            let number_of_sea_monsters = replace_sea_monsters(image);
+            if number_of_sea_monsters > 0 {
+                return number_of_sea_monsters;
+            }
+            image.rotate90();
+        }
+        image.mirror_hor();
+    }
+    0
+}
+
+fn display_grid(grid: &[Vec<Tile>]) -> String {
+    let mut text = String::from("Grid:\n");
+    for row in grid.iter() {
+        for tile in row.iter() {
+            write!(
+                text,
+                "    {:>3} {:>3}    ",
+                tile.edges[TOP],
+                tile.edges[MIRRORED + TOP]
+            )
+            .unwrap();
+        }
+        text.push('\n');
+        for tile in row.iter() {
+            write!(
+                text,
+                " {:>3} [{}] {:>3}",
+                tile.edges[LEFT], tile.id, tile.edges[RIGHT]
+            )
+            .unwrap();
+        }
+        text.push('\n');
+        for tile in row.iter() {
+            write!(
+                text,
+                " {:>3}        {:>3}",
+                tile.edges[MIRRORED + LEFT],
+                tile.edges[MIRRORED + RIGHT]
+            )
+            .unwrap();
+        }
+        text.push('\n');
+        for tile in row.iter() {
+            write!(
+                text,
+                "    {:>3} {:>3}    ",
+                tile.edges[BOTTOM],
+                tile.edges[MIRRORED + BOTTOM]
+            )
+            .unwrap();
+        }
+        text.push('\n');
+    }
+    text
+}
+
+fn assemble_grid(tiles: &[Tile]) -> Vec<Vec<Tile>> {
+    let mut remaining_tiles = tiles.to_vec();
+    let mut grid = Vec::new();
+    while !remaining_tiles.is_empty() {
+        if grid.is_empty() {
+            grid.push(vec![find_top_left_tile(&mut remaining_tiles)]);
+        } else {
+            grid.push(vec![find_bottom_tile(&mut remaining_tiles, &grid)]);
+        }
+        while let Some(tile) = try_find_right_tile(&mut remaining_tiles, &grid) {
+            grid.iter_mut().last().unwrap().push(tile);
+        }
+    }
+    grid
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<Tile>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input::parse_with(input, read_tiles)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_with(input, read_tiles)
+    }
+
+    fn part1(&self, tiles: &Self::Input) -> Answer {
+        let grid = assemble_grid(tiles);
+        trace::event(
+            "day20",
+            "assembled",
+            &[("rows", &grid.len()), ("columns", &grid[0].len())],
+        );
+        if trace::enabled(Level::Walkthrough) {
+            trace::walkthrough(display_grid(&grid));
+        }
+        let corner_product = grid[0][0].id as u64
+            * grid[0][grid[0].len() - 1].id as u64
+            * grid[grid.len() - 1][0].id as u64
+            * grid[grid.len() - 1][grid[grid.len() - 1].len() - 1].id as u64;
+        Answer::new(
+            corner_product,
+            format!("Product of corner tile IDs: {}", corner_product),
+        )
+    }
+
+    fn part2(&self, tiles: &Self::Input) -> Answer {
+        let grid = assemble_grid(tiles);
+        let mut image = Image::from_grid(&grid);
+        let number_of_sea_monsters = find_sea_monsters(&mut image);
+        trace::event(
+            "day20",
+            "sea_monsters",
+            &[("count", &number_of_sea_monsters)],
+        );
+        trace::walkthrough(format_args!("Image:\n{}", image.image));
+        let roughness = image.count('#');
+        Answer::new(
+            roughness,
+            format!(
+                "Found {} sea monster(s)! Habitat's water roughness: {}",
+                number_of_sea_monsters, roughness
+            ),
+        )
+        .with("sea_monsters", number_of_sea_monsters)
+    }
+
+    /// The assembled image of the second part with the sea monsters marked.
+    fn simulation(&self, part: Part, tiles: &Self::Input) -> Option<Box<dyn Simulation>> {
+        if part == Part::One {
+            return None;
+        }
+        let mut image = Image::from_grid(&assemble_grid(tiles));
+        find_sea_monsters(&mut image);
+        let picture = image.image.render(|&c| c);
+        Some(Box::new(Still(Frame {
+            generation: 0,
+            live: image.count('O'),
+            rows: picture.lines().map(str::to_owned).collect(),
+            hexagonal: false,
+        })))
+    }
+}
@@ -0,0 +1,155 @@
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::ParseError;
+use crate::input::{self, Line};
+use crate::solution::Solution;
+use regex::Regex;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Food {
+    ingredients: Vec<String>,
+    allergens: Vec<String>,
+}
+
+impl Food {
+    fn new(line: Line) -> Result<Self, ParseError> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+                r"^(?P<ingredients>\w+( \w+)*) \(contains (?P<allergens>\w+(, \w+)*)\)$"
+            )
+            .unwrap();
+        }
+        let captures = RE
+            .captures(line.text)
+            .ok_or_else(|| line.error("expected '<ingredients> (contains <allergens>)'"))?;
+        let ingredients = captures["ingredients"]
+            .split(' ')
+            .map(str::to_string)
+            .collect();
+        let allergens = captures["allergens"]
+            .split(", ")
+            .map(str::to_string)
+            .collect();
+        Ok(Self {
+            ingredients,
+            allergens,
+        })
+    }
+}
+
+fn parse_foods(input: &str) -> Result<Vec<Food>, ParseError> {
+    input::parse_lines(input, Food::new)
+}
+
+/// Maps the ingredients to the allergens they contain, or explains why some allergens could not
+/// be mapped.
+fn map_allergens(foods: &[Food]) -> Result<HashMap<String, String>, &'static str> {
+    let mut possibilities: HashMap<String, Vec<String>> = HashMap::new();
+    for food in foods {
+        for allergen in &food.allergens {
+            possibilities.insert(
+                allergen.clone(),
+                if let Some(poss) = possibilities.get(allergen) {
+                    poss.iter()
+                        .filter(|&a| food.ingredients.contains(a))
+                        .cloned()
+                        .collect()
+                } else {
+                    food.ingredients.clone()
+                },
+            );
+        }
+    }
+
+    let mut allergen_map = HashMap::new();
+    while !possibilities.is_empty() {
+        if cancel::cancelled() {
+            return Err("Cancelled before all allergens were mapped.");
+        }
+        // find allergens with only one possibility
+        let known_allergens: Vec<_> = possibilities
+            .iter()
+            .filter_map(|(allergen, ingredients)| match ingredients.len() {
+                1 => Some(allergen),
+                _ => None,
+            })
+            .cloned()
+            .collect();
+        if known_allergens.is_empty() {
+            return Err("Ambiguous foods, no remaining allergen is in exactly one ingredient.");
+        }
+        for allergen in known_allergens {
+            let ingredient = possibilities.remove(&allergen).unwrap().pop().unwrap();
+            // remove ingredient from remaining possibilities
+            possibilities = possibilities
+                .into_iter()
+                .map(|(allergen, ingredients)| {
+                    (
+                        allergen,
+                        ingredients
+                            .into_iter()
+                            .filter(|i| i != &ingredient)
+                            .collect(),
+                    )
+                })
+                .collect();
+            allergen_map.insert(ingredient, allergen);
+        }
+    }
+    Ok(allergen_map)
+}
+
+fn count_inert_ingredients(foods: &[Food], allergens: &HashMap<String, String>) -> usize {
+    foods
+        .iter()
+        .flat_map(|food| food.ingredients.iter())
+        .filter(|&ingredient| allergens.get(ingredient).is_none())
+        .count()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Food>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_foods(input)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_lines(input, Food::new)
+    }
+
+    fn part1(&self, foods: &Self::Input) -> Answer {
+        let allergens = match map_allergens(foods) {
+            Ok(allergens) => allergens,
+            Err(reason) => return Answer::empty(reason),
+        };
+        let number_of_appearances = count_inert_ingredients(foods, &allergens);
+        Answer::new(
+            number_of_appearances,
+            format!(
+                "Appearances of non-allergenic ingredients: {}",
+                number_of_appearances
+            ),
+        )
+    }
+
+    fn part2(&self, foods: &Self::Input) -> Answer {
+        let allergens = match map_allergens(foods) {
+            Ok(allergens) => allergens,
+            Err(reason) => return Answer::empty(reason),
+        };
+        let mut dangerous_ingredients: Vec<_> = allergens.keys().cloned().collect();
+        dangerous_ingredients.sort_unstable_by_key(|k| allergens.get(k).unwrap());
+        let dangerous_ingredients = dangerous_ingredients.join(",");
+        Answer::new(
+            dangerous_ingredients.clone(),
+            format!(
+                "Canonical dangerous ingredient list: {}",
+                dangerous_ingredients
+            ),
+        )
+    }
+}
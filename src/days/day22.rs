@@ -0,0 +1,308 @@
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::input;
+use crate::solution::Solution;
+use crate::trace::{self, Level};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+type Card = u32;
+
+#[derive(Clone, Debug)]
+pub struct Deck {
+    cards: Vec<u32>,
+}
+
+impl Deck {
+    fn new() -> Self {
+        Self { cards: Vec::new() }
+    }
+
+    fn add(&mut self, card: Card) {
+        self.cards.push(card);
+    }
+
+    fn draw(&mut self) -> Card {
+        self.cards.remove(0)
+    }
+
+    fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    fn score(&self) -> u32 {
+        self.cards
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, c)| (i as u32 + 1) * c)
+            .sum()
+    }
+
+    fn copy_cards(&self) -> Vec<Card> {
+        self.cards.clone()
+    }
+
+    fn copy_subdeck(&self, n: usize) -> Self {
+        Self {
+            cards: self.cards[0..n].into(),
+        }
+    }
+}
+
+impl std::fmt::Display for Deck {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.cards
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct GameResult {
+    winning_player: usize,
+    score: u32,
+    max_game_id: u32,
+}
+
+#[derive(Clone, Debug)]
+struct Game {
+    id: u32,
+    decks: Vec<Deck>,
+    recursion: bool,
+    state_history: Vec<u64>,
+}
+
+impl Game {
+    fn new(decks: &[Deck]) -> Self {
+        Self::new_inner(1, decks)
+    }
+
+    fn new_inner(id: u32, decks: &[Deck]) -> Self {
+        assert_eq!(decks.len(), 2, "Number of players should be 2");
+        Self {
+            id,
+            decks: decks.to_vec(),
+            recursion: false,
+            state_history: Vec::new(),
+        }
+    }
+
+    fn enable_recursion(&mut self) {
+        self.recursion = true;
+    }
+
+    fn trace_decks(&self) {
+        self.decks
+            .iter()
+            .enumerate()
+            .for_each(|(i, p)| trace::walkthrough(format_args!("Player {}'s deck: {}", i + 1, p)));
+    }
+
+    fn play(&mut self) -> GameResult {
+        let walkthrough = trace::enabled(Level::Walkthrough);
+        if walkthrough && self.recursion {
+            trace::walkthrough(format_args!("=== Game {} ===", self.id));
+        }
+        let mut round = 0;
+        let mut max_game_id = self.id;
+        let mut winning_player = 0;
+        while self.all_players_have_cards() {
+            round += 1;
+            if walkthrough {
+                match self.recursion {
+                    true => trace::walkthrough(format_args!(
+                        "\n-- Round {} (Game {}) --",
+                        round, self.id
+                    )),
+                    false => trace::walkthrough(format_args!("-- Round {} --", round)),
+                }
+                self.trace_decks();
+            }
+            // check if configuration is known
+            let mut hasher = DefaultHasher::new();
+            self.decks
+                .iter()
+                .map(|d| d.copy_cards())
+                .collect::<Vec<_>>()
+                .hash(&mut hasher);
+            let state = hasher.finish();
+            if self.state_history.contains(&state) {
+                trace::event(
+                    "day22",
+                    "repetition",
+                    &[("game", &self.id), ("round", &round)],
+                );
+                trace::walkthrough("Known state detected!");
+                winning_player = 0;
+                break;
+            }
+            self.state_history.push(state);
+            // draw cards
+            let cards: Vec<_> = self.decks.iter_mut().map(|p| p.draw()).collect();
+            if walkthrough {
+                cards.iter().enumerate().for_each(|(i, c)| {
+                    trace::walkthrough(format_args!("Player {} plays: {}", i + 1, c))
+                });
+            }
+            // check recursion
+            let recurse = self.recursion
+                && cards
+                    .iter()
+                    .enumerate()
+                    .all(|(i, &c)| self.decks[i].len() >= c as usize);
+            if recurse {
+                // winner of inner game wins this round
+                trace::walkthrough("Playing a sub-game to determine the winner...\n");
+                let decks: Vec<_> = cards
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &c)| self.decks[i].copy_subdeck(c as usize))
+                    .collect();
+                let mut game = Self::new_inner(max_game_id + 1, &decks);
+                game.enable_recursion();
+                let result = game.play();
+                trace::walkthrough(format_args!("...anyway, back to game {}.", self.id));
+                max_game_id = result.max_game_id;
+                winning_player = result.winning_player;
+            } else {
+                // highest cards wins
+                let winning_card = cards.iter().max().unwrap();
+                winning_player = cards.iter().position(|c| c == winning_card).unwrap();
+            };
+            trace::event(
+                "day22",
+                "round",
+                &[
+                    ("game", &self.id),
+                    ("round", &round),
+                    ("player_1", &cards[0]),
+                    ("player_2", &cards[1]),
+                    ("winner", &(winning_player + 1)),
+                ],
+            );
+            match self.recursion {
+                true => trace::walkthrough(format_args!(
+                    "Player {} wins round {} of game {}!",
+                    winning_player + 1,
+                    round,
+                    self.id
+                )),
+                false => trace::walkthrough(format_args!(
+                    "Player {} wins the round!\n",
+                    winning_player + 1
+                )),
+            }
+            // winning player gets the cards (winning card first)
+            self.decks[winning_player].add(cards[winning_player]);
+            self.decks[winning_player].add(cards[1 - winning_player]);
+        }
+        trace::event(
+            "day22",
+            "game",
+            &[
+                ("game", &self.id),
+                ("rounds", &round),
+                ("winner", &(winning_player + 1)),
+            ],
+        );
+        if walkthrough {
+            if self.recursion {
+                trace::walkthrough(format_args!(
+                    "The winner of game {} is player {}!\n",
+                    self.id,
+                    winning_player + 1
+                ));
+            }
+            if self.id == 1 {
+                trace::walkthrough("\n== Post-game results ==");
+                self.trace_decks();
+            }
+        }
+        GameResult {
+            winning_player,
+            score: self.decks[winning_player].score(),
+            max_game_id,
+        }
+    }
+
+    fn all_players_have_cards(&self) -> bool {
+        self.decks.iter().all(|p| p.len() > 0)
+    }
+}
+
+fn read_decks(input: &str, errors: &mut Vec<ParseError>) -> Vec<Deck> {
+    let blocks = input::blocks(input);
+    if blocks.len() != 2 {
+        errors.push(input::end_of_input(
+            input,
+            format!("found {} players, expected 2", blocks.len()),
+        ));
+    }
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(index, block)| {
+            let header = format!("Player {}:", index + 1);
+            if block[0].text.trim() != header {
+                errors.push(block[0].error(format!("expected '{}'", header)));
+            }
+            let mut deck = Deck::new();
+            for line in &block[1..] {
+                if let Some(card) = input::record(errors, line.parse(line.text.trim(), "card")) {
+                    deck.add(card);
+                }
+            }
+            deck
+        })
+        .collect()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Deck>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input::parse_with(input, read_decks)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_with(input, read_decks)
+    }
+
+    fn part1(&self, decks: &Self::Input) -> Answer {
+        let mut game = Game::new(decks);
+        let result = game.play();
+        Answer::new(
+            result.score,
+            format!(
+                "Player {} wins the game with this score: {}",
+                result.winning_player + 1,
+                result.score
+            ),
+        )
+        .with("winning_player", result.winning_player + 1)
+    }
+
+    fn part2(&self, decks: &Self::Input) -> Answer {
+        let mut game = Game::new(decks);
+        game.enable_recursion();
+        let result = game.play();
+        Answer::new(
+            result.score,
+            format!(
+                "Player {} wins the game with this score: {}",
+                result.winning_player + 1,
+                result.score
+            ),
+        )
+        .with("winning_player", result.winning_player + 1)
+    }
+}
@@ -0,0 +1,444 @@
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::ParseError;
+use crate::input;
+use crate::parameters::Parameter;
+use crate::solution::{Solution, Variant};
+use crate::trace::{self, Level};
+use crate::Part;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Debug)]
+struct CupCircle {
+    cups: HashMap<u32, (u32, u32)>,
+    number_of_cups: usize,
+}
+
+impl CupCircle {
+    fn new(mut starting_cups: Vec<u32>, number_of_cups: usize) -> Self {
+        let first_cup = starting_cups.remove(0);
+        let mut cup_circle = Self {
+            cups: vec![(first_cup, (first_cup, first_cup))]
+                .into_iter()
+                .collect(),
+            number_of_cups,
+        };
+        starting_cups.iter().for_each(|c| {
+            cup_circle.insert_cup_before(first_cup, *c);
+        });
+        (cup_circle.len()..number_of_cups)
+            .map(|c| c as u32 + 1)
+            .for_each(|c| cup_circle.insert_cup_before(first_cup, c));
+        cup_circle
+    }
+
+    fn len(&self) -> usize {
+        self.cups.len()
+    }
+
+    fn insert_cup_before(&mut self, label: u32, cup: u32) {
+        let label_tuple = self.cups.remove(&label).unwrap();
+        self.cups.insert(cup, (label_tuple.0, label));
+        self.cups.insert(label, (cup, label_tuple.1));
+        // update pointer of previous cup
+        let prev_tuple = *self.cups.get(&label_tuple.0).unwrap();
+        self.cups.insert(label_tuple.0, (prev_tuple.0, cup));
+    }
+
+    fn insert_cup_after(&mut self, label: u32, cup: u32) {
+        let label_tuple = self.cups.remove(&label).unwrap();
+        self.cups.insert(cup, (label, label_tuple.1));
+        self.cups.insert(label, (label_tuple.0, cup));
+        // update pointer of next cup
+        let next_tuple = *self.cups.get(&label_tuple.1).unwrap();
+        self.cups.insert(label_tuple.1, (cup, next_tuple.1));
+    }
+
+    fn remove_cups_after(&mut self, label: u32, amount: usize) -> Vec<u32> {
+        let mut label_tuple = self.cups.remove(&label).unwrap();
+        let mut pick_up = Vec::new();
+        for _ in 0..amount {
+            pick_up.push(label_tuple.1);
+            let cup_tuple = self.cups.remove(&label_tuple.1).unwrap();
+            label_tuple.1 = cup_tuple.1;
+        }
+        self.cups.insert(label, label_tuple);
+        // update pointer of next cup
+        let next_tuple = *self.cups.get(&label_tuple.1).unwrap();
+        self.cups.insert(label_tuple.1, (label, next_tuple.1));
+        pick_up
+    }
+
+    fn find_destination_cup(&self, current_cup: u32) -> u32 {
+        let mut destination = current_cup - 1;
+        while !self.cups.contains_key(&destination) {
+            if destination == 0 {
+                destination = self.number_of_cups as u32;
+            } else {
+                destination -= 1;
+            }
+        }
+        destination
+    }
+
+    fn find_next_cup(&self, label: u32) -> u32 {
+        self.cups.get(&label).unwrap().1
+    }
+
+    fn find_previous_cup(&self, label: u32) -> u32 {
+        self.cups.get(&label).unwrap().0
+    }
+
+    /// Lists the cups with the current one at the given position, like the puzzle text does.
+    fn to_display_string(&self, current_cup: u32, position: usize) -> String {
+        let first_cup = (0..position).fold(current_cup, |cup, _| self.find_previous_cup(cup));
+        let mut result = String::new();
+        let mut cup = first_cup;
+        loop {
+            result.push_str(&match cup == current_cup {
+                true => format!("({})", cup),
+                false => format!(" {} ", cup),
+            });
+            cup = self.find_next_cup(cup);
+            if cup == first_cup {
+                break;
+            }
+        }
+        result
+    }
+
+    fn result_part1(&self) -> String {
+        let mut result = String::new();
+        let mut cup = 1;
+        loop {
+            cup = self.find_next_cup(cup);
+            if cup == 1 {
+                break;
+            }
+            result.push_str(&cup.to_string());
+        }
+        result
+    }
+
+    fn result_part2(&self) -> u64 {
+        let first_star_cup = self.find_next_cup(1);
+        let second_star_cup = self.find_next_cup(first_star_cup);
+        first_star_cup as u64 * second_star_cup as u64
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Game {
+    cups: CupCircle,
+    current_cup: u32,
+    trace: bool,
+}
+
+impl Game {
+    fn new(cups: Vec<u32>, number_of_cups: usize) -> Self {
+        let current_cup = cups[0];
+        let number_of_cups = number_of_cups.max(cups.len());
+        Self {
+            cups: CupCircle::new(cups, number_of_cups),
+            current_cup,
+            trace: true,
+        }
+    }
+
+    fn disable_trace(&mut self) {
+        self.trace = false;
+    }
+
+    /// Plays the given number of moves, false if cancelled before.
+    fn play(&mut self, moves: u32) -> bool {
+        let walkthrough = self.trace && trace::enabled(Level::Walkthrough);
+        let number_of_cups = self.cups.len();
+        for move_ in 1..moves + 1 {
+            if cancel::cancelled_at(move_.into()) {
+                return false;
+            }
+            if walkthrough {
+                let position = (move_ as usize - 1) % number_of_cups;
+                trace::walkthrough(format_args!("-- move {} --", move_));
+                trace::walkthrough(format_args!(
+                    "cups: {}",
+                    self.cups.to_display_string(self.current_cup, position)
+                ));
+            }
+            let pick_up = self.cups.remove_cups_after(self.current_cup, 3);
+            let destination = self.cups.find_destination_cup(self.current_cup);
+            if self.trace {
+                trace::event(
+                    "day23",
+                    "move",
+                    &[
+                        ("move", &move_),
+                        ("current", &self.current_cup),
+                        ("destination", &destination),
+                    ],
+                );
+            } else if move_.is_multiple_of(trace::STEPS_PER_PROGRESS) {
+                trace::event(
+                    "day23",
+                    "progress",
+                    &[("move", &move_), ("current", &self.current_cup)],
+                );
+            }
+            if walkthrough {
+                trace::walkthrough(format_args!(
+                    "pick up: {}\ndestination: {}\n",
+                    pick_up
+                        .iter()
+                        .map(|c| c.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    destination
+                ));
+            }
+            pick_up
+                .iter()
+                .rev()
+                .for_each(|c| self.cups.insert_cup_after(destination, *c));
+            self.current_cup = self.cups.find_next_cup(self.current_cup);
+        }
+
+        if walkthrough {
+            let position = moves as usize % number_of_cups;
+            trace::walkthrough("-- final --");
+            trace::walkthrough(format_args!(
+                "cups: {}",
+                self.cups.to_display_string(self.current_cup, position)
+            ));
+        }
+        true
+    }
+
+    fn result_part1(&self) -> String {
+        self.cups.result_part1()
+    }
+
+    fn result_part2(&self) -> u64 {
+        self.cups.result_part2()
+    }
+}
+
+/// Plays the game on an array holding the label of the next cup for each cup instead of the
+/// hash map of `CupCircle`, returning that array, or `None` if cancelled before all moves.
+fn play_on_array(cups: &[u32], number_of_cups: usize, moves: u32) -> Option<Vec<u32>> {
+    let number_of_cups = number_of_cups.max(cups.len()) as u32;
+    let labels: Vec<_> = cups
+        .iter()
+        .cloned()
+        .chain(cups.len() as u32 + 1..=number_of_cups)
+        .collect();
+    let mut next = vec![0; number_of_cups as usize + 1];
+    for (i, &label) in labels.iter().enumerate() {
+        next[label as usize] = labels[(i + 1) % labels.len()];
+    }
+    let mut current = labels[0];
+    for move_ in 0..moves {
+        if cancel::cancelled_at(move_.into()) {
+            return None;
+        }
+        let first = next[current as usize];
+        let second = next[first as usize];
+        let third = next[second as usize];
+        next[current as usize] = next[third as usize];
+        let mut destination = current;
+        loop {
+            destination = if destination == 1 {
+                number_of_cups
+            } else {
+                destination - 1
+            };
+            if ![first, second, third].contains(&destination) {
+                break;
+            }
+        }
+        next[third as usize] = next[destination as usize];
+        next[destination as usize] = first;
+        current = next[current as usize];
+    }
+    Some(next)
+}
+
+fn parse_labeling(input: &str) -> Result<Vec<u32>, ParseError> {
+    let mut lines = input::lines(input).filter(|line| !line.text.trim().is_empty());
+    let line = lines
+        .next()
+        .ok_or_else(|| input::end_of_input(input, "missing cup labeling"))?;
+    if let Some(line) = lines.next() {
+        return Err(line.error("unexpected line after cup labeling"));
+    }
+    let labeling = line.text.trim();
+    let mut labels = Vec::new();
+    for (index, c) in labeling.char_indices() {
+        let label = c.to_digit(10).filter(|&label| label > 0).ok_or_else(|| {
+            line.error_at(&labeling[index..], format!("invalid cup label '{}'", c))
+        })?;
+        if labels.contains(&label) {
+            return Err(line.error_at(&labeling[index..], format!("duplicate cup label {}", c)));
+        }
+        labels.push(label);
+    }
+    if labels.len() < MIN_CUPS {
+        return Err(line.error(format!(
+            "found {} cups, expected at least {}",
+            labels.len(),
+            MIN_CUPS
+        )));
+    }
+    if (1..=labels.len() as u32).any(|label| !labels.contains(&label)) {
+        return Err(line.error(format!("cup labels have to be 1 to {}", labels.len())));
+    }
+    Ok(labels)
+}
+
+/// Fewest cups a labeling has, leaving the three picked up cups, the current cup and a
+/// destination.
+const MIN_CUPS: usize = 5;
+const MOVES_PART1: u32 = 100;
+const MOVES_PART2: u32 = 10_000_000;
+const CUPS_PART2: usize = 1_000_000;
+
+/// Number of cups of part 2, the labeled ones followed by as many more as needed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cups(usize);
+
+impl fmt::Display for Cups {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Cups {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s
+            .parse()
+            .map_err(|err: std::num::ParseIntError| err.to_string())?
+        {
+            cups if cups < MIN_CUPS => Err(format!("expected at least {} cups", MIN_CUPS)),
+            cups => Ok(Self(cups)),
+        }
+    }
+}
+
+pub struct Day23 {
+    moves_part1: u32,
+    moves_part2: u32,
+    cups_part2: Cups,
+}
+
+impl Default for Day23 {
+    fn default() -> Self {
+        Self {
+            moves_part1: MOVES_PART1,
+            moves_part2: MOVES_PART2,
+            cups_part2: Cups(CUPS_PART2),
+        }
+    }
+}
+
+impl Solution for Day23 {
+    type Input = Vec<u32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_labeling(input)
+    }
+
+    fn parameters(&mut self) -> Vec<(&'static str, &mut dyn Parameter)> {
+        vec![
+            ("part1_moves", &mut self.moves_part1),
+            ("part2_moves", &mut self.moves_part2),
+            ("part2_cups", &mut self.cups_part2),
+        ]
+    }
+
+    fn part1(&self, cups: &Self::Input) -> Answer {
+        let number_of_cups = cups.len();
+        let mut game = Game::new(cups.clone(), number_of_cups);
+        if !game.play(self.moves_part1) {
+            return Answer::empty(cancel::CANCELLED);
+        }
+        let labels = game.result_part1();
+        Answer::new(
+            labels.clone(),
+            format!("labels on the cups after cup 1: {}", labels),
+        )
+    }
+
+    fn part2(&self, cups: &Self::Input) -> Answer {
+        if let Some(answer) = self.too_few_cups(cups) {
+            return answer;
+        }
+        let mut game = Game::new(cups.clone(), self.cups_part2.0);
+        // the moves of part 2 are too many to trace each
+        game.disable_trace();
+        if !game.play(self.moves_part2) {
+            return Answer::empty(cancel::CANCELLED);
+        }
+        let product = game.result_part2();
+        Answer::new(product, format!("Product of star cup labels: {}", product))
+    }
+
+    fn variants(&self) -> Vec<Variant<Self>> {
+        vec![
+            Variant {
+                name: "array",
+                part: Part::One,
+                solve: |day, cups| day.part1_array(cups),
+            },
+            Variant {
+                name: "array",
+                part: Part::Two,
+                solve: |day, cups| day.part2_array(cups),
+            },
+        ]
+    }
+}
+
+impl Day23 {
+    /// Refuses to play part 2 with fewer cups than labeled, which would drop labeled cups.
+    fn too_few_cups(&self, cups: &[u32]) -> Option<Answer> {
+        (self.cups_part2.0 < cups.len()).then(|| {
+            Answer::empty(format!(
+                "Parameter part2_cups is {}, fewer than the {} labeled cups.",
+                self.cups_part2,
+                cups.len()
+            ))
+        })
+    }
+
+    fn part1_array(&self, cups: &[u32]) -> Answer {
+        let next = match play_on_array(cups, cups.len(), self.moves_part1) {
+            Some(next) => next,
+            None => return Answer::empty(cancel::CANCELLED),
+        };
+        let mut labels = String::new();
+        let mut cup = next[1];
+        while cup != 1 {
+            labels.push_str(&cup.to_string());
+            cup = next[cup as usize];
+        }
+        Answer::new(
+            labels.clone(),
+            format!("labels on the cups after cup 1: {}", labels),
+        )
+    }
+
+    fn part2_array(&self, cups: &[u32]) -> Answer {
+        if let Some(answer) = self.too_few_cups(cups) {
+            return answer;
+        }
+        let next = match play_on_array(cups, self.cups_part2.0, self.moves_part2) {
+            Some(next) => next,
+            None => return Answer::empty(cancel::CANCELLED),
+        };
+        let product = next[1] as u64 * next[next[1] as usize] as u64;
+        Answer::new(product, format!("Product of star cup labels: {}", product))
+    }
+}
@@ -0,0 +1,258 @@
+use crate::animation::{Frame, Simulation};
+use crate::answer::Answer;
+use crate::cancel;
+use crate::error::ParseError;
+use crate::input::{self, Line};
+use crate::parameters::Parameter;
+use crate::solution::Solution;
+use crate::trace;
+use crate::Part;
+use std::collections::HashSet;
+
+//        north                    east
+// / 2,-2 \____/ 1, 1 \____/ 0, 4
+// \      /    \      /    \
+//  \____/ 1,-1 \____/ 0, 2 \____/
+//  /    \      /    \      /    \
+// / 1,-3 \____/ 0, 0 \____/-1, 3
+// \      /    \ ref  /    \
+//  \____/ 0,-2 \____/-1, 1 \____/
+//  /    \      /    \      /    \
+// / 0,-4 \____/-1, 1 \____/-2, 2
+// west              south
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Tile {
+    position: (i32, i32),
+}
+
+impl Tile {
+    fn new(line: Line) -> Result<Self, ParseError> {
+        const DIRECTIONS: [(&str, (i32, i32)); 6] = [
+            ("e", (0, 2)),
+            ("se", (-1, 1)),
+            ("sw", (-1, -1)),
+            ("w", (0, -2)),
+            ("nw", (1, -1)),
+            ("ne", (1, 1)),
+        ];
+        let mut position = (0, 0);
+        let mut remaining = line.text;
+        while !remaining.is_empty() {
+            let (direction, offset) = DIRECTIONS
+                .iter()
+                .find(|(direction, _)| remaining.starts_with(direction))
+                .ok_or_else(|| {
+                    line.error_at(remaining, "expected direction e, se, sw, w, nw or ne")
+                })?;
+            position = (position.0 + offset.0, position.1 + offset.1);
+            remaining = &remaining[direction.len()..];
+        }
+        Ok(Self { position })
+    }
+
+    fn neighbors(&self) -> Vec<Tile> {
+        vec![
+            Self {
+                position: (self.position.0 + 1, self.position.1 - 1), // north west
+            },
+            Self {
+                position: (self.position.0 + 1, self.position.1 + 1), // north east
+            },
+            Self {
+                position: (self.position.0, self.position.1 + 2), // east
+            },
+            Self {
+                position: (self.position.0 - 1, self.position.1 + 1), // south east
+            },
+            Self {
+                position: (self.position.0 - 1, self.position.1 - 1), // south west
+            },
+            Self {
+                position: (self.position.0, self.position.1 - 2), // west
+            },
+        ]
+    }
+
+    fn flipped_neighbors(&self, flipped_tiles: &HashSet<Tile>) -> usize {
+        self.neighbors()
+            .into_iter()
+            .filter(|neighbor| flipped_tiles.contains(neighbor))
+            .count()
+    }
+}
+
+fn parse_tiles(input: &str) -> Result<Vec<Tile>, ParseError> {
+    input::parse_lines(input, Tile::new)
+}
+
+fn flip_tiles(tiles: &[Tile]) -> HashSet<Tile> {
+    let mut flipped_tiles = HashSet::new();
+    for tile in tiles.iter().cloned() {
+        if flipped_tiles.contains(&tile) {
+            // tile is black
+            flipped_tiles.remove(&tile);
+        } else {
+            // tile is white
+            flipped_tiles.insert(tile);
+        }
+    }
+    flipped_tiles
+}
+
+/// Flips the tiles according to the rules of the living art exhibit.
+fn flip_daily(flipped_tiles: &HashSet<Tile>) -> HashSet<Tile> {
+    let mut flip_candidates = flipped_tiles.clone();
+    flip_candidates.extend(flipped_tiles.iter().flat_map(|tile| tile.neighbors()));
+
+    let mut flipped_tiles_new = flipped_tiles.clone();
+    for candidate in flip_candidates.into_iter() {
+        let flipped_neighbors = candidate.flipped_neighbors(flipped_tiles);
+        if flipped_tiles.contains(&candidate) {
+            // tile is black
+            if flipped_neighbors == 0 || flipped_neighbors > 2 {
+                flipped_tiles_new.remove(&candidate);
+            }
+        } else {
+            // tile is white
+            if flipped_neighbors == 2 {
+                flipped_tiles_new.insert(candidate);
+            }
+        }
+    }
+    flipped_tiles_new
+}
+
+/// Draws the floor with north at the top, tiles in every other column being shifted by half
+/// a tile like in the diagram above.
+fn render_floor(flipped_tiles: &HashSet<Tile>) -> Vec<String> {
+    let rows = flipped_tiles.iter().map(|tile| tile.position.0);
+    let columns = flipped_tiles.iter().map(|tile| tile.position.1);
+    let (south, north) = (rows.clone().min().unwrap_or(0), rows.max().unwrap_or(-1));
+    let (west, east) = (
+        columns.clone().min().unwrap_or(0),
+        columns.max().unwrap_or(-1),
+    );
+    (south..=north)
+        .rev()
+        .map(|row| {
+            (west..=east)
+                .map(|column| {
+                    let tile = Tile {
+                        position: (row, column),
+                    };
+                    if (row + column) % 2 != 0 {
+                        ' '
+                    } else if flipped_tiles.contains(&tile) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Daily flips of the living art exhibit, ending after the configured number of days.
+struct FloorSimulation {
+    flipped_tiles: HashSet<Tile>,
+    day: u32,
+    number_of_days: u32,
+}
+
+impl Simulation for FloorSimulation {
+    fn frame(&self) -> Frame {
+        Frame {
+            generation: self.day as usize,
+            live: self.flipped_tiles.len(),
+            rows: render_floor(&self.flipped_tiles),
+            hexagonal: true,
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        if self.day == self.number_of_days {
+            return false;
+        }
+        self.flipped_tiles = flip_daily(&self.flipped_tiles);
+        self.day += 1;
+        true
+    }
+}
+
+const NUMBER_OF_DAYS: u32 = 100;
+
+pub struct Day24 {
+    number_of_days: u32,
+}
+
+impl Default for Day24 {
+    fn default() -> Self {
+        Self {
+            number_of_days: NUMBER_OF_DAYS,
+        }
+    }
+}
+
+impl Solution for Day24 {
+    type Input = Vec<Tile>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_tiles(input)
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_lines(input, Tile::new)
+    }
+
+    fn parameters(&mut self) -> Vec<(&'static str, &mut dyn Parameter)> {
+        vec![("days", &mut self.number_of_days)]
+    }
+
+    fn part1(&self, tiles: &Self::Input) -> Answer {
+        let flipped_tiles = flip_tiles(tiles);
+        Answer::new(
+            flipped_tiles.len(),
+            format!("Number of flipped tiles: {}", flipped_tiles.len()),
+        )
+    }
+
+    fn part2(&self, tiles: &Self::Input) -> Answer {
+        let mut flipped_tiles = flip_tiles(tiles);
+        let number_of_days = self.number_of_days;
+        for day in 1..number_of_days + 1 {
+            if cancel::cancelled() {
+                return Answer::empty(cancel::CANCELLED);
+            }
+            flipped_tiles = flip_daily(&flipped_tiles);
+            trace::event(
+                "day24",
+                "day",
+                &[("day", &day), ("black", &flipped_tiles.len())],
+            );
+            trace::walkthrough(format_args!("Day {}: {}", day, flipped_tiles.len()));
+        }
+        Answer::new(
+            flipped_tiles.len(),
+            format!(
+                "Number of flipped tiles after {} days: {}",
+                number_of_days,
+                flipped_tiles.len()
+            ),
+        )
+        .with("days", number_of_days)
+    }
+
+    /// Only the second part is a simulation, starting from the tiles flipped in the first.
+    fn simulation(&self, part: Part, tiles: &Self::Input) -> Option<Box<dyn Simulation>> {
+        match part {
+            Part::One => None,
+            Part::Two => Some(Box::new(FloorSimulation {
+                flipped_tiles: flip_tiles(tiles),
+                day: 0,
+                number_of_days: self.number_of_days,
+            })),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::input::{self, Line};
+use crate::parameters::Parameter;
+use crate::solution::Solution;
+use std::num::NonZeroU32;
+
+const MODULUS: u32 = 20201227;
+const SUBJECT_NUMBER: u32 = 7;
+
+fn parse_public_key(line: Line, modulus: u32) -> Result<u32, ParseError> {
+    let key = line.parse(line.text, "public key")?;
+    if key == 0 || key >= modulus {
+        return Err(line.error(format!(
+            "public key {} is not in the range 1 to {}",
+            key,
+            modulus - 1
+        )));
+    }
+    Ok(key)
+}
+
+fn parse_public_keys(input: &str, modulus: u32) -> Result<Vec<u32>, ParseError> {
+    let public_keys = input::parse_lines(input, |line| parse_public_key(line, modulus))?;
+    match public_keys.len() {
+        2 => Ok(public_keys),
+        n => Err(input::end_of_input(
+            input,
+            format!("found {} public keys, expected 2", n),
+        )),
+    }
+}
+
+/// Loop sizes transforming the subject number into the public keys, if there are any.
+fn calculate_loop_sizes(
+    public_keys: &[u32],
+    subject_number: u32,
+    modulus: u32,
+) -> Option<Vec<usize>> {
+    public_keys
+        .iter()
+        .map(|&key| {
+            let mut loop_size = 0;
+            let mut value = 1u64;
+            while value != key as u64 {
+                // the values repeat after at most as many steps as the modulus
+                if loop_size == modulus as usize {
+                    return None;
+                }
+                value *= subject_number as u64;
+                value %= modulus as u64;
+                loop_size += 1;
+            }
+            Some(loop_size)
+        })
+        .collect()
+}
+
+fn calculate_encryption_key(public_key: u32, loop_size: usize, modulus: u32) -> u32 {
+    let mut value = 1u64;
+    for _ in 0..loop_size {
+        value *= public_key as u64;
+        value %= modulus as u64;
+    }
+    value as u32
+}
+
+pub struct Day25 {
+    modulus: NonZeroU32,
+    subject_number: u32,
+}
+
+impl Default for Day25 {
+    fn default() -> Self {
+        Self {
+            modulus: NonZeroU32::new(MODULUS).unwrap(),
+            subject_number: SUBJECT_NUMBER,
+        }
+    }
+}
+
+impl Solution for Day25 {
+    type Input = Vec<u32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_public_keys(input, self.modulus.get())
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        input::check_lines(input, |line| parse_public_key(line, self.modulus.get()))
+    }
+
+    fn parameters(&mut self) -> Vec<(&'static str, &mut dyn Parameter)> {
+        vec![
+            ("modulus", &mut self.modulus),
+            ("subject_number", &mut self.subject_number),
+        ]
+    }
+
+    fn part1(&self, public_keys: &Self::Input) -> Answer {
+        let modulus = self.modulus.get();
+        let loop_sizes = match calculate_loop_sizes(public_keys, self.subject_number, modulus) {
+            Some(loop_sizes) => loop_sizes,
+            None => return Answer::empty("No loop size yields the public keys."),
+        };
+        let encryption_key = calculate_encryption_key(public_keys[0], loop_sizes[1], modulus);
+        Answer::new(encryption_key, format!("Encryption key {}", encryption_key))
+            .with("card_loop_size", loop_sizes[0])
+            .with("door_loop_size", loop_sizes[1])
+    }
+
+    fn part2(&self, _public_keys: &Self::Input) -> Answer {
+        Answer::empty("There is no second puzzle on day 25.")
+    }
+}
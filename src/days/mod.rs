@@ -0,0 +1,458 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use crate::animation::Simulation;
+use crate::answer::Answer;
+use crate::audit::Audit;
+use crate::bench::{Benchmark, Phase, Stats};
+use crate::cancel;
+use crate::error;
+use crate::input;
+use crate::parameters::Parameters;
+use crate::solution::DynSolution;
+use crate::summary::{Outcome, Status};
+use crate::trace;
+use crate::Part;
+use std::any::Any;
+use std::env;
+use std::error::Error;
+use std::iter;
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const NUMBER_OF_DAYS: u32 = 25;
+
+pub fn solution(day: u32) -> Option<Box<dyn DynSolution>> {
+    let solution: Box<dyn DynSolution> = match day {
+        1 => Box::new(day01::Day01::default()),
+        2 => Box::new(day02::Day02::default()),
+        3 => Box::new(day03::Day03::default()),
+        4 => Box::new(day04::Day04),
+        5 => Box::new(day05::Day05),
+        6 => Box::new(day06::Day06),
+        7 => Box::new(day07::Day07::default()),
+        8 => Box::new(day08::Day08),
+        9 => Box::new(day09::Day09::default()),
+        10 => Box::new(day10::Day10::default()),
+        11 => Box::new(day11::Day11),
+        12 => Box::new(day12::Day12),
+        13 => Box::new(day13::Day13),
+        14 => Box::new(day14::Day14),
+        15 => Box::new(day15::Day15),
+        16 => Box::new(day16::Day16),
+        17 => Box::new(day17::Day17::default()),
+        18 => Box::new(day18::Day18),
+        19 => Box::new(day19::Day19),
+        20 => Box::new(day20::Day20),
+        21 => Box::new(day21::Day21),
+        22 => Box::new(day22::Day22),
+        23 => Box::new(day23::Day23::default()),
+        24 => Box::new(day24::Day24::default()),
+        25 => Box::new(day25::Day25::default()),
+        _ => return None,
+    };
+    Some(solution)
+}
+
+/// Environment variable overriding the directory of the default puzzle inputs.
+pub const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+
+/// Default input file of a day, located in `$AOC_INPUT_DIR` or otherwise in `inputs`.
+pub fn default_input(day: u32) -> String {
+    let directory = env::var(INPUT_DIR_VARIABLE).unwrap_or_else(|_| "inputs".to_owned());
+    format!("{}/day-{:02}.txt", directory.trim_end_matches('/'), day)
+}
+
+/// Solution of a day with its puzzle parameters changed to the given values.
+pub fn lookup(day: u32, parameters: &Parameters) -> Result<Box<dyn DynSolution>, String> {
+    let mut solution = solution(day)
+        .ok_or_else(|| format!("invalid day {}, expected 1 to {}", day, NUMBER_OF_DAYS))?;
+    parameters.apply(day, solution.as_mut())?;
+    Ok(solution)
+}
+
+/// Reads a puzzle input file, `-` denoting the standard input.
+pub fn read_input(filename: &str) -> Result<String, error::Error> {
+    input::read_to_string(filename).map_err(|err| error::Error::Io(filename.to_owned(), err))
+}
+
+/// Parses a puzzle input read from the given file, attaching the file name to parse errors.
+pub fn parse_input(
+    solution: &dyn DynSolution,
+    input: &str,
+    filename: &str,
+) -> Result<Box<dyn Any>, error::Error> {
+    solution.parse(input).map_err(|err| match filename {
+        input::STDIN => error::Error::Parse(err.in_file("<stdin>")),
+        _ => error::Error::Parse(err.in_file(filename)),
+    })
+}
+
+/// Solves a part of a day's puzzle, tracing the answer along with the time taken.
+fn solve_part(day: u32, solution: &dyn DynSolution, part: Part, input: &dyn Any) -> Answer {
+    let start = Instant::now();
+    let answer = solution.solve(part, input);
+    let time = format!("{:?}", start.elapsed());
+    let fields: [(&str, &dyn std::fmt::Display); 3] =
+        [("part", &part), ("answer", answer.value()), ("time", &time)];
+    trace::event(&format!("day{:02}", day), "solved", &fields);
+    answer
+}
+
+/// Parses the given input file and solves the requested parts of a day's puzzle, using the
+/// given values of its puzzle parameters.
+pub fn solve(
+    day: u32,
+    filename: &str,
+    parts: &[Part],
+    parameters: &Parameters,
+) -> Result<Vec<(Part, Answer)>, Box<dyn Error>> {
+    let solution = lookup(day, parameters)?;
+    let input = parse_input(solution.as_ref(), &read_input(filename)?, filename)?;
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            (
+                part,
+                solve_part(day, solution.as_ref(), part, input.as_ref()),
+            )
+        })
+        .collect())
+}
+
+/// Parses the given input file and sets up the simulation of a day's puzzle, of the given part
+/// or otherwise of the first part having one.
+pub fn simulation(
+    day: u32,
+    filename: &str,
+    part: Option<Part>,
+    parameters: &Parameters,
+) -> Result<(Part, Box<dyn Simulation>), Box<dyn Error>> {
+    let solution = lookup(day, parameters)?;
+    let input = parse_input(solution.as_ref(), &read_input(filename)?, filename)?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let simulation = parts
+        .into_iter()
+        .find_map(|part| Some((part, solution.simulation(part, input.as_ref())?)));
+    simulation.ok_or_else(|| {
+        match part {
+            Some(part) => format!("day {} part {} has no simulation", day, part),
+            None => format!("day {} has no simulation", day),
+        }
+        .into()
+    })
+}
+
+/// Audits the records of the given input file against the rules of the selected parts.
+pub fn audit(
+    day: u32,
+    filename: &str,
+    parts: &[Part],
+    parameters: &Parameters,
+) -> Result<Vec<(Part, Audit)>, Box<dyn Error>> {
+    let solution = lookup(day, parameters)?;
+    let input = read_input(filename)?;
+    let name = match filename {
+        input::STDIN => "<stdin>",
+        _ => filename,
+    };
+    parts
+        .iter()
+        .map(|&part| match solution.audit(part, &input) {
+            Some(mut audit) => {
+                audit.malformed = audit
+                    .malformed
+                    .into_iter()
+                    .map(|err| err.in_file(name))
+                    .collect();
+                Ok((part, audit))
+            }
+            None => Err(format!("day {} part {} has no audit", day, part).into()),
+        })
+        .collect()
+}
+
+/// Checks the given input file of a day's puzzle without solving it, returning all problems
+/// found with the file name attached.
+pub fn check_input(day: u32, filename: &str) -> Result<Vec<error::ParseError>, Box<dyn Error>> {
+    let solution = lookup(day, &Parameters::new())?;
+    let name = match filename {
+        input::STDIN => "<stdin>",
+        _ => filename,
+    };
+    Ok(solution
+        .check(&read_input(filename)?)
+        .into_iter()
+        .map(|err| err.in_file(name))
+        .collect())
+}
+
+/// Times parsing and solving of a day's puzzle over the given number of repetitions.
+pub fn benchmark(
+    day: u32,
+    filename: &str,
+    parts: &[Part],
+    repeats: usize,
+) -> Result<Benchmark, Box<dyn Error>> {
+    let solution = lookup(day, &Parameters::new())?;
+    let text = read_input(filename)?;
+    let mut samples = vec![Vec::with_capacity(repeats); parts.len() + 1];
+    for _ in 0..repeats {
+        let start = Instant::now();
+        let input = parse_input(solution.as_ref(), &text, filename)?;
+        samples[0].push(start.elapsed());
+        for (&part, part_samples) in parts.iter().zip(&mut samples[1..]) {
+            let start = Instant::now();
+            solution.solve(part, input.as_ref());
+            part_samples.push(start.elapsed());
+        }
+    }
+    let phases = iter::once(Phase::Parse)
+        .chain(parts.iter().map(|&part| Phase::Part(part)))
+        .zip(samples.into_iter().map(Stats::new))
+        .collect();
+    Ok(Benchmark {
+        day,
+        input: filename.to_owned(),
+        fingerprint: input::fingerprint(&text),
+        phases,
+    })
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast_ref::<&str>() {
+            Some(message) => (*message).to_owned(),
+            None => "unknown panic".to_owned(),
+        },
+    }
+}
+
+/// Solves the parts on the current thread like `solve`, sending the outcome of each part once
+/// done and catching errors and panics.
+fn solve_parts(
+    day: u32,
+    filename: &str,
+    parts: &[Part],
+    parameters: &Parameters,
+    done: &mpsc::Sender<Outcome>,
+) {
+    let outcome = |part, answer, time, status| Outcome {
+        day,
+        part,
+        answer,
+        time,
+        status,
+    };
+    let parsed = panic::catch_unwind(|| -> Result<_, Box<dyn Error>> {
+        let solution = lookup(day, parameters)?;
+        let input = parse_input(solution.as_ref(), &read_input(filename)?, filename)?;
+        Ok((solution, input))
+    });
+    let (solution, input) = match parsed {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(err)) => {
+            let status = Status::Failed(err.to_string());
+            for &part in parts {
+                let _ = done.send(outcome(part, None, Duration::ZERO, status.clone()));
+            }
+            return;
+        }
+        Err(payload) => {
+            let status = Status::Panicked(panic_message(payload));
+            for &part in parts {
+                let _ = done.send(outcome(part, None, Duration::ZERO, status.clone()));
+            }
+            return;
+        }
+    };
+    for &part in parts {
+        // the runner has given up on the remaining parts
+        if cancel::cancelled() {
+            return;
+        }
+        let start = Instant::now();
+        let answer = panic::catch_unwind(AssertUnwindSafe(|| {
+            solve_part(day, solution.as_ref(), part, input.as_ref())
+        }));
+        let time = start.elapsed();
+        let _ = done.send(match answer {
+            Ok(answer) => outcome(part, Some(answer), time, Status::Solved),
+            Err(payload) => outcome(part, None, time, Status::Panicked(panic_message(payload))),
+        });
+    }
+}
+
+/// Solves the requested parts of a day's puzzle like `solve`, but reports errors and panics
+/// as outcomes of the affected parts instead of propagating them. Parts not solved within the
+/// time budget of the day are cancelled and reported as timed out.
+pub fn solve_isolated(
+    day: u32,
+    filename: &str,
+    parts: &[Part],
+    parameters: &Parameters,
+    budget: Option<Duration>,
+) -> Vec<Outcome> {
+    let token = cancel::Token::new();
+    let (done, outcomes) = mpsc::channel();
+    let worker = {
+        let (token, filename) = (token.clone(), filename.to_owned());
+        let (parts, parameters) = (parts.to_vec(), parameters.clone());
+        move || {
+            cancel::with_token(token, || {
+                solve_parts(day, &filename, &parts, &parameters, &done)
+            })
+        }
+    };
+    // a solver ignoring the cancellation keeps running detached until the process exits
+    let spawned = thread::Builder::new()
+        .name(format!("day{:02}", day))
+        .spawn(worker);
+    if let Err(err) = spawned {
+        let status = Status::Failed(format!("cannot start solver: {}", err));
+        return parts
+            .iter()
+            .map(|&part| Outcome {
+                day,
+                part,
+                answer: None,
+                time: Duration::ZERO,
+                status: status.clone(),
+            })
+            .collect();
+    }
+
+    // budgets beyond the range of the clock never run out
+    let deadline = budget.and_then(|budget| Instant::now().checked_add(budget));
+    let mut solved = Vec::new();
+    let mut unsolved = Status::Solved;
+    while solved.len() < parts.len() {
+        let received = match deadline {
+            Some(deadline) => {
+                outcomes.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => outcomes.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match received {
+            Ok(outcome) => solved.push(outcome),
+            Err(RecvTimeoutError::Timeout) => {
+                token.cancel();
+                let budget = budget.unwrap_or_default();
+                trace::event(
+                    &format!("day{:02}", day),
+                    "timed_out",
+                    &[("budget", &format_args!("{:?}", budget))],
+                );
+                unsolved = Status::TimedOut(format!("not solved within {:?}", budget));
+                break;
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                unsolved = Status::Failed("solver stopped without an answer".to_owned());
+                break;
+            }
+        }
+    }
+    let time = match unsolved {
+        Status::TimedOut(_) => budget.unwrap_or_default(),
+        _ => Duration::ZERO,
+    };
+    for &part in &parts[solved.len()..] {
+        solved.push(Outcome {
+            day,
+            part,
+            answer: None,
+            time,
+            status: unsolved.clone(),
+        });
+    }
+    solved
+}
+
+/// Solves the given days on their default inputs using up to `jobs` threads, returning the
+/// outcomes ordered by day and part.
+pub fn solve_all(
+    days: &[u32],
+    parts: &[Part],
+    jobs: usize,
+    parameters: &Parameters,
+    budget: Option<Duration>,
+) -> Vec<Outcome> {
+    let next = AtomicUsize::new(0);
+    let outcomes = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let day_outcomes =
+                        solve_isolated(day, &default_input(day), parts, parameters, budget);
+                    outcomes.lock().unwrap().extend(day_outcomes);
+                }
+            });
+        }
+    });
+    let mut outcomes = outcomes.into_inner().unwrap();
+    outcomes.sort_by_key(|outcome| (outcome.day, outcome.part));
+    outcomes
+}
+
+/// Entry point of the per-day binaries, printing the answers for the input file given as
+/// argument, `-` for stdin, or the default input, and tracing with `-v` or `-vv`.
+pub fn main(day: u32) {
+    let mut args: Vec<_> = env::args().skip(1).collect();
+    let mut verbosity = 0;
+    while let Some(index) = args.iter().position(|arg| arg == "-v" || arg == "-vv") {
+        verbosity += args.remove(index).len() - 1;
+    }
+    trace::set_level(trace::Level::from_verbosity(verbosity));
+    let filename = match args.as_slice() {
+        [] => default_input(day),
+        [filename] if !filename.starts_with('-') || filename == input::STDIN => filename.clone(),
+        _ => {
+            eprintln!("Usage: day-{:02} [-v | -vv] [<PATH> | -]", day);
+            process::exit(1);
+        }
+    };
+    match solve(day, &filename, &Part::ALL, &Parameters::new()) {
+        Ok(answers) => {
+            for (part, answer) in answers {
+                println!("Part {}: {}", part, answer.description());
+            }
+        }
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            process::exit(1);
+        }
+    }
+}
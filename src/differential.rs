@@ -0,0 +1,112 @@
+//! Differential testing of the solutions against their variants, shrinking inputs on which
+//! they disagree.
+
+use crate::answer::Value;
+use crate::error::ParseError;
+use crate::solution::DynSolution;
+use crate::Part;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Name under which the answers of the solution itself are reported.
+pub const SOLUTION: &str = "solution";
+
+/// Answer value of an implementation, `None` if it panicked.
+pub type Outcome = Option<Value>;
+
+/// Part on which the implementations disagree.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Divergence {
+    pub part: Part,
+    /// Smallest input found by dropping lines on which the implementations still disagree.
+    pub input: String,
+    /// Outcomes of the solution and its variants on that input.
+    pub outcomes: Vec<(&'static str, Outcome)>,
+}
+
+/// Parts of the solution having variants.
+pub fn parts(solution: &dyn DynSolution) -> Vec<Part> {
+    Part::ALL
+        .iter()
+        .cloned()
+        .filter(|&part| solution.variants().iter().any(|&(p, _)| p == part))
+        .collect()
+}
+
+/// Outcomes of all implementations of a part on the input, `None` if it cannot be parsed.
+fn outcomes(
+    solution: &dyn DynSolution,
+    part: Part,
+    input: &str,
+) -> Option<Vec<(&'static str, Outcome)>> {
+    let input = solution.parse(input).ok()?;
+    let run = |solve: &dyn Fn() -> Option<Value>| {
+        panic::catch_unwind(AssertUnwindSafe(solve)).unwrap_or(None)
+    };
+    let mut outcomes = vec![(
+        SOLUTION,
+        run(&|| Some(solution.solve(part, input.as_ref()).value().clone())),
+    )];
+    for (_, name) in solution.variants().into_iter().filter(|&(p, _)| p == part) {
+        let outcome = run(&|| {
+            let answer = solution.solve_variant(part, name, input.as_ref())?;
+            Some(answer.value().clone())
+        });
+        outcomes.push((name, outcome));
+    }
+    Some(outcomes)
+}
+
+fn diverges(outcomes: &[(&'static str, Outcome)]) -> bool {
+    outcomes
+        .iter()
+        .any(|(_, outcome)| *outcome != outcomes[0].1)
+}
+
+/// Shrinks an input on which the implementations of a part disagree by dropping ever
+/// smaller runs of lines, as long as the rest still parses and still yields a disagreement.
+pub fn minimize(solution: &dyn DynSolution, part: Part, input: &str) -> String {
+    let disagree = |lines: &[&str]| {
+        let input: String = lines.iter().map(|line| format!("{}\n", line)).collect();
+        outcomes(solution, part, &input).is_some_and(|outcomes| diverges(&outcomes))
+    };
+    let mut lines: Vec<_> = input.lines().collect();
+    let mut chunk = lines.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        let mut shrunk = false;
+        while start < lines.len() {
+            let end = (start + chunk).min(lines.len());
+            let candidate: Vec<_> = [&lines[..start], &lines[end..]].concat();
+            if !candidate.is_empty() && disagree(&candidate) {
+                lines = candidate;
+                shrunk = true;
+            } else {
+                start += chunk;
+            }
+        }
+        if !shrunk {
+            chunk /= 2;
+        }
+    }
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+/// Runs the solution and its variants on the input, returning the parts on which they
+/// disagree along with the smallest input found for each.
+pub fn compare(solution: &dyn DynSolution, input: &str) -> Result<Vec<Divergence>, ParseError> {
+    solution.parse(input)?;
+    let mut divergences = Vec::new();
+    for part in parts(solution) {
+        let diverging = outcomes(solution, part, input).filter(|outcomes| diverges(outcomes));
+        if diverging.is_some() {
+            let input = minimize(solution, part, input);
+            let outcomes = outcomes(solution, part, &input).unwrap_or_default();
+            divergences.push(Divergence {
+                part,
+                input,
+                outcomes,
+            });
+        }
+    }
+    Ok(divergences)
+}
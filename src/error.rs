@@ -0,0 +1,101 @@
+use std::error;
+use std::fmt;
+use std::io;
+
+/// Error in a puzzle input, pointing to the offending text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    file: Option<String>,
+    line: usize,
+    column: usize,
+    text: String,
+    message: String,
+}
+
+impl ParseError {
+    /// Creates an error for the given 1-based line and column of the input.
+    pub fn new(line: usize, column: usize, text: &str, message: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            text: text.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    pub fn in_file(mut self, filename: &str) -> Self {
+        self.file = Some(filename.to_owned());
+        self
+    }
+
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        writeln!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+        let number = self.line.to_string();
+        writeln!(f, "{} | {}", number, self.text)?;
+        write!(
+            f,
+            "{} | {}^",
+            " ".repeat(number.len()),
+            " ".repeat(self.column - 1)
+        )
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Error loading a puzzle input from a file.
+#[derive(Debug)]
+pub enum Error {
+    Io(String, io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(filename, err) => write!(f, "{}: {}", filename, err),
+            Error::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(_, err) => Some(err),
+            Error::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
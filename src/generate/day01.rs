@@ -0,0 +1,67 @@
+use super::{lines, Generated, Rng};
+use crate::Part;
+use std::collections::HashSet;
+
+const TARGET: u32 = 2020;
+
+fn completes_pair(entries: &HashSet<u32>, entry: u32) -> bool {
+    entry <= TARGET && entries.contains(&(TARGET - entry))
+}
+
+fn completes_triple(entries: &HashSet<u32>, entry: u32) -> bool {
+    entries
+        .iter()
+        .any(|&other| other + entry <= TARGET && entries.contains(&(TARGET - other - entry)))
+}
+
+/// Distinct entries with exactly one pair and one triple summing to 2020, all other entries
+/// drawn below 2020 as long as they do not complete another pair or triple.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let (pair, triple) = loop {
+        let a = rng.between(1, TARGET as u64 - 1) as u32;
+        let b = rng.between(1, TARGET as u64 - 2) as u32;
+        let c = rng.between(1, (TARGET - b - 1) as u64) as u32;
+        let planted = [a, TARGET - a, b, c, TARGET - b - c];
+        let distinct = planted.iter().collect::<HashSet<_>>().len() == planted.len();
+        let pairs = (0..5)
+            .flat_map(|i| (i + 1..5).map(move |j| (i, j)))
+            .filter(|&(i, j)| planted[i] + planted[j] == TARGET)
+            .count();
+        let triples = (0..5)
+            .flat_map(|i| (i + 1..5).flat_map(move |j| (j + 1..5).map(move |k| (i, j, k))))
+            .filter(|&(i, j, k)| planted[i] + planted[j] + planted[k] == TARGET)
+            .count();
+        // the planted pair and triple must be the only ones
+        if distinct && pairs == 1 && triples == 1 {
+            break ([a, TARGET - a], [b, c, TARGET - b - c]);
+        }
+    };
+
+    let mut entries: Vec<_> = pair.iter().chain(&triple).cloned().collect();
+    let mut set: HashSet<_> = entries.iter().cloned().collect();
+    let mut small = true;
+    while entries.len() < size {
+        let candidate = match small {
+            true => (0..100)
+                .map(|_| rng.between(1, TARGET as u64 - 1) as u32)
+                .find(|&x| {
+                    !set.contains(&x) && !completes_pair(&set, x) && !completes_triple(&set, x)
+                }),
+            false => None,
+        };
+        // entries above 2020 never take part in a sum, once the small ones are exhausted
+        small = candidate.is_some();
+        let entry = candidate.unwrap_or_else(|| loop {
+            let x = rng.between(TARGET as u64 + 1, 99_999) as u32;
+            if !set.contains(&x) {
+                break x;
+            }
+        });
+        set.insert(entry);
+        entries.push(entry);
+    }
+    rng.shuffle(&mut entries);
+    Generated::new(lines(entries.iter().map(u32::to_string)))
+        .with_answer(Part::One, pair[0] * pair[1])
+        .with_answer(Part::Two, triple[0] * triple[1] * triple[2])
+}
@@ -0,0 +1,36 @@
+use super::{lines, Generated, Rng};
+use crate::Part;
+
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
+
+/// Passwords with policies, the letter of the policy appearing about as often as the policy
+/// requires so that both schemes accept a fair share of them.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let (mut valid1, mut valid2) = (0, 0);
+    let passwords: Vec<_> = (0..size)
+        .map(|_| {
+            let length = rng.between(3, 20) as usize;
+            let first = rng.between(1, length as u64 - 1) as usize;
+            let second = rng.between(first as u64 + 1, length as u64) as usize;
+            let letter = rng.string(1, LETTERS);
+            let mut password: Vec<_> = rng.string(length, LETTERS).chars().collect();
+            for _ in 0..rng.between(0, second as u64 + 1) {
+                password[rng.index(length)] = letter.chars().next().unwrap();
+            }
+            let password: String = password.into_iter().collect();
+
+            let count = password.matches(letter.as_str()).count();
+            if (first..=second).contains(&count) {
+                valid1 += 1;
+            }
+            let at = |position: usize| password[position - 1..].starts_with(letter.as_str());
+            if at(first) != at(second) {
+                valid2 += 1;
+            }
+            format!("{}-{} {}: {}", first, second, letter, password)
+        })
+        .collect();
+    Generated::new(lines(passwords))
+        .with_answer(Part::One, valid1)
+        .with_answer(Part::Two, valid2)
+}
@@ -0,0 +1,29 @@
+use super::{lines, Generated, Rng};
+use crate::Part;
+
+const WIDTH: usize = 31;
+const SLOPES: [(usize, usize); 5] = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+/// Map of about a fifth trees, repeating to the right.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let rows: Vec<Vec<bool>> = (0..size)
+        .map(|_| (0..WIDTH).map(|_| rng.chance(0.2)).collect())
+        .collect();
+    let trees = |(right, down): (usize, usize)| {
+        (0..size)
+            .step_by(down)
+            .filter(|&y| rows[y][y / down * right % WIDTH])
+            .count()
+    };
+    let input = lines(rows.iter().map(|row| {
+        row.iter()
+            .map(|&tree| if tree { '#' } else { '.' })
+            .collect::<String>()
+    }));
+    Generated::new(input)
+        .with_answer(Part::One, trees((3, 1)))
+        .with_answer(
+            Part::Two,
+            SLOPES.iter().map(|&slope| trees(slope)).product::<usize>(),
+        )
+}
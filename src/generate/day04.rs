@@ -0,0 +1,83 @@
+use super::{Generated, Rng};
+use crate::Part;
+
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+fn valid_value(rng: &mut Rng, field: &str) -> String {
+    match field {
+        "byr" => rng.between(1920, 2002).to_string(),
+        "iyr" => rng.between(2010, 2020).to_string(),
+        "eyr" => rng.between(2020, 2030).to_string(),
+        "hgt" if rng.chance(0.5) => format!("{}cm", rng.between(150, 193)),
+        "hgt" => format!("{}in", rng.between(59, 76)),
+        "hcl" => format!("#{}", rng.string(6, "0123456789abcdef")),
+        "ecl" => rng.choose(&EYE_COLORS).to_string(),
+        "pid" => rng.string(9, "0123456789"),
+        _ => rng.between(100, 350).to_string(),
+    }
+}
+
+fn invalid_value(rng: &mut Rng, field: &str) -> String {
+    match field {
+        "byr" => rng.choose(&[1900, 1919, 2003, 2020]).to_string(),
+        "iyr" => rng.choose(&[2000, 2009, 2021, 2030]).to_string(),
+        "eyr" => rng.choose(&[2010, 2019, 2031, 2040]).to_string(),
+        "hgt" => rng
+            .choose(&["149cm", "194cm", "58in", "77in", "170", "65"])
+            .to_string(),
+        "hcl" => rng
+            .choose(&["123abc", "#123abz", "#12345", "#1234567"])
+            .to_string(),
+        "ecl" => rng.choose(&["wat", "gmt", "xry", "zzz"]).to_string(),
+        _ => {
+            let length = *rng.choose(&[8, 10]);
+            rng.string(length, "0123456789")
+        }
+    }
+}
+
+/// Passports of which a third each are valid, have an invalid value or miss a required
+/// field, with the fields shuffled and wrapped over up to four lines.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let (mut complete, mut valid) = (0, 0);
+    let passports: Vec<_> = (0..size)
+        .map(|_| {
+            let mut fields: Vec<_> = REQUIRED.to_vec();
+            if rng.chance(0.5) {
+                fields.push("cid");
+            }
+            let mut values: Vec<_> = fields
+                .iter()
+                .map(|&field| format!("{}:{}", field, valid_value(rng, field)))
+                .collect();
+            match rng.below(3) {
+                0 => {
+                    complete += 1;
+                    valid += 1;
+                }
+                1 => {
+                    let index = rng.index(REQUIRED.len());
+                    let value = invalid_value(rng, fields[index]);
+                    values[index] = format!("{}:{}", fields[index], value);
+                    complete += 1;
+                }
+                _ => {
+                    values.remove(rng.index(REQUIRED.len()));
+                }
+            }
+            rng.shuffle(&mut values);
+            let mut text = String::new();
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    text.push(if rng.chance(0.25) { '\n' } else { ' ' });
+                }
+                text.push_str(value);
+            }
+            text + "\n"
+        })
+        .collect();
+    Generated::new(passports.join("\n"))
+        .with_answer(Part::One, complete)
+        .with_answer(Part::Two, valid)
+}
@@ -0,0 +1,26 @@
+use super::{lines, Generated, Rng};
+use crate::Part;
+
+fn boarding_pass(seat_id: u64) -> String {
+    (0..10)
+        .rev()
+        .map(|bit| match (bit, seat_id >> bit & 1) {
+            (3..=9, 1) => 'B',
+            (3..=9, _) => 'F',
+            (_, 1) => 'R',
+            _ => 'L',
+        })
+        .collect()
+}
+
+/// Boarding passes of a contiguous range of seats, missing only one seat inside the range.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let lowest = rng.between(1, 1022 - size as u64);
+    let highest = lowest + size as u64;
+    let mine = rng.between(lowest + 1, highest - 1);
+    let mut seat_ids: Vec<_> = (lowest..=highest).filter(|&id| id != mine).collect();
+    rng.shuffle(&mut seat_ids);
+    Generated::new(lines(seat_ids.into_iter().map(boarding_pass)))
+        .with_answer(Part::One, highest)
+        .with_answer(Part::Two, mine)
+}
@@ -0,0 +1,42 @@
+use super::{Generated, Rng};
+use crate::Part;
+
+const QUESTIONS: &str = "abcdefghijklmnopqrstuvwxyz";
+
+/// Groups of up to five people, each answering "yes" to a few questions, mostly ones shared
+/// within the group so that some questions are answered by everyone.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let (mut anyone, mut everyone) = (0, 0);
+    let groups: Vec<_> = (0..size)
+        .map(|_| {
+            let length = rng.between(1, 10) as usize;
+            let shared = rng.string(length, QUESTIONS);
+            let people: Vec<String> = (0..rng.between(1, 5))
+                .map(|_| {
+                    let mut answers: Vec<_> = shared.chars().filter(|_| rng.chance(0.8)).collect();
+                    let extra = rng.index(4);
+                    answers.extend(rng.string(extra, QUESTIONS).chars());
+                    if answers.is_empty() {
+                        answers.push(shared.chars().next().unwrap());
+                    }
+                    answers.sort_unstable();
+                    answers.dedup();
+                    rng.shuffle(&mut answers);
+                    answers.into_iter().collect()
+                })
+                .collect();
+            anyone += QUESTIONS
+                .chars()
+                .filter(|&q| people.iter().any(|p| p.contains(q)))
+                .count();
+            everyone += QUESTIONS
+                .chars()
+                .filter(|&q| people.iter().all(|p| p.contains(q)))
+                .count();
+            people.join("\n") + "\n"
+        })
+        .collect();
+    Generated::new(groups.join("\n"))
+        .with_answer(Part::One, anyone)
+        .with_answer(Part::Two, everyone)
+}
@@ -0,0 +1,128 @@
+use super::{lines, Generated, Rng};
+use crate::Part;
+use std::collections::{HashMap, HashSet};
+
+const ADJECTIVES: [&str; 33] = [
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy", "dashed", "dusty",
+    "pink", "pastel", "soft", "deep", "neat", "rich", "bleached", "glossy", "frosted", "tinted",
+    "mellow", "smoky", "hazy",
+];
+const COLORS: [&str; 33] = [
+    "aqua",
+    "beige",
+    "black",
+    "blue",
+    "bronze",
+    "brown",
+    "chartreuse",
+    "coral",
+    "crimson",
+    "cyan",
+    "fuchsia",
+    "gold",
+    "gray",
+    "green",
+    "indigo",
+    "lavender",
+    "lime",
+    "magenta",
+    "maroon",
+    "olive",
+    "orange",
+    "plum",
+    "purple",
+    "red",
+    "salmon",
+    "silver",
+    "tan",
+    "teal",
+    "tomato",
+    "turquoise",
+    "violet",
+    "white",
+    "yellow",
+];
+const TARGET: &str = "shiny gold";
+const LEVELS: usize = 6;
+
+/// Acyclic bag rules: every color gets a level and only contains colors of lower levels,
+/// the shiny gold bag sitting in the middle.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut colors: Vec<_> = ADJECTIVES
+        .iter()
+        .flat_map(|adjective| {
+            COLORS
+                .iter()
+                .map(move |color| format!("{} {}", adjective, color))
+        })
+        .filter(|color| color != TARGET)
+        .collect();
+    rng.shuffle(&mut colors);
+    colors.truncate(size - 1);
+    colors.push(TARGET.to_owned());
+    let levels: Vec<_> = colors
+        .iter()
+        .map(|color| match color.as_str() {
+            TARGET => LEVELS / 2,
+            _ => rng.index(LEVELS),
+        })
+        .collect();
+
+    let mut contents: HashMap<&str, Vec<(u64, &str)>> = HashMap::new();
+    for (color, &level) in colors.iter().zip(&levels) {
+        let lower: Vec<_> = colors
+            .iter()
+            .zip(&levels)
+            .filter(|&(_, &other)| other < level)
+            .map(|(color, _)| color.as_str())
+            .collect();
+        let mut inner = Vec::new();
+        if !lower.is_empty() {
+            for _ in 0..rng.below(5) {
+                let bag = *rng.choose(&lower);
+                if inner.iter().all(|&(_, other)| other != bag) {
+                    inner.push((rng.between(1, 5), bag));
+                }
+            }
+        }
+        contents.insert(color, inner);
+    }
+
+    let mut containers = HashSet::new();
+    let mut queue = vec![TARGET];
+    while let Some(bag) = queue.pop() {
+        for (&color, inner) in &contents {
+            if inner.iter().any(|&(_, other)| other == bag) && containers.insert(color) {
+                queue.push(color);
+            }
+        }
+    }
+    fn count(contents: &HashMap<&str, Vec<(u64, &str)>>, bag: &str) -> u64 {
+        contents[bag]
+            .iter()
+            .map(|&(amount, inner)| amount * (1 + count(contents, inner)))
+            .sum()
+    }
+
+    let mut rules: Vec<_> = colors
+        .iter()
+        .map(|color| {
+            let inner: Vec<_> = contents[color.as_str()]
+                .iter()
+                .map(|&(amount, bag)| match amount {
+                    1 => format!("1 {} bag", bag),
+                    _ => format!("{} {} bags", amount, bag),
+                })
+                .collect();
+            match inner.is_empty() {
+                true => format!("{} bags contain no other bags.", color),
+                false => format!("{} bags contain {}.", color, inner.join(", ")),
+            }
+        })
+        .collect();
+    rng.shuffle(&mut rules);
+    Generated::new(lines(rules))
+        .with_answer(Part::One, containers.len())
+        .with_answer(Part::Two, count(&contents, TARGET))
+}
@@ -0,0 +1,70 @@
+use super::{lines, Generated, Rng};
+use crate::Part;
+use std::collections::HashSet;
+
+/// Random argument of a `jmp` or `nop` at `index`, targeting an instruction up to `limit`.
+fn target(rng: &mut Rng, index: usize, limit: usize) -> i64 {
+    rng.between(0, limit as u64) as i64 - index as i64
+}
+
+/// Program running into a loop, which is fixed only by turning the `jmp` at one index into
+/// a `nop`: all jumps before it, when taken, stay before it, and a forward jump skipping
+/// dead code separates the straight path from the instructions never executed.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let fix = rng.between(size as u64 / 2, size as u64 - 1) as usize;
+    let mut program = Vec::new();
+    let mut path_sum = 0;
+    while program.len() < fix {
+        let index = program.len();
+        let dead = rng.between(1, 3) as usize;
+        if index + dead < fix && rng.chance(0.1) {
+            program.push(("jmp", dead as i64 + 1));
+            for index in index + 1..=index + dead {
+                match rng.below(3) {
+                    0 => program.push(("acc", rng.between(0, 100) as i64 - 50)),
+                    1 => program.push(("nop", target(rng, index, fix))),
+                    _ => program.push(("jmp", target(rng, index, fix))),
+                }
+            }
+        } else if rng.chance(0.3) {
+            program.push(("nop", target(rng, index, fix)));
+        } else {
+            let argument = rng.between(0, 100) as i64 - 50;
+            path_sum += argument;
+            program.push(("acc", argument));
+        }
+    }
+    program.push(("jmp", -(rng.between(1, fix as u64) as i64)));
+    while program.len() < size {
+        let argument = rng.between(0, 100) as i64 - 50;
+        match rng.chance(0.3) {
+            true => program.push(("nop", argument)),
+            false => {
+                path_sum += argument;
+                program.push(("acc", argument))
+            }
+        }
+    }
+
+    let (mut accumulator, mut counter, mut visited) = (0, 0, HashSet::new());
+    while visited.insert(counter) {
+        let (operation, argument) = program[counter];
+        match operation {
+            "acc" => accumulator += argument,
+            "jmp" => {
+                counter = (counter as i64 + argument) as usize;
+                continue;
+            }
+            _ => (),
+        }
+        counter += 1;
+    }
+    let input = lines(
+        program
+            .iter()
+            .map(|(operation, argument)| format!("{} {:+}", operation, argument)),
+    );
+    Generated::new(input)
+        .with_answer(Part::One, accumulator)
+        .with_answer(Part::Two, path_sum)
+}
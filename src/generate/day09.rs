@@ -0,0 +1,59 @@
+use super::{lines, Generated, Rng};
+use crate::Part;
+
+const PREAMBLE_LENGTH: usize = 25;
+
+fn is_sum_of_two(window: &[u64], number: u64) -> bool {
+    (0..window.len()).any(|i| (i + 1..window.len()).any(|j| window[i] + window[j] == number))
+}
+
+/// Numbers each being the sum of two of the previous 25, except for one number, which is the
+/// sum of a contiguous run of at least three numbers well before it. Sums are mostly drawn
+/// from the smallest previous numbers to keep the numbers from growing too fast.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut numbers: Vec<u64> = (1..=2 * PREAMBLE_LENGTH as u64).collect();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(PREAMBLE_LENGTH);
+    let invalid_index = rng.between(size as u64 / 2, size as u64 - 1) as usize;
+    debug_assert!(invalid_index >= 2 * PREAMBLE_LENGTH);
+    while numbers.len() < size {
+        let window = &numbers[numbers.len() - PREAMBLE_LENGTH..];
+        let number = if numbers.len() == invalid_index {
+            // the run lies before the window, its numbers mostly being smaller
+            loop {
+                let length = rng.between(3, 6) as usize;
+                let start = rng.index(invalid_index - PREAMBLE_LENGTH - length + 1);
+                let sum = numbers[start..start + length].iter().sum();
+                if !is_sum_of_two(window, sum) && !numbers.contains(&sum) {
+                    break sum;
+                }
+            }
+        } else {
+            let mut smallest = window.to_vec();
+            smallest.sort_unstable();
+            let first = rng.index(6);
+            let second = (first + 1 + rng.index(5)) % 6;
+            smallest[first] + smallest[second]
+        };
+        numbers.push(number);
+    }
+
+    let invalid = numbers[invalid_index];
+    let weakness = (0..invalid_index).find_map(|start| {
+        let mut sum = 0;
+        for end in start..invalid_index {
+            sum += numbers[end];
+            if sum == invalid {
+                let run = &numbers[start..=end];
+                return Some(run.iter().min().unwrap() + run.iter().max().unwrap());
+            }
+            if sum >= invalid {
+                return None;
+            }
+        }
+        None
+    });
+    Generated::new(lines(numbers.iter().map(u64::to_string)))
+        .with_answer(Part::One, invalid)
+        .with_answer(Part::Two, weakness.unwrap())
+}
@@ -0,0 +1,42 @@
+use super::{lines, Generated, Rng};
+use crate::Part;
+
+/// Adapters differing by 1 or 3 jolts, with at most four differences of 1 in a row like in
+/// the puzzle, and redrawn in the rare case of more arrangements than fit into 64 bits.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    loop {
+        let (mut jolts, mut ones, mut threes, mut run) = (vec![0u64], 0, 1, 0);
+        while jolts.len() <= size {
+            let difference = if run < 4 && rng.chance(0.6) { 1 } else { 3 };
+            match difference {
+                1 => {
+                    ones += 1;
+                    run += 1;
+                }
+                _ => {
+                    threes += 1;
+                    run = 0;
+                }
+            }
+            jolts.push(jolts[jolts.len() - 1] + difference);
+        }
+        // arrangements reaching each adapter, the device being reachable from the last one
+        let mut arrangements = vec![1u64];
+        for i in 1..jolts.len() {
+            let reachable = (i.saturating_sub(3)..i)
+                .filter(|&j| jolts[i] - jolts[j] <= 3)
+                .try_fold(0u64, |sum, j| sum.checked_add(arrangements[j]));
+            match reachable {
+                Some(count) => arrangements.push(count),
+                None => break,
+            }
+        }
+        if arrangements.len() == jolts.len() {
+            let mut adapters = jolts.split_off(1);
+            rng.shuffle(&mut adapters);
+            return Generated::new(lines(adapters.iter().map(u64::to_string)))
+                .with_answer(Part::One, ones * threes)
+                .with_answer(Part::Two, arrangements[arrangements.len() - 1]);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use super::{lines, Generated, Rng};
+
+/// Square seat layout with a few floor tiles, all seats empty.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let rows = (0..size).map(|_| {
+        (0..size)
+            .map(|_| if rng.chance(0.15) { '.' } else { 'L' })
+            .collect::<String>()
+    });
+    Generated::new(lines(rows.collect::<Vec<_>>()))
+}
@@ -0,0 +1,18 @@
+use super::{lines, Generated, Rng};
+
+/// Navigation instructions, turns only by multiples of 90 degrees.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let instructions: Vec<_> = (0..size)
+        .map(|_| match rng.below(7) {
+            0 => format!("L{}", 90 * rng.between(1, 3)),
+            1 => format!("R{}", 90 * rng.between(1, 3)),
+            2 => format!("F{}", rng.between(1, 100)),
+            action => format!(
+                "{}{}",
+                ["N", "S", "E", "W"][action as usize - 3],
+                rng.between(1, 5)
+            ),
+        })
+        .collect();
+    Generated::new(lines(instructions))
+}
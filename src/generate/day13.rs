@@ -0,0 +1,51 @@
+use super::{Generated, Rng};
+use crate::Part;
+
+const PRIMES: [u64; 22] = [
+    7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// Buses with distinct prime IDs, placed in the schedule so that a timestamp drawn first is
+/// the earliest one to fit the whole schedule.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let (ids, product) = loop {
+        let mut primes = PRIMES.to_vec();
+        rng.shuffle(&mut primes);
+        primes.truncate(size);
+        if let Some(product) = primes.iter().try_fold(1u64, |p, &id| p.checked_mul(id)) {
+            if product < 1 << 62 {
+                break (primes, product);
+            }
+        }
+    };
+    let earliest = rng.between(1, product - 1);
+    let mut schedule: Vec<Option<u64>> = Vec::new();
+    for &id in &ids {
+        // offsets fitting the timestamp repeat with the bus ID
+        let mut offset = ((id - earliest % id) % id) as usize + id as usize * rng.index(2);
+        while schedule.get(offset).is_some_and(Option::is_some) {
+            offset += id as usize;
+        }
+        if schedule.len() <= offset {
+            schedule.resize(offset + 1, None);
+        }
+        schedule[offset] = Some(id);
+    }
+
+    let (timestamp, bus) = loop {
+        let timestamp = rng.between(100_000, 1_000_000);
+        let mut waits: Vec<_> = ids.iter().map(|&id| (id - timestamp % id, id)).collect();
+        waits.sort_unstable();
+        let distinct = waits.len() == 1 || waits[0].0 < waits[1].0;
+        if distinct && ids.iter().all(|&id| !timestamp.is_multiple_of(id)) {
+            break (timestamp, waits[0]);
+        }
+    };
+    let schedule: Vec<_> = schedule
+        .iter()
+        .map(|id| id.map_or_else(|| "x".to_owned(), |id| id.to_string()))
+        .collect();
+    Generated::new(format!("{}\n{}\n", timestamp, schedule.join(",")))
+        .with_answer(Part::One, bus.0 * bus.1)
+        .with_answer(Part::Two, earliest)
+}
@@ -0,0 +1,45 @@
+use super::{lines, Generated, Rng};
+use crate::Part;
+use std::collections::HashMap;
+
+const BITS: usize = 36;
+
+/// Masks with up to eight floating bits, each followed by a few writes to memory.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut program = Vec::new();
+    let (mut memory1, mut memory2) = (HashMap::new(), HashMap::new());
+    for _ in 0..size {
+        let floating = rng.between(0, 8) as usize;
+        let mut mask: Vec<_> = (0..BITS)
+            .map(|_| if rng.chance(0.5) { '1' } else { '0' })
+            .collect();
+        for _ in 0..floating {
+            mask[rng.index(BITS)] = 'X';
+        }
+        let bits = |c| {
+            mask.iter()
+                .fold(0u64, |bits, &m| bits << 1 | u64::from(m == c))
+        };
+        let (ones, xs) = (bits('1'), bits('X'));
+        program.push(format!("mask = {}", mask.iter().collect::<String>()));
+
+        for _ in 0..rng.between(1, 5) {
+            let address = rng.below(1 << 16);
+            let value = rng.below(1 << 30);
+            program.push(format!("mem[{}] = {}", address, value));
+            memory1.insert(address, value & xs | ones);
+            // every subset of the floating bits, counting down through them
+            let mut subset = xs;
+            loop {
+                memory2.insert(address & !xs | ones | subset, value);
+                if subset == 0 {
+                    break;
+                }
+                subset = (subset - 1) & xs;
+            }
+        }
+    }
+    Generated::new(lines(program))
+        .with_answer(Part::One, memory1.values().sum::<u64>())
+        .with_answer(Part::Two, memory2.values().sum::<u64>())
+}
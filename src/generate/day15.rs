@@ -0,0 +1,26 @@
+use super::{Generated, Rng};
+use crate::Part;
+use std::collections::HashMap;
+
+/// Distinct small starting numbers. Only the 2020th number spoken is given as answer, the
+/// 30000000th one taking as long to compute as the solver.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut numbers: Vec<u64> = (0..2 * size as u64 + 5).collect();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(size);
+
+    let mut spoken: HashMap<u64, usize> = HashMap::new();
+    let mut last = numbers[0];
+    for turn in 1..2020 {
+        let next = match numbers.get(turn) {
+            Some(&number) => number,
+            None => spoken
+                .get(&last)
+                .map_or(0, |&before| (turn - before) as u64),
+        };
+        spoken.insert(last, turn);
+        last = next;
+    }
+    let input: Vec<_> = numbers.iter().map(u64::to_string).collect();
+    Generated::new(input.join(",") + "\n").with_answer(Part::One, last)
+}
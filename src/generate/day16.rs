@@ -0,0 +1,93 @@
+use super::{Generated, Rng};
+use crate::Part;
+
+const FIELDS: [&str; 20] = [
+    "departure location",
+    "departure station",
+    "departure platform",
+    "departure track",
+    "departure date",
+    "departure time",
+    "arrival location",
+    "arrival station",
+    "arrival platform",
+    "arrival track",
+    "class",
+    "duration",
+    "price",
+    "route",
+    "row",
+    "seat",
+    "train",
+    "type",
+    "wagon",
+    "zone",
+];
+const STEP: u64 = 50;
+
+/// Rules with nested ranges: the field of rank `r` accepts 1 to `50 * r`, so that each
+/// position has one candidate field more than the previous one and the fields can be mapped
+/// by elimination. A quarter of the nearby tickets get a value no field accepts.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut ranks: Vec<u64> = (1..=FIELDS.len() as u64).collect();
+    rng.shuffle(&mut ranks);
+    // field at each position of the tickets
+    let mut positions: Vec<usize> = (0..FIELDS.len()).collect();
+    rng.shuffle(&mut positions);
+    let ticket = |rng: &mut Rng| -> Vec<u64> {
+        positions
+            .iter()
+            .map(|&field| rng.between(1, STEP * ranks[field]))
+            .collect()
+    };
+
+    let your_ticket = ticket(rng);
+    let mut tickets: Vec<_> = (0..size).map(|_| ticket(rng)).collect();
+    let mut error_rate = 0;
+    let mut valid = vec![0];
+    for (index, ticket) in tickets.iter_mut().enumerate().skip(1) {
+        if rng.chance(0.25) {
+            let value = rng.between(
+                STEP * FIELDS.len() as u64 + 1,
+                2 * STEP * FIELDS.len() as u64,
+            );
+            ticket[rng.index(FIELDS.len())] = value;
+            error_rate += value;
+        } else {
+            valid.push(index);
+        }
+    }
+    // each position needs a value on a valid ticket ruling out the fields of lower rank
+    for (position, &field) in positions.iter().enumerate() {
+        let maximum = STEP * ranks[field];
+        tickets[*rng.choose(&valid)][position] = rng.between(maximum - STEP + 1, maximum);
+    }
+
+    let rules: Vec<_> = FIELDS
+        .iter()
+        .zip(&ranks)
+        .map(|(name, rank)| {
+            let split = rng.between(1, STEP * rank - 1);
+            format!("{}: 1-{} or {}-{}\n", name, split, split + 1, STEP * rank)
+        })
+        .collect();
+    let format_ticket = |ticket: &[u64]| {
+        let values: Vec<_> = ticket.iter().map(u64::to_string).collect();
+        values.join(",") + "\n"
+    };
+    let departure_product: u64 = positions
+        .iter()
+        .zip(&your_ticket)
+        .filter(|(&field, _)| FIELDS[field].starts_with("departure"))
+        .map(|(_, &value)| value)
+        .product();
+    let input = format!(
+        "{}\nyour ticket:\n{}\nnearby tickets:\n{}",
+        rules.concat(),
+        format_ticket(&your_ticket),
+        tickets.iter().map(|t| format_ticket(t)).collect::<String>()
+    );
+    Generated::new(input)
+        .with_answer(Part::One, error_rate)
+        .with_answer(Part::Two, departure_product)
+}
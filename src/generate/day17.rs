@@ -0,0 +1,13 @@
+use super::{lines, Generated, Rng};
+
+/// Square initial slice with about two fifths of the cubes active.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let rows: Vec<String> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.chance(0.4) { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+    Generated::new(lines(rows))
+}
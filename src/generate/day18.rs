@@ -0,0 +1,91 @@
+use super::{lines, Generated, Rng};
+use crate::Part;
+
+/// Largest value of an expression kept, so that the sum of all values fits into 64 bits.
+const MAXIMUM: u64 = 1_000_000_000_000;
+
+enum Term {
+    Number(u64),
+    Parenthesized(Expression),
+}
+
+/// Terms joined by operators, `true` standing for `+` and `false` for `*`.
+struct Expression {
+    first: Box<Term>,
+    rest: Vec<(bool, Term)>,
+}
+
+fn expression(rng: &mut Rng, depth: u32) -> Expression {
+    let term = |rng: &mut Rng| match depth < 2 && rng.chance(0.2) {
+        true => Term::Parenthesized(expression(rng, depth + 1)),
+        false => Term::Number(rng.between(1, 9)),
+    };
+    let first = Box::new(term(rng));
+    let rest = (0..rng.between(1, 5))
+        .map(|_| (rng.chance(0.5), term(rng)))
+        .collect();
+    Expression { first, rest }
+}
+
+impl Expression {
+    /// Value with operators evaluated from left to right, or with additions first.
+    fn value(&self, additions_first: bool) -> Option<u64> {
+        let term = |term: &Term| match term {
+            Term::Number(number) => Some(*number),
+            Term::Parenthesized(expression) => expression.value(additions_first),
+        };
+        let mut value = term(&self.first)?;
+        // with additions first, the product of all sums evaluated so far
+        let mut product = 1u64;
+        for (plus, operand) in &self.rest {
+            let operand = term(operand)?;
+            value = match (plus, additions_first) {
+                (true, _) => value.checked_add(operand)?,
+                (false, false) => value.checked_mul(operand)?,
+                (false, true) => {
+                    product = product.checked_mul(value)?;
+                    operand
+                }
+            };
+        }
+        product.checked_mul(value).filter(|&value| value <= MAXIMUM)
+    }
+}
+
+impl std::fmt::Display for Term {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Term::Number(number) => write!(f, "{}", number),
+            Term::Parenthesized(expression) => write!(f, "({})", expression),
+        }
+    }
+}
+
+impl std::fmt::Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.first)?;
+        for (plus, term) in &self.rest {
+            write!(f, " {} {}", if *plus { '+' } else { '*' }, term)?;
+        }
+        Ok(())
+    }
+}
+
+/// Expressions of single digits, nested up to two levels deep.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let (mut sum1, mut sum2) = (0, 0);
+    let expressions: Vec<_> = (0..size)
+        .map(|_| loop {
+            let expression = expression(rng, 0);
+            if let (Some(value1), Some(value2)) = (expression.value(false), expression.value(true))
+            {
+                sum1 += value1;
+                sum2 += value2;
+                break expression.to_string();
+            }
+        })
+        .collect();
+    Generated::new(lines(expressions))
+        .with_answer(Part::One, sum1)
+        .with_answer(Part::Two, sum2)
+}
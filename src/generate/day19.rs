@@ -0,0 +1,70 @@
+use super::{lines, Generated, Rng};
+use crate::Part;
+
+/// Rules matching chunks of `a` and `b` by parity: rule 42 matches the chunks with an even
+/// number of `b`, rule 31 the ones with an odd number. Whether a message matches rule 0 thus
+/// only depends on the parities of its chunks.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let chunk = rng.between(3, 6) as usize;
+    let mut ids: Vec<u32> = (1..=2 * chunk as u32 + 40)
+        .filter(|id| ![8, 11, 31, 42].contains(id))
+        .collect();
+    rng.shuffle(&mut ids);
+    let (a, b) = (ids[0], ids[1]);
+    let mut rules = vec![
+        "0: 8 11".to_owned(),
+        "8: 42".to_owned(),
+        "11: 42 31".to_owned(),
+        format!("{}: \"a\"", a),
+        format!("{}: \"b\"", b),
+    ];
+    // rules matching strings of growing length with an even or odd number of `b`
+    let (mut even, mut odd) = (a, b);
+    for length in 2..=chunk {
+        let (next_even, next_odd) = match length {
+            _ if length == chunk => (42, 31),
+            _ => (ids[2 * length - 2], ids[2 * length - 1]),
+        };
+        rules.push(format!("{}: {} {} | {} {}", next_even, even, a, odd, b));
+        rules.push(format!("{}: {} {} | {} {}", next_odd, even, b, odd, a));
+        even = next_even;
+        odd = next_odd;
+    }
+    rng.shuffle(&mut rules);
+
+    let (mut matches1, mut matches2) = (0, 0);
+    let messages: Vec<_> = (0..size)
+        .map(|_| {
+            let (evens, odds) = (rng.between(1, 5), rng.between(1, 4));
+            let mut parities: Vec<_> = (0..evens).map(|_| 0).chain((0..odds).map(|_| 1)).collect();
+            if rng.chance(0.3) {
+                rng.shuffle(&mut parities);
+            }
+            let matching = |parities: &[usize]| {
+                let evens = parities.iter().take_while(|&&p| p == 0).count();
+                let odds = parities.len() - evens;
+                parities[evens..].iter().all(|&p| p == 1) && evens > odds && odds >= 1
+            };
+            let mut message: String = parities
+                .iter()
+                .map(|&parity| loop {
+                    let chunk = rng.string(chunk, "ab");
+                    if chunk.matches('b').count() % 2 == parity {
+                        break chunk;
+                    }
+                })
+                .collect();
+            if rng.chance(0.1) {
+                // no longer consisting of whole chunks
+                message.push('a');
+            } else {
+                matches1 += usize::from(parities == [0, 0, 1]);
+                matches2 += usize::from(matching(&parities));
+            }
+            message
+        })
+        .collect();
+    Generated::new(lines(rules) + "\n" + &lines(messages))
+        .with_answer(Part::One, matches1)
+        .with_answer(Part::Two, matches2)
+}
@@ -0,0 +1,141 @@
+use super::{Generated, Rng};
+use crate::grid::Grid;
+use crate::Part;
+use std::collections::HashSet;
+
+const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+/// Tiles overlap by their edges, each tile starting 9 pixels after the previous one.
+const STEP: usize = 9;
+const TILE: usize = STEP + 1;
+
+fn sea_monster() -> impl Iterator<Item = (usize, usize)> + Clone {
+    SEA_MONSTER.iter().enumerate().flat_map(|(y, row)| {
+        row.char_indices()
+            .filter(|&(_, c)| c == '#')
+            .map(move |(x, _)| (x, y))
+    })
+}
+
+fn count_sea_monsters(image: &Grid<char>) -> usize {
+    let (width, height) = (SEA_MONSTER[0].len(), SEA_MONSTER.len());
+    (0..=image.height() - height)
+        .flat_map(|y| (0..=image.width() - width).map(move |x| (x, y)))
+        .filter(|&(x, y)| sea_monster().all(|(dx, dy)| image[(x + dx, y + dy)] == '#'))
+        .count()
+}
+
+fn orientations(image: &Grid<char>) -> Vec<Grid<char>> {
+    let mut orientations = vec![image.clone()];
+    for i in 1..8 {
+        let previous = &orientations[i - 1];
+        orientations.push(match i {
+            4 => previous.flipped_horizontally(),
+            _ => previous.rotated_left(),
+        });
+    }
+    orientations
+}
+
+/// Edge pixels of the tiles, each edge given as positions in the full picture.
+fn edges(side: usize) -> Vec<Vec<(usize, usize)>> {
+    let lines = (0..=side).flat_map(|i| (0..side).map(move |j| (i * STEP, j * STEP)));
+    lines
+        .flat_map(|(fixed, start)| {
+            let horizontal = (0..TILE).map(|k| (start + k, fixed)).collect();
+            let vertical = (0..TILE).map(|k| (fixed, start + k)).collect();
+            vec![horizontal, vertical]
+        })
+        .collect()
+}
+
+/// Square arrangement of tiles whose edges match no other edge than their neighbor's, hiding
+/// sea monsters in the image, which are not found in any other orientation.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let pixels = STEP * size + 1;
+    let (picture, roughness) = loop {
+        let mut picture =
+            Grid::from_fn(pixels, pixels, |_| if rng.chance(0.4) { '#' } else { '.' });
+
+        // redraw the inner pixels of edges until all edges are unique and not symmetric
+        let edges = edges(size);
+        let mut seen = HashSet::new();
+        for edge in &edges {
+            loop {
+                let pixels: String = edge.iter().map(|&position| picture[position]).collect();
+                let reversed: String = pixels.chars().rev().collect();
+                let canonical = pixels.clone().min(reversed.clone());
+                if pixels != reversed && seen.insert(canonical) {
+                    break;
+                }
+                for &position in &edge[1..TILE - 1] {
+                    picture[position] = if rng.chance(0.5) { '#' } else { '.' };
+                }
+            }
+        }
+
+        let image_size = 8 * size;
+        let to_picture =
+            |(x, y): (usize, usize)| (x / 8 * STEP + 1 + x % 8, y / 8 * STEP + 1 + y % 8);
+        let mut image = Grid::from_fn(image_size, image_size, |position| {
+            picture[to_picture(position)]
+        });
+        let mut occupied: HashSet<(usize, usize)> = HashSet::new();
+        let mut monsters = 0;
+        for _ in 0..size * size / 4 {
+            let x = rng.index(image_size - SEA_MONSTER[0].len() + 1);
+            let y = rng.index(image_size - SEA_MONSTER.len() + 1);
+            let cells: Vec<_> = sea_monster().map(|(dx, dy)| (x + dx, y + dy)).collect();
+            let around = |&(x, y): &(usize, usize)| {
+                (x.saturating_sub(1)..=x + 1)
+                    .any(|x| (y.saturating_sub(1)..=y + 1).any(|y| occupied.contains(&(x, y))))
+            };
+            if !cells.iter().any(around) {
+                for &cell in &cells {
+                    image[cell] = '#';
+                    occupied.insert(cell);
+                }
+                monsters += 1;
+            }
+        }
+        let counts: Vec<_> = orientations(&image)
+            .iter()
+            .map(count_sea_monsters)
+            .collect();
+        if monsters > 0 && counts[0] == monsters && counts[1..].iter().all(|&count| count == 0) {
+            for position in image.positions() {
+                picture[to_picture(position)] = image[position];
+            }
+            let roughness = image.iter().filter(|&&c| c == '#').count() - 15 * monsters;
+            break (picture, roughness);
+        }
+    };
+
+    let mut ids: Vec<u64> = (1000..10000).collect();
+    rng.shuffle(&mut ids);
+    let mut tiles: Vec<_> = (0..size * size)
+        .map(|index| {
+            let (x, y) = (index % size * STEP, index / size * STEP);
+            let mut tile = picture.section((x, y), TILE, TILE);
+            for _ in 0..rng.below(4) {
+                tile = tile.rotated_left();
+            }
+            if rng.chance(0.5) {
+                tile = tile.flipped_horizontally();
+            }
+            (ids[index], tile)
+        })
+        .collect();
+    let corners = ids[0] * ids[size - 1] * ids[size * size - size] * ids[size * size - 1];
+    rng.shuffle(&mut tiles);
+    let tiles: Vec<_> = tiles
+        .iter()
+        .map(|(id, tile)| format!("Tile {}:\n{}", id, tile))
+        .collect();
+    Generated::new(tiles.join("\n"))
+        .with_answer(Part::One, corners)
+        .with_answer(Part::Two, roughness)
+}
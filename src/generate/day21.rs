@@ -0,0 +1,103 @@
+use super::{lines, Generated, Rng};
+use crate::Part;
+use std::collections::{BTreeMap, HashSet};
+
+const ALLERGENS: [&str; 8] = [
+    "dairy",
+    "eggs",
+    "fish",
+    "nuts",
+    "peanuts",
+    "sesame",
+    "shellfish",
+    "soy",
+];
+
+/// Foods listing some of the allergens of their ingredients, redrawn until the ingredient of
+/// every allergen follows by elimination like in the puzzle.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut words = HashSet::new();
+    while words.len() < 60 {
+        let length = rng.between(4, 8) as usize;
+        words.insert(rng.string(length, "abcdefghijklmnopqrstuvwxyz"));
+    }
+    let mut words: Vec<_> = words.into_iter().collect();
+    words.sort_unstable();
+    rng.shuffle(&mut words);
+    loop {
+        let mut allergens = ALLERGENS.to_vec();
+        rng.shuffle(&mut allergens);
+        allergens.truncate(rng.between(2, (size as u64 / 2).min(8)) as usize);
+        let (dangerous, safe) = words.split_at(allergens.len());
+
+        let foods: Vec<(Vec<&str>, Vec<&str>)> = (0..size)
+            .map(|index| {
+                let mut listed = vec![allergens[index % allergens.len()]];
+                for _ in 0..rng.below(3) {
+                    let allergen = *rng.choose(&allergens);
+                    if !listed.contains(&allergen) {
+                        listed.push(allergen);
+                    }
+                }
+                let mut ingredients: Vec<&str> = safe
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|_| rng.chance(0.25))
+                    .collect();
+                for (allergen, ingredient) in allergens.iter().zip(dangerous) {
+                    if listed.contains(allergen) || rng.chance(0.3) {
+                        ingredients.push(ingredient);
+                    }
+                }
+                rng.shuffle(&mut ingredients);
+                listed.sort_unstable();
+                (ingredients, listed)
+            })
+            .collect();
+
+        // candidates of each allergen, resolved by elimination
+        let mut candidates: BTreeMap<&str, HashSet<&str>> = BTreeMap::new();
+        for (ingredients, listed) in &foods {
+            for allergen in listed {
+                let contained: HashSet<_> = ingredients.iter().cloned().collect();
+                candidates
+                    .entry(allergen)
+                    .and_modify(|c| c.retain(|i| contained.contains(i)))
+                    .or_insert(contained);
+            }
+        }
+        let mut resolved = BTreeMap::new();
+        while let Some((&allergen, _)) = candidates.iter().find(|(_, c)| c.len() == 1) {
+            let ingredient = candidates
+                .remove(allergen)
+                .unwrap()
+                .into_iter()
+                .next()
+                .unwrap();
+            for c in candidates.values_mut() {
+                c.remove(ingredient);
+            }
+            resolved.insert(allergen, ingredient);
+        }
+        let expected = allergens
+            .iter()
+            .zip(dangerous)
+            .all(|(allergen, ingredient)| resolved.get(allergen) == Some(&ingredient.as_str()));
+        if !candidates.is_empty() || !expected {
+            continue;
+        }
+
+        let appearances = foods
+            .iter()
+            .flat_map(|(ingredients, _)| ingredients)
+            .filter(|i| !resolved.values().any(|d| d == *i))
+            .count();
+        let canonical: Vec<_> = resolved.values().cloned().collect();
+        let input = lines(foods.iter().map(|(ingredients, listed)| {
+            format!("{} (contains {})", ingredients.join(" "), listed.join(", "))
+        }));
+        return Generated::new(input)
+            .with_answer(Part::One, appearances)
+            .with_answer(Part::Two, canonical.join(","));
+    }
+}
@@ -0,0 +1,49 @@
+use super::{lines, Generated, Rng};
+use crate::Part;
+use std::collections::VecDeque;
+
+/// Score of the winner of a plain game of Combat, if it ends within the given rounds.
+fn combat(decks: &[Vec<u32>], rounds: usize) -> Option<u32> {
+    let mut decks: Vec<VecDeque<_>> = decks.iter().map(|d| d.iter().cloned().collect()).collect();
+    for _ in 0..rounds {
+        if decks.iter().any(VecDeque::is_empty) {
+            let winner = decks.iter().find(|deck| !deck.is_empty())?;
+            let score = winner
+                .iter()
+                .rev()
+                .zip(1..)
+                .map(|(card, factor)| card * factor);
+            return Some(score.sum());
+        }
+        let (first, second) = (decks[0].pop_front()?, decks[1].pop_front()?);
+        let (winner, cards) = if first > second {
+            (0, [first, second])
+        } else {
+            (1, [second, first])
+        };
+        decks[winner].extend(cards.iter());
+    }
+    None
+}
+
+/// Two decks dealt from the shuffled cards 1 to twice the size, redealt until a plain game
+/// ends.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    loop {
+        let mut cards: Vec<u32> = (1..=2 * size as u32).collect();
+        rng.shuffle(&mut cards);
+        let decks = [cards[..size].to_vec(), cards[size..].to_vec()];
+        if let Some(score) = combat(&decks, 100_000) {
+            let input = decks
+                .iter()
+                .enumerate()
+                .map(|(index, deck)| {
+                    let cards = deck.iter().map(u32::to_string);
+                    format!("Player {}:\n{}", index + 1, lines(cards))
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            return Generated::new(input).with_answer(Part::One, score);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use super::{Generated, Rng};
+use crate::Part;
+
+/// Labels after cup 1 once the crab made its moves on the small circle.
+fn labels(cups: &[u32], moves: usize) -> String {
+    let mut cups = cups.to_vec();
+    let highest = cups.len() as u32;
+    for _ in 0..moves {
+        let current = cups[0];
+        let picked: Vec<_> = cups.drain(1..4).collect();
+        let mut destination = current;
+        loop {
+            destination = if destination == 1 {
+                highest
+            } else {
+                destination - 1
+            };
+            if !picked.contains(&destination) {
+                break;
+            }
+        }
+        let index = cups.iter().position(|&cup| cup == destination).unwrap();
+        cups.splice(index + 1..index + 1, picked);
+        cups.rotate_left(1);
+    }
+    let one = cups.iter().position(|&cup| cup == 1).unwrap();
+    cups.rotate_left(one);
+    cups[1..].iter().map(u32::to_string).collect()
+}
+
+/// Shuffled cups labeled 1 to the size.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut cups: Vec<u32> = (1..=size as u32).collect();
+    rng.shuffle(&mut cups);
+    let labeling: String = cups.iter().map(u32::to_string).collect();
+    Generated::new(format!("{}\n", labeling)).with_answer(Part::One, labels(&cups, 100))
+}
@@ -0,0 +1,51 @@
+use super::{lines, Generated, Rng};
+use crate::Part;
+use std::collections::HashSet;
+
+const DIRECTIONS: [(&str, (i32, i32)); 6] = [
+    ("e", (2, 0)),
+    ("se", (1, -1)),
+    ("sw", (-1, -1)),
+    ("w", (-2, 0)),
+    ("nw", (-1, 1)),
+    ("ne", (1, 1)),
+];
+
+/// Paths of up to twenty steps, a few of them leading to a tile flipped before.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut black = HashSet::new();
+    let mut paths: Vec<String> = Vec::new();
+    for _ in 0..size {
+        let steps = rng.between(1, 20) as usize;
+        let mut path = String::new();
+        let mut position = (0, 0);
+        for _ in 0..steps {
+            let (direction, (dx, dy)) = rng.choose(&DIRECTIONS);
+            path.push_str(direction);
+            position = (position.0 + dx, position.1 + dy);
+        }
+        if !paths.is_empty() && rng.chance(0.05) {
+            // flip an earlier tile back, reaching it along a shuffled path
+            let mut directions: Vec<_> = rng.choose(&paths).clone().chars().collect();
+            let mut steps = Vec::new();
+            while !directions.is_empty() {
+                let length = match directions[..] {
+                    ['n', ..] | ['s', ..] => 2,
+                    _ => 1,
+                };
+                steps.push(directions.drain(..length).collect::<String>());
+            }
+            rng.shuffle(&mut steps);
+            path = steps.concat();
+            position = steps.iter().fold((0, 0), |(x, y), step| {
+                let (_, (dx, dy)) = DIRECTIONS.iter().find(|(d, _)| d == step).unwrap();
+                (x + dx, y + dy)
+            });
+        }
+        if !black.remove(&position) {
+            black.insert(position);
+        }
+        paths.push(path);
+    }
+    Generated::new(lines(&paths)).with_answer(Part::One, black.len())
+}
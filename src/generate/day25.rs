@@ -0,0 +1,19 @@
+use super::{Generated, Rng};
+use crate::Part;
+
+const MODULUS: u64 = 20201227;
+const SUBJECT: u64 = 7;
+
+fn transform(subject: u64, loop_size: u64) -> u64 {
+    (0..loop_size).fold(1, |value, _| value * subject % MODULUS)
+}
+
+/// Public keys of a card and a door with loop sizes up to the size.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let card_loop = rng.between(1, size as u64);
+    let door_loop = rng.between(1, size as u64);
+    let card_key = transform(SUBJECT, card_loop);
+    let door_key = transform(SUBJECT, door_loop);
+    Generated::new(format!("{}\n{}\n", card_key, door_key))
+        .with_answer(Part::One, transform(card_key, door_loop))
+}
@@ -0,0 +1,143 @@
+//! Generators of random, structurally valid puzzle inputs, most of them along with their
+//! answers known by construction.
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+mod rng;
+
+pub use rng::Rng;
+
+use crate::answer::Value;
+use crate::days::NUMBER_OF_DAYS;
+use crate::Part;
+use std::ops::RangeInclusive;
+
+/// Generated puzzle input along with the answers known for it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Generated {
+    pub input: String,
+    answers: [Option<Value>; 2],
+}
+
+impl Generated {
+    pub fn new(input: String) -> Self {
+        Self {
+            input,
+            answers: [None, None],
+        }
+    }
+
+    pub fn with_answer(mut self, part: Part, answer: impl Into<Value>) -> Self {
+        self.answers[part.number() as usize - 1] = Some(answer.into());
+        self
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&Value> {
+        self.answers[part.number() as usize - 1].as_ref()
+    }
+}
+
+/// Generator of a day's inputs, the size giving e.g. the number of lines.
+pub struct Generator {
+    pub generate: fn(&mut Rng, usize) -> Generated,
+    /// What the size counts, e.g. "passports".
+    pub unit: &'static str,
+    pub default_size: usize,
+    pub sizes: RangeInclusive<usize>,
+}
+
+pub fn generator(day: u32) -> Option<Generator> {
+    let (generate, unit, default_size, sizes): (fn(&mut Rng, usize) -> Generated, _, _, _) =
+        match day {
+            1 => (day01::generate, "entries", 200, 5..=5000),
+            2 => (day02::generate, "passwords", 1000, 1..=1_000_000),
+            3 => (day03::generate, "rows", 323, 1..=10_000),
+            4 => (day04::generate, "passports", 250, 1..=100_000),
+            5 => (day05::generate, "boarding passes", 800, 2..=1000),
+            6 => (day06::generate, "groups", 500, 1..=100_000),
+            7 => (day07::generate, "bag colors", 600, 2..=1000),
+            8 => (day08::generate, "instructions", 600, 4..=100_000),
+            9 => (day09::generate, "numbers", 1000, 100..=1000),
+            10 => (day10::generate, "adapters", 100, 1..=200),
+            11 => (day11::generate, "rows and columns", 90, 1..=500),
+            12 => (day12::generate, "instructions", 780, 1..=100_000),
+            13 => (day13::generate, "buses", 9, 1..=12),
+            14 => (day14::generate, "masks", 100, 1..=10_000),
+            15 => (day15::generate, "starting numbers", 6, 1..=20),
+            16 => (day16::generate, "nearby tickets", 240, 1..=100_000),
+            17 => (day17::generate, "columns and rows", 8, 1..=20),
+            18 => (day18::generate, "expressions", 370, 1..=100_000),
+            19 => (day19::generate, "messages", 400, 1..=10_000),
+            20 => (day20::generate, "tiles per side", 12, 3..=30),
+            21 => (day21::generate, "foods", 40, 4..=1000),
+            22 => (day22::generate, "cards per player", 25, 1..=50),
+            23 => (day23::generate, "cups", 9, 5..=9),
+            24 => (day24::generate, "tiles", 400, 1..=10_000),
+            25 => (
+                day25::generate,
+                "maximum loop size",
+                10_000_000,
+                1..=20_000_000,
+            ),
+            _ => return None,
+        };
+    Some(Generator {
+        generate,
+        unit,
+        default_size,
+        sizes,
+    })
+}
+
+/// Generates an input of a day from the seed, using the day's default size if none is given.
+pub fn generate(day: u32, seed: u64, size: Option<usize>) -> Result<Generated, String> {
+    let generator = generator(day)
+        .ok_or_else(|| format!("invalid day {}, expected 1 to {}", day, NUMBER_OF_DAYS))?;
+    let size = size.unwrap_or(generator.default_size);
+    if !generator.sizes.contains(&size) {
+        return Err(format!(
+            "invalid size {} for day {}, expected {} to {} {}",
+            size,
+            day,
+            generator.sizes.start(),
+            generator.sizes.end(),
+            generator.unit
+        ));
+    }
+    Ok((generator.generate)(&mut Rng::new(seed), size))
+}
+
+/// Joins lines into an input, each line terminated by a newline.
+fn lines<I>(lines: I) -> String
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    lines
+        .into_iter()
+        .map(|line| format!("{}\n", line.as_ref()))
+        .collect()
+}
@@ -0,0 +1,300 @@
+use crate::error::ParseError;
+use crate::input::{self, Line};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Position of a cell given as `(x, y)`, starting at the top left corner.
+pub type Position = (usize, usize);
+
+/// Direction given as `(dx, dy)`, `dy` pointing downwards.
+pub type Direction = (isize, isize);
+
+/// Directions to the four orthogonal neighbors.
+pub const ORTHOGONAL: [Direction; 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Directions to all eight neighbors including the diagonal ones.
+pub const ALL_DIRECTIONS: [Direction; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Two-dimensional grid of cells stored row by row.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(Position) -> T,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid with one character per cell, rejecting characters `parse_cell` returns
+    /// `None` for as well as rows of differing widths.
+    pub fn parse<F>(input: &str, parse_cell: F) -> Result<Self, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        input::parse_with(input, |input, errors| Self::read(input, parse_cell, errors))
+    }
+
+    /// Like `parse`, but returns the problems of all rows.
+    pub fn check<F>(input: &str, parse_cell: F) -> Vec<ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        input::check_with(input, |input, errors| Self::read(input, parse_cell, errors))
+    }
+
+    fn read<F>(input: &str, parse_cell: F, errors: &mut Vec<ParseError>) -> Self
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let grid = Self::read_lines(input::lines(input), parse_cell, errors);
+        if grid.height == 0 && errors.is_empty() {
+            errors.push(input::end_of_input(input, "empty grid"));
+        }
+        grid
+    }
+
+    pub fn parse_lines<'a, I, F>(lines: I, parse_cell: F) -> Result<Self, ParseError>
+    where
+        I: IntoIterator<Item = Line<'a>>,
+        F: Fn(char) -> Option<T>,
+    {
+        let mut errors = Vec::new();
+        let grid = Self::read_lines(lines, parse_cell, &mut errors);
+        match errors.into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(grid),
+        }
+    }
+
+    /// Parses rows, recording the first problem of every row in `errors`. Rows with
+    /// problems are left out of the grid.
+    fn read_lines<'a, I, F>(lines: I, parse_cell: F, errors: &mut Vec<ParseError>) -> Self
+    where
+        I: IntoIterator<Item = Line<'a>>,
+        F: Fn(char) -> Option<T>,
+    {
+        let mut grid = Self {
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+        };
+        for line in lines {
+            let width = line.text.chars().count();
+            let row = line
+                .text
+                .char_indices()
+                .map(|(index, c)| {
+                    parse_cell(c).ok_or_else(|| {
+                        line.error_at(&line.text[index..], format!("invalid character '{}'", c))
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .and_then(|row| match width {
+                    0 => Err(line.error("empty row")),
+                    _ if grid.height > 0 && width != grid.width => {
+                        Err(line.error(format!("row has width {}, expected {}", width, grid.width)))
+                    }
+                    _ => Ok(row),
+                });
+            if let Some(row) = input::record(errors, row) {
+                grid.cells.extend(row);
+                grid.width = width;
+                grid.height += 1;
+            }
+        }
+        grid
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Position) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Cell at the given position, continuing the grid periodically in both directions.
+    pub fn get_wrapping(&self, (x, y): Position) -> &T {
+        &self[(x % self.width, y % self.height)]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        (0..self.height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Position one step in the given direction, if still inside the grid.
+    pub fn step(&self, (x, y): Position, (dx, dy): Direction) -> Option<Position> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+        Some((x, y))
+    }
+
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&direction| self.step(position, direction))
+    }
+
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ALL_DIRECTIONS
+            .iter()
+            .filter_map(move |&direction| self.step(position, direction))
+    }
+
+    /// Positions along a ray in the given direction, excluding the start and ending at the
+    /// grid's border.
+    pub fn ray(
+        &self,
+        mut position: Position,
+        direction: Direction,
+    ) -> impl Iterator<Item = Position> + '_ {
+        std::iter::from_fn(move || {
+            position = self.step(position, direction)?;
+            Some(position)
+        })
+    }
+
+    pub fn map<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(&mut f).collect(),
+        }
+    }
+
+    /// Renders the grid as text, one line per row.
+    pub fn render<F>(&self, f: F) -> String
+    where
+        F: Fn(&T) -> char,
+    {
+        self.rows()
+            .flat_map(|row| row.iter().map(&f).chain(std::iter::once('\n')))
+            .collect()
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Grid rotated by 90 degrees counterclockwise.
+    pub fn rotated_left(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+
+    /// Grid rotated by 90 degrees clockwise.
+    pub fn rotated_right(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Grid mirrored at the vertical axis, swapping left and right.
+    pub fn flipped_horizontally(&self) -> Self {
+        Self::from_fn(self.width, self.height, |(x, y)| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// Grid mirrored at the horizontal axis, swapping top and bottom.
+    pub fn flipped_vertically(&self) -> Self {
+        Self::from_fn(self.width, self.height, |(x, y)| {
+            self[(x, self.height - 1 - y)].clone()
+        })
+    }
+
+    /// Rectangular section of the grid starting at the given position.
+    pub fn section(&self, (left, top): Position, width: usize, height: usize) -> Self {
+        Self::from_fn(width, height, |(x, y)| self[(left + x, top + y)].clone())
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "position {:?} outside of {}x{} grid",
+                position, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Position) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "position {:?} outside of {}x{} grid",
+            (x, y),
+            self.width,
+            self.height
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(|&c| c))
+    }
+}
@@ -0,0 +1,160 @@
+use crate::error::ParseError;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::str::FromStr;
+
+/// Input name selecting the standard input instead of a file.
+pub const STDIN: &str = "-";
+
+/// Reads a complete puzzle input, `-` denoting the standard input.
+pub fn read_to_string(filename: &str) -> Result<String, io::Error> {
+    if filename == STDIN {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        fs::read_to_string(filename)
+    }
+}
+
+/// Stable fingerprint of a text, e.g. to recognize a puzzle input wherever it is stored.
+pub fn fingerprint(text: &str) -> String {
+    // 64-bit FNV-1a, unlike `DefaultHasher` guaranteed not to change between releases
+    let hash = text.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Line of a puzzle input together with its 1-based line number, used to locate parse errors.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Error pointing to the start of the line.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, 1, self.text, message)
+    }
+
+    /// Error pointing to `part`, which has to be a slice of the line's text.
+    pub fn error_at(&self, part: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column(part), self.text, message)
+    }
+
+    /// Parses `part` of the line, describing it as `what` in case of an error.
+    pub fn parse<T>(&self, part: &str, what: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        part.parse()
+            .map_err(|err| self.error_at(part, format!("invalid {} '{}': {}", what, part, err)))
+    }
+
+    /// Checks that all characters of `part` are valid, describing them as `what` otherwise.
+    pub fn check_chars<F>(&self, part: &str, what: &str, valid: F) -> Result<(), ParseError>
+    where
+        F: Fn(char) -> bool,
+    {
+        match part.char_indices().find(|&(_, c)| !valid(c)) {
+            Some((index, c)) => {
+                Err(self.error_at(&part[index..], format!("invalid {} '{}'", what, c)))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Splits the line at the first occurrence of `delimiter`.
+    pub fn split_once(&self, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(format!("missing '{}'", delimiter)))
+    }
+
+    fn column(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= self.text.len() && self.text.is_char_boundary(offset))
+            .unwrap_or(0);
+        self.text[..offset].chars().count() + 1
+    }
+}
+
+/// Iterates over the lines of a puzzle input.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line {
+        number: index + 1,
+        text,
+    })
+}
+
+/// Parses every line of a puzzle input, stopping at the first error.
+pub fn parse_lines<'a, T, F>(input: &'a str, parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(Line<'a>) -> Result<T, ParseError>,
+{
+    lines(input).map(parse).collect()
+}
+
+/// Splits a puzzle input into blocks separated by empty lines.
+pub fn blocks(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![Vec::new()];
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            if !blocks.last().unwrap().is_empty() {
+                blocks.push(Vec::new());
+            }
+        } else {
+            blocks.last_mut().unwrap().push(line);
+        }
+    }
+    blocks.retain(|block| !block.is_empty());
+    blocks
+}
+
+/// Error located after the last line, for content missing from a puzzle input.
+pub fn end_of_input(input: &str, message: impl Into<String>) -> ParseError {
+    ParseError::new(input.lines().count() + 1, 1, "", message)
+}
+
+/// Records the error of `result` in `errors`, for parsers continuing after a problem.
+pub fn record<T>(errors: &mut Vec<ParseError>, result: Result<T, ParseError>) -> Option<T> {
+    result.map_err(|err| errors.push(err)).ok()
+}
+
+/// Runs a parser recording problems in a list instead of stopping at the first one, failing
+/// with the first problem recorded.
+pub fn parse_with<'a, T, F>(input: &'a str, read: F) -> Result<T, ParseError>
+where
+    F: FnOnce(&'a str, &mut Vec<ParseError>) -> T,
+{
+    let mut errors = Vec::new();
+    let value = read(input, &mut errors);
+    match errors.into_iter().next() {
+        Some(err) => Err(err),
+        None => Ok(value),
+    }
+}
+
+/// Runs a parser recording problems in a list, returning all of them.
+pub fn check_with<'a, T, F>(input: &'a str, read: F) -> Vec<ParseError>
+where
+    F: FnOnce(&'a str, &mut Vec<ParseError>) -> T,
+{
+    let mut errors = Vec::new();
+    read(input, &mut errors);
+    errors
+}
+
+/// Parses every line of a puzzle input, returning the errors of all lines.
+pub fn check_lines<'a, T, F>(input: &'a str, mut parse: F) -> Vec<ParseError>
+where
+    F: FnMut(Line<'a>) -> Result<T, ParseError>,
+{
+    lines(input).filter_map(|line| parse(line).err()).collect()
+}
@@ -0,0 +1,62 @@
+#[macro_use]
+extern crate lazy_static;
+
+pub mod animation;
+pub mod answer;
+pub mod audit;
+pub mod bench;
+pub mod cache;
+pub mod cancel;
+pub mod client;
+pub mod config;
+pub mod days;
+pub mod differential;
+pub mod error;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod parameters;
+pub mod registry;
+pub mod render;
+pub mod solution;
+pub mod submission;
+pub mod summary;
+pub mod trace;
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part '{}', expected 1 or 2", s)),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
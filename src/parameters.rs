@@ -0,0 +1,109 @@
+//! Puzzle parameters like the preamble length of day 9, which the worked examples of the
+//! puzzle texts partly change.
+
+use crate::config::Config;
+use crate::solution::DynSolution;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Value of a solution's puzzle parameter, which can be changed from its text form.
+pub trait Parameter {
+    fn set(&mut self, value: &str) -> Result<(), String>;
+    fn value(&self) -> String;
+}
+
+impl<T> Parameter for T
+where
+    T: FromStr + fmt::Display,
+    T::Err: fmt::Display,
+{
+    fn set(&mut self, value: &str) -> Result<(), String> {
+        *self = value.parse().map_err(|err: T::Err| err.to_string())?;
+        Ok(())
+    }
+
+    fn value(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Values of puzzle parameters given per day, overriding the defaults of the solutions.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Parameters {
+    values: BTreeMap<(u32, String), String>,
+}
+
+impl Parameters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Takes the parameters from the `[day09]` style sections of a configuration.
+    pub fn from_config(config: &Config) -> Self {
+        let mut parameters = Self::new();
+        for (key, value) in config.iter() {
+            let day = key.split_once('.').and_then(|(section, name)| {
+                Some((section.strip_prefix("day")?.parse().ok()?, name))
+            });
+            if let Some((day, name)) = day {
+                parameters.set(day, name, value);
+            }
+        }
+        parameters
+    }
+
+    pub fn set(&mut self, day: u32, name: &str, value: &str) {
+        self.values.insert((day, name.to_owned()), value.to_owned());
+    }
+
+    /// Sets a parameter of a day from a `name=value` assignment given on the command line.
+    pub fn assign(&mut self, day: u32, assignment: &str) -> Result<(), String> {
+        match assignment.split_once('=') {
+            Some((name, value)) if !name.trim().is_empty() => {
+                self.set(day, name.trim(), value.trim());
+                Ok(())
+            }
+            _ => Err(format!(
+                "invalid parameter '{}', expected <NAME>=<VALUE>",
+                assignment
+            )),
+        }
+    }
+
+    /// Values given for the parameters of a day, ordered by name.
+    pub fn of_day(&self, day: u32) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .range((day, String::new())..(day + 1, String::new()))
+            .map(|((_, name), value)| (name.as_str(), value.as_str()))
+    }
+
+    /// Changes the parameters of a day's solution to the values given for the day.
+    pub fn apply(&self, day: u32, solution: &mut dyn DynSolution) -> Result<(), String> {
+        let mut parameters = solution.parameters();
+        for (name, value) in self.of_day(day) {
+            let index = match parameters.iter().position(|(known, _)| *known == name) {
+                Some(index) => index,
+                None if parameters.is_empty() => {
+                    return Err(format!("day {} has no parameters, found '{}'", day, name))
+                }
+                None => {
+                    let known: Vec<_> = parameters.iter().map(|(known, _)| *known).collect();
+                    return Err(format!(
+                        "unknown parameter '{}' of day {}, expected one of {}",
+                        name,
+                        day,
+                        known.join(", ")
+                    ));
+                }
+            };
+            parameters[index].1.set(value).map_err(|err| {
+                format!(
+                    "invalid value '{}' for parameter '{}' of day {}: {}",
+                    value, name, day, err
+                )
+            })?;
+        }
+        Ok(())
+    }
+}
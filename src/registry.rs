@@ -0,0 +1,124 @@
+use crate::error::{Error, ParseError};
+use crate::input::{self, Line};
+use crate::Part;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+
+/// File the confirmed answers are stored in unless another one is given.
+pub const DEFAULT_PATH: &str = "answers.txt";
+
+const HEADER: &str =
+    "# Confirmed puzzle answers: day, part, input fingerprint and answer, separated by tabs.";
+
+/// Identifies an answer by day, part and the fingerprint of the input it was computed for, so
+/// that the answer is found wherever the input is stored.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Key {
+    pub day: u32,
+    pub part: Part,
+    pub input: String,
+}
+
+impl Key {
+    pub fn new(day: u32, part: Part, input: &str) -> Self {
+        Self {
+            day,
+            part,
+            input: input::fingerprint(input),
+        }
+    }
+}
+
+fn parse_fingerprint(line: Line, text: &str) -> Result<String, ParseError> {
+    match text.len() == 16 && text.chars().all(|c| c.is_ascii_hexdigit()) {
+        true => Ok(text.to_ascii_lowercase()),
+        false => Err(line.error_at(
+            text,
+            format!(
+                "invalid input fingerprint '{}', expected 16 hexadecimal digits",
+                text
+            ),
+        )),
+    }
+}
+
+/// Outcome of comparing a computed answer with the registry.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Status {
+    Match,
+    Mismatch(String),
+    Missing,
+}
+
+/// Registry of confirmed answers, used to detect regressions.
+#[derive(Debug, Default)]
+pub struct Registry {
+    answers: BTreeMap<Key, String>,
+}
+
+impl Registry {
+    /// Loads the registry from a file, a missing file yielding an empty registry.
+    pub fn load(path: &str) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content).map_err(|err| Error::Parse(err.in_file(path))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(Error::Io(path.to_owned(), err)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, ParseError> {
+        let mut registry = Self::default();
+        for line in input::lines(content) {
+            if line.text.trim().is_empty() || line.text.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<_> = line.text.split('\t').collect();
+            if fields.len() != 4 {
+                return Err(line.error(format!(
+                    "found {} tab-separated fields, expected 4",
+                    fields.len()
+                )));
+            }
+            let key = Key {
+                day: line.parse(fields[0], "day")?,
+                part: line.parse(fields[1], "part")?,
+                input: parse_fingerprint(line, fields[2])?,
+            };
+            if registry.answers.insert(key, fields[3].to_owned()).is_some() {
+                return Err(line.error("duplicate answer"));
+            }
+        }
+        Ok(registry)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), Error> {
+        fs::write(path, self.to_string()).map_err(|err| Error::Io(path.to_owned(), err))
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&str> {
+        self.answers.get(key).map(String::as_str)
+    }
+
+    pub fn record(&mut self, key: Key, answer: &str) {
+        self.answers.insert(key, answer.to_owned());
+    }
+
+    pub fn check(&self, key: &Key, answer: &str) -> Status {
+        match self.get(key) {
+            Some(expected) if expected == answer => Status::Match,
+            Some(expected) => Status::Mismatch(expected.to_owned()),
+            None => Status::Missing,
+        }
+    }
+}
+
+impl std::fmt::Display for Registry {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "{}", HEADER)?;
+        for (key, answer) in &self.answers {
+            writeln!(f, "{}\t{}\t{}\t{}", key.day, key.part, key.input, answer)?;
+        }
+        Ok(())
+    }
+}
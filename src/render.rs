@@ -0,0 +1,221 @@
+//! Pictures of simulation frames, written as PNG images and stitched into animated GIFs.
+
+use crate::animation::Frame;
+use std::error::Error;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+pub type Color = [u8; 3];
+
+/// Colors of the characters of frames, the background being used for all others.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Palette {
+    background: Color,
+    colors: Vec<(char, Color)>,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self {
+            background: [0, 0, 0],
+            colors: vec![
+                ('.', [16, 24, 48]),
+                ('#', [240, 200, 64]),
+                ('L', [72, 96, 144]),
+                ('O', [224, 48, 48]),
+                ('X', [240, 240, 240]),
+            ],
+        }
+    }
+}
+
+fn parse_color(text: &str) -> Result<Color, String> {
+    let hex = text.strip_prefix('#').unwrap_or(text);
+    let channel = |i: usize| {
+        hex.get(i..i + 2)
+            .and_then(|c| u8::from_str_radix(c, 16).ok())
+    };
+    match (hex.len(), channel(0), channel(2), channel(4)) {
+        (6, Some(red), Some(green), Some(blue)) => Ok([red, green, blue]),
+        _ => Err(format!("invalid color '{}', expected RRGGBB", text)),
+    }
+}
+
+impl Palette {
+    pub fn set(&mut self, c: char, color: Color) {
+        match self.colors.iter_mut().find(|(known, _)| *known == c) {
+            Some(entry) => entry.1 = color,
+            None => self.colors.push((c, color)),
+        }
+    }
+
+    pub fn set_background(&mut self, color: Color) {
+        self.background = color;
+    }
+
+    /// Index of the color of a character, 0 being the background.
+    fn index(&self, c: char) -> u8 {
+        self.colors
+            .iter()
+            .position(|(known, _)| *known == c)
+            .map_or(0, |index| index as u8 + 1)
+    }
+
+    /// Colors by index as consecutive red, green and blue values.
+    fn rgb(&self) -> Vec<u8> {
+        std::iter::once(&self.background)
+            .chain(self.colors.iter().map(|(_, color)| color))
+            .flatten()
+            .cloned()
+            .collect()
+    }
+}
+
+/// Changes the colors of the default palette from a list like `#=ffffff,.=000000`, where
+/// `background` names the color of all characters not listed.
+impl FromStr for Palette {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut palette = Self::default();
+        for entry in s.split(',').filter(|entry| !entry.is_empty()) {
+            let (name, color) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("invalid color '{}', expected <CHAR>=RRGGBB", entry))?;
+            let color = parse_color(color)?;
+            let mut chars = name.chars();
+            match (chars.next(), chars.next()) {
+                _ if name == "background" => palette.set_background(color),
+                (Some(c), None) => palette.set(c, color),
+                _ => return Err(format!("invalid character '{}' of color", name)),
+            }
+        }
+        if palette.colors.len() > 255 {
+            return Err("too many colors, at most 255 are supported".to_owned());
+        }
+        Ok(palette)
+    }
+}
+
+/// Frame drawn with the colors of a palette, each pixel being the index of its color.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Picture {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Picture {
+    /// Draws each cell of a frame as a square of `scale` pixels, on a canvas of the given
+    /// number of columns and rows to give all frames of an animation the same size.
+    ///
+    /// Blank cells of hexagonal frames are split between the cells to their left and right,
+    /// which closes the gaps between the tiles of shifted columns, like those of the floor of
+    /// day 24.
+    pub fn draw(frame: &Frame, palette: &Palette, scale: usize, size: (usize, usize)) -> Self {
+        let (width, height) = (size.0 * scale, size.1 * scale);
+        let mut pixels = vec![0; width * height];
+        for (row, line) in frame.rows.iter().take(size.1).enumerate() {
+            let cells: Vec<char> = line.chars().take(size.0).collect();
+            let index = |column: Option<usize>| {
+                column
+                    .and_then(|column| cells.get(column))
+                    .map_or(0, |&c| palette.index(c))
+            };
+            for (column, &c) in cells.iter().enumerate() {
+                let (left, right) = match c {
+                    ' ' if frame.hexagonal => {
+                        (index(column.checked_sub(1)), index(Some(column + 1)))
+                    }
+                    _ => (index(Some(column)), index(Some(column))),
+                };
+                for y in row * scale..(row + 1) * scale {
+                    let start = y * width + column * scale;
+                    pixels[start..start + scale / 2].fill(left);
+                    pixels[start + scale / 2..start + scale].fill(right);
+                }
+            }
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Color of the pixel in the given column and row.
+    pub fn color(&self, palette: &Palette, x: usize, y: usize) -> Color {
+        match self.pixels[y * self.width + x] as usize {
+            0 => palette.background,
+            index => palette.colors[index - 1].1,
+        }
+    }
+
+    pub fn write_png(&self, palette: &Palette, path: &Path) -> Result<(), Box<dyn Error>> {
+        let file = File::create(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+        let mut encoder =
+            png::Encoder::new(BufWriter::new(file), self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Indexed);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_palette(palette.rgb());
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        writer.finish()?;
+        Ok(())
+    }
+}
+
+/// Number of columns and rows fitting every frame.
+pub fn size(frames: &[Frame]) -> (usize, usize) {
+    let columns = frames.iter().flat_map(|frame| &frame.rows);
+    let columns = columns.map(|row| row.chars().count()).max().unwrap_or(0);
+    let rows = frames
+        .iter()
+        .map(|frame| frame.rows.len())
+        .max()
+        .unwrap_or(0);
+    (columns.max(1), rows.max(1))
+}
+
+/// Writes pictures of the same size as an endlessly repeating animated GIF.
+pub fn write_gif(
+    pictures: &[Picture],
+    palette: &Palette,
+    delay: Duration,
+    path: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let (width, height) = pictures
+        .first()
+        .map_or((1, 1), |picture| (picture.width, picture.height));
+    if width > u16::MAX as usize || height > u16::MAX as usize {
+        return Err(format!("picture of {}x{} pixels too large for a GIF", width, height).into());
+    }
+    let file = File::create(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+    let rgb = palette.rgb();
+    let mut encoder = gif::Encoder::new(BufWriter::new(file), width as u16, height as u16, &rgb)?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+    // the delay is given in hundredths of a second
+    let delay = (delay.as_millis() / 10).clamp(1, u16::MAX as u128) as u16;
+    for picture in pictures {
+        let frame = gif::Frame {
+            width: width as u16,
+            height: height as u16,
+            delay,
+            buffer: picture.pixels.as_slice().into(),
+            ..gif::Frame::default()
+        };
+        encoder.write_frame(&frame)?;
+    }
+    Ok(())
+}
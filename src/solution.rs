@@ -0,0 +1,127 @@
+use crate::animation::Simulation;
+use crate::answer::Answer;
+use crate::audit::Audit;
+use crate::error::ParseError;
+use crate::parameters::Parameter;
+use crate::Part;
+use std::any::Any;
+
+/// Alternative implementation of a part, e.g. a straightforward one kept to check an optimized
+/// solver against or the other way round.
+pub struct Variant<S: Solution + ?Sized> {
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: fn(&S, &S::Input) -> Answer,
+}
+
+/// Solver for the two parts of a puzzle operating on the parsed puzzle input.
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Checks the input without solving, reporting all problems found instead of only the
+    /// first one. Inputs without problems found here are still parsed to catch the rest.
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        self.parse(input).err().into_iter().collect()
+    }
+
+    /// Named puzzle parameters, e.g. fixed sizes or targets, which the worked examples of
+    /// some puzzles change.
+    fn parameters(&mut self) -> Vec<(&'static str, &mut dyn Parameter)> {
+        Vec::new()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Answer;
+    fn part2(&self, input: &Self::Input) -> Answer;
+
+    /// Other implementations of the parts, which have to give the same answers.
+    fn variants(&self) -> Vec<Variant<Self>> {
+        Vec::new()
+    }
+
+    /// Simulation stepping through the generations of a part, for the days solved by running
+    /// a cellular automaton.
+    fn simulation(&self, _part: Part, _input: &Self::Input) -> Option<Box<dyn Simulation>> {
+        None
+    }
+
+    /// Report of the records of the raw input breaking the rules of a part, for the days
+    /// solved by counting valid records.
+    fn audit(&self, _part: Part, _input: &str) -> Option<Audit> {
+        None
+    }
+}
+
+/// Type-erased form of `Solution`, allowing solutions of all days to be handled uniformly.
+pub trait DynSolution {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn check(&self, input: &str) -> Vec<ParseError>;
+    fn parameters(&mut self) -> Vec<(&'static str, &mut dyn Parameter)>;
+    fn solve(&self, part: Part, input: &dyn Any) -> Answer;
+    fn variants(&self) -> Vec<(Part, &'static str)>;
+    fn solve_variant(&self, part: Part, name: &str, input: &dyn Any) -> Option<Answer>;
+    fn simulation(&self, part: Part, input: &dyn Any) -> Option<Box<dyn Simulation>>;
+    fn audit(&self, part: Part, input: &str) -> Option<Audit>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        let mut errors = Solution::check(self, input);
+        if errors.is_empty() {
+            errors.extend(Solution::parse(self, input).err());
+        }
+        errors.sort_by_key(|err| (err.line(), err.column()));
+        errors
+    }
+
+    fn parameters(&mut self) -> Vec<(&'static str, &mut dyn Parameter)> {
+        Solution::parameters(self)
+    }
+
+    fn solve(&self, part: Part, input: &dyn Any) -> Answer {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input parsed by a different solution");
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+
+    fn variants(&self) -> Vec<(Part, &'static str)> {
+        Solution::variants(self)
+            .iter()
+            .map(|variant| (variant.part, variant.name))
+            .collect()
+    }
+
+    fn solve_variant(&self, part: Part, name: &str, input: &dyn Any) -> Option<Answer> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input parsed by a different solution");
+        Solution::variants(self)
+            .into_iter()
+            .find(|variant| variant.part == part && variant.name == name)
+            .map(|variant| (variant.solve)(self, input))
+    }
+
+    fn simulation(&self, part: Part, input: &dyn Any) -> Option<Box<dyn Simulation>> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input parsed by a different solution");
+        Solution::simulation(self, part, input)
+    }
+
+    fn audit(&self, part: Part, input: &str) -> Option<Audit> {
+        Solution::audit(self, part, input)
+    }
+}
@@ -0,0 +1,233 @@
+use crate::error::{Error, ParseError};
+use crate::input;
+use crate::Part;
+use regex::Regex;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::str::FromStr;
+
+/// Name of the submission log, kept next to the cached inputs of an account.
+pub const LOG_FILE: &str = "submissions.txt";
+
+const HEADER: &str =
+    "# Submitted answers: time, day, part, verdict, seconds to wait and answer, separated by tabs.";
+
+/// Verdict of the puzzle server on a submitted answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    /// The part is not the one to be solved next, e.g. because it is solved already.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Whether the submitted answer is known to be wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow
+        )
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect => "incorrect",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::RateLimited => "rate-limited",
+            Verdict::WrongLevel => "wrong-level",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "incorrect" => Ok(Verdict::Incorrect),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "rate-limited" => Ok(Verdict::RateLimited),
+            "wrong-level" => Ok(Verdict::WrongLevel),
+            _ => Err(format!("unknown verdict '{}'", s)),
+        }
+    }
+}
+
+/// Reads the verdict and the seconds to wait before the next submission from the
+/// server's response page.
+pub fn parse_response(body: &str) -> Option<(Verdict, Option<u64>)> {
+    lazy_static! {
+        static ref LEFT_TO_WAIT: Regex =
+            Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+        static ref WAIT_MINUTES: Regex = Regex::new(r"(?i)wait (one|\d+) minutes?").unwrap();
+    }
+    let wait = if let Some(captures) = LEFT_TO_WAIT.captures(body) {
+        let minutes: u64 = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+        Some(minutes * 60 + captures[2].parse::<u64>().unwrap())
+    } else {
+        WAIT_MINUTES
+            .captures(body)
+            .map(|captures| match &captures[1] {
+                "one" => 60,
+                minutes => minutes.parse::<u64>().unwrap() * 60,
+            })
+    };
+    let verdict = if body.contains("That's the right answer") {
+        Verdict::Correct
+    } else if body.contains("That's not the right answer") {
+        if body.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if body.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        }
+    } else if body.contains("You gave an answer too recently") {
+        Verdict::RateLimited
+    } else if body.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        return None;
+    };
+    Some((verdict, wait))
+}
+
+/// A submitted answer along with the server's verdict.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub day: u32,
+    pub part: Part,
+    pub verdict: Verdict,
+    /// Seconds to wait before the next submission, if the server asked for it.
+    pub wait: Option<u64>,
+    pub answer: String,
+}
+
+/// Log of all submissions of an account, used to avoid pointless submissions.
+#[derive(Debug, Default)]
+pub struct SubmissionLog {
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    /// Loads the log from a file, a missing file yielding an empty log.
+    pub fn load(path: &str) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content).map_err(|err| Error::Parse(err.in_file(path))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(Error::Io(path.to_owned(), err)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, ParseError> {
+        let mut log = Self::default();
+        for line in input::lines(content) {
+            if line.text.trim().is_empty() || line.text.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<_> = line.text.splitn(6, '\t').collect();
+            if fields.len() != 6 {
+                return Err(line.error(format!(
+                    "found {} tab-separated fields, expected 6",
+                    fields.len()
+                )));
+            }
+            log.submissions.push(Submission {
+                time: line.parse(fields[0], "time")?,
+                day: line.parse(fields[1], "day")?,
+                part: line.parse(fields[2], "part")?,
+                verdict: line.parse(fields[3], "verdict")?,
+                wait: match fields[4] {
+                    "" => None,
+                    wait => Some(line.parse(wait, "wait")?),
+                },
+                answer: fields[5].to_owned(),
+            });
+        }
+        Ok(log)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), Error> {
+        fs::write(path, self.to_string()).map_err(|err| Error::Io(path.to_owned(), err))
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    /// Tells why submitting the answer at the given time is known to be pointless, if it is.
+    pub fn check(&self, day: u32, part: Part, answer: &str, now: u64) -> Result<(), String> {
+        if let Some(wait) = self
+            .submissions
+            .iter()
+            .filter_map(|s| Some(s.time + s.wait?))
+            .max()
+            .filter(|&until| until > now)
+        {
+            return Err(format!("rate-limited, {}s left to wait", wait - now));
+        }
+        let earlier = self
+            .submissions
+            .iter()
+            .filter(|s| s.day == day && s.part == part);
+        let value = answer.parse::<i128>().ok();
+        for submission in earlier {
+            let bound = submission.answer.parse::<i128>().ok();
+            match (submission.verdict, value.zip(bound)) {
+                (Verdict::Correct, _) if submission.answer == answer => {
+                    return Err("answer is already confirmed as correct".to_owned())
+                }
+                (Verdict::Correct, _) => {
+                    return Err(format!(
+                        "part is already solved with answer {}",
+                        submission.answer
+                    ))
+                }
+                (verdict, _) if verdict.is_wrong() && submission.answer == answer => {
+                    return Err(format!("answer was already submitted and is {}", verdict))
+                }
+                (Verdict::TooHigh, Some((value, bound))) if value > bound => {
+                    return Err(format!("answer is above {}, which is too high", bound))
+                }
+                (Verdict::TooLow, Some((value, bound))) if value < bound => {
+                    return Err(format!("answer is below {}, which is too low", bound))
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for SubmissionLog {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", HEADER)?;
+        for s in &self.submissions {
+            let wait = s.wait.map(|wait| wait.to_string()).unwrap_or_default();
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                s.time, s.day, s.part, s.verdict, wait, s.answer
+            )?;
+        }
+        Ok(())
+    }
+}
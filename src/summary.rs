@@ -0,0 +1,139 @@
+use crate::answer::{Answer, Value};
+use crate::Part;
+use serde_json::json;
+use std::convert::TryFrom;
+use std::fmt;
+use std::time::Duration;
+
+/// Whether solving a part succeeded, returned an error or panicked.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Status {
+    Solved,
+    Failed(String),
+    Panicked(String),
+    /// Not solved within the time budget of the day.
+    TimedOut(String),
+}
+
+impl Status {
+    pub fn is_solved(&self) -> bool {
+        *self == Status::Solved
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Status::Solved => None,
+            Status::Failed(message) | Status::Panicked(message) | Status::TimedOut(message) => {
+                Some(message)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Solved => f.pad("ok"),
+            Status::Failed(_) => f.pad("FAILED"),
+            Status::Panicked(_) => f.pad("PANICKED"),
+            Status::TimedOut(_) => f.pad("TIMEOUT"),
+        }
+    }
+}
+
+/// Result of solving one part of a day's puzzle, timed without parsing the input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Outcome {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub time: Duration,
+    pub status: Status,
+}
+
+impl Outcome {
+    pub fn value(&self) -> &Value {
+        self.answer.as_ref().map_or(&Value::Empty, Answer::value)
+    }
+}
+
+fn value_to_json(value: &Value) -> serde_json::Value {
+    match value {
+        Value::Integer(value) => match i64::try_from(*value) {
+            Ok(value) => json!(value),
+            Err(_) => json!(value.to_string()),
+        },
+        Value::Text(value) => json!(value),
+        Value::Empty => serde_json::Value::Null,
+    }
+}
+
+fn status_name(status: &Status) -> &'static str {
+    match status {
+        Status::Solved => "ok",
+        Status::Failed(_) => "failed",
+        Status::Panicked(_) => "panicked",
+        Status::TimedOut(_) => "timed_out",
+    }
+}
+
+fn outcome_to_json(outcome: &Outcome) -> serde_json::Value {
+    let details: serde_json::Map<_, _> = outcome
+        .answer
+        .iter()
+        .flat_map(|answer| answer.details())
+        .map(|(name, value)| (name.clone(), value_to_json(value)))
+        .collect();
+    json!({
+        "day": outcome.day,
+        "part": outcome.part.number(),
+        "answer": value_to_json(outcome.value()),
+        "details": details,
+        "time_ns": outcome.time.as_nanos() as u64,
+        "status": status_name(&outcome.status),
+        "error": outcome.status.message(),
+    })
+}
+
+/// Serializes outcomes as a JSON array, one object per day and part.
+pub fn to_json(outcomes: &[Outcome]) -> String {
+    let value: Vec<_> = outcomes.iter().map(outcome_to_json).collect();
+    serde_json::to_string_pretty(&value).unwrap() + "\n"
+}
+
+/// Serializes outcomes as JSON Lines, one compact object per day and part.
+pub fn to_json_lines(outcomes: &[Outcome]) -> String {
+    outcomes
+        .iter()
+        .map(|outcome| outcome_to_json(outcome).to_string() + "\n")
+        .collect()
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// Serializes outcomes as CSV with a header row, one row per day and part.
+pub fn to_csv(outcomes: &[Outcome]) -> String {
+    let mut csv = "day,part,answer,time_ns,status,error\n".to_owned();
+    for outcome in outcomes {
+        let answer = match outcome.value() {
+            Value::Empty => String::new(),
+            value => value.to_string(),
+        };
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            outcome.day,
+            outcome.part,
+            csv_field(&answer),
+            outcome.time.as_nanos(),
+            status_name(&outcome.status),
+            csv_field(outcome.status.message().unwrap_or_default()),
+        ));
+    }
+    csv
+}
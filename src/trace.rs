@@ -0,0 +1,86 @@
+//! Trace of what the solvers do step by step, e.g. the rounds of a game, written to stderr or
+//! a log file depending on the verbosity chosen on the command line.
+
+use std::fmt::{self, Write as _};
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Mutex;
+
+/// What is traced: `-v` gives events to be processed further, `-vv` adds the walkthroughs to
+/// be compared with the puzzle texts.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    Off,
+    /// One line per step, e.g. `day22 round game=1 round=3 winner=2`.
+    Events,
+    /// Intermediate states printed like the example walkthroughs of the puzzle texts.
+    Walkthrough,
+}
+
+impl Level {
+    /// Level of the number of `-v` options given.
+    pub fn from_verbosity(verbosity: usize) -> Self {
+        match verbosity {
+            0 => Level::Off,
+            1 => Level::Events,
+            _ => Level::Walkthrough,
+        }
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+
+lazy_static! {
+    static ref OUTPUT: Mutex<Box<dyn Write + Send>> = Mutex::new(Box::new(io::stderr()));
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Steps of loops too long to trace each between two progress events.
+pub const STEPS_PER_PROGRESS: u32 = 1_000_000;
+
+/// True if the given level is traced, each level including the ones below.
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+/// Redirects the trace, which goes to stderr by default.
+pub fn set_output(output: Box<dyn Write + Send>) {
+    *OUTPUT.lock().unwrap() = output;
+}
+
+fn write(text: &str) {
+    // a broken trace must not keep the puzzle from being solved
+    let mut output = OUTPUT.lock().unwrap_or_else(|err| err.into_inner());
+    let _ = output
+        .write_all(text.as_bytes())
+        .and_then(|_| output.flush());
+}
+
+/// Emits an event of the given source, e.g. `day22`, as one line of `key=value` fields, quoting
+/// values containing spaces.
+pub fn event(source: &str, name: &str, fields: &[(&str, &dyn fmt::Display)]) {
+    if !enabled(Level::Events) {
+        return;
+    }
+    let mut line = format!("{} {}", source, name);
+    for (key, value) in fields {
+        let value = value.to_string();
+        match value.contains(char::is_whitespace) || value.is_empty() {
+            true => write!(line, " {}={:?}", key, value),
+            false => write!(line, " {}={}", key, value),
+        }
+        .unwrap();
+    }
+    line.push('\n');
+    write(&line);
+}
+
+/// Emits a line of a walkthrough as it appears in the puzzle text.
+pub fn walkthrough(text: impl fmt::Display) {
+    if enabled(Level::Walkthrough) {
+        write(&format!("{}\n", text));
+    }
+}
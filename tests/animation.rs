@@ -0,0 +1,114 @@
+//! Generations of the cellular automata of days 11, 17 and 24 and the pictures of days 3 and
+//! 20 on the worked examples.
+
+use adventofcode_2020::animation::{Animation, Frame};
+use adventofcode_2020::days;
+use adventofcode_2020::parameters::Parameters;
+use adventofcode_2020::Part::{self, One, Two};
+use std::fs;
+
+fn animate(day: u32, fixture: &str, part: Part) -> Option<Animation> {
+    let filename = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), fixture);
+    let solution = days::solution(day).unwrap();
+    let input = solution
+        .parse(&fs::read_to_string(filename).unwrap())
+        .unwrap();
+    let simulation = solution.simulation(part, input.as_ref())?;
+    Some(Animation::new(simulation))
+}
+
+/// Generation and live cells of every frame up to the end of the simulation.
+fn counts(animation: &mut Animation) -> Vec<(usize, usize)> {
+    let mut counts = vec![];
+    loop {
+        let frame = animation.frame();
+        counts.push((frame.generation, frame.live));
+        if !animation.forward() {
+            return counts;
+        }
+    }
+}
+
+#[test]
+fn day11_rounds_until_stable() {
+    let mut animation = animate(11, "day-11.txt", One).unwrap();
+    assert_eq!(
+        counts(&mut animation),
+        vec![(0, 0), (1, 71), (2, 20), (3, 51), (4, 30), (5, 37)]
+    );
+    assert!(animation.at_end());
+
+    let mut animation = animate(11, "day-11.txt", Two).unwrap();
+    assert_eq!(counts(&mut animation).last(), Some(&(6, 26)));
+}
+
+#[test]
+fn day11_frames_show_seats() {
+    let mut animation = animate(11, "day-11.txt", One).unwrap();
+    assert_eq!(animation.frame().rows[0], "L.LL.LL.LL");
+    animation.forward();
+    assert_eq!(animation.frame().rows[0], "#.##.##.##");
+}
+
+#[test]
+fn day17_cycles() {
+    let mut animation = animate(17, "day-17.txt", One).unwrap();
+    let counts = counts(&mut animation);
+    assert_eq!(counts[1], (1, 11));
+    assert_eq!(counts.last(), Some(&(6, 112)));
+
+    let mut animation = animate(17, "day-17.txt", Two).unwrap();
+    animation.forward();
+    assert_eq!(animation.frame().live, 29);
+}
+
+#[test]
+fn day03_path_is_marked_on_the_map() {
+    let animation = animate(3, "day-03.txt", One).unwrap();
+    let Frame { rows, live, .. } = animation.frame().clone();
+    assert_eq!(live, 7);
+    assert_eq!(rows.len(), 11);
+    assert_eq!(rows[10], ".#..#...#.#.#..#...#.#.#..#...X.#");
+    let hits: usize = rows.iter().map(|row| row.matches('X').count()).sum();
+    assert_eq!(hits, live);
+
+    let mut parameters = Parameters::new();
+    parameters.set(3, "part2_search", "3,2");
+    let filename = format!("{}/tests/fixtures/day-03.txt", env!("CARGO_MANIFEST_DIR"));
+    let (_, simulation) = days::simulation(3, &filename, Some(Two), &parameters).unwrap();
+    assert_eq!(simulation.frame().rows[2], ".#..O.#..#..#....#..#.");
+    assert!(animate(3, "day-03.txt", Two).is_none());
+}
+
+#[test]
+fn day24_only_second_part_is_animated() {
+    assert!(animate(24, "day-24.txt", One).is_none());
+    let mut animation = animate(24, "day-24.txt", Two).unwrap();
+    let counts = counts(&mut animation);
+    assert_eq!(counts[0], (0, 10));
+    assert_eq!(counts[10], (10, 37));
+    assert_eq!(counts.last(), Some(&(100, 2208)));
+}
+
+#[test]
+fn day24_frames_show_hexagonal_floor() {
+    let animation = animate(24, "day-24.txt", Two).unwrap();
+    let Frame { rows, live, .. } = animation.frame().clone();
+    let black: usize = rows.iter().map(|row| row.matches('#').count()).sum();
+    assert_eq!(black, live);
+    assert!(rows.iter().all(|row| row.len() == rows[0].len()));
+}
+
+#[test]
+fn stepping_back_returns_earlier_frames() {
+    let mut animation = animate(11, "day-11.txt", One).unwrap();
+    let first = animation.frame().clone();
+    assert!(!animation.back());
+    animation.forward();
+    animation.forward();
+    assert!(animation.back());
+    assert!(animation.back());
+    assert_eq!(animation.frame(), &first);
+    assert!(animation.forward());
+    assert_eq!(animation.frame().generation, 1);
+}
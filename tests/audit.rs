@@ -0,0 +1,147 @@
+//! Reports of the passwords of day 2 violating their policies, line by line.
+
+use adventofcode_2020::audit::Violation;
+use adventofcode_2020::days::{self, day02::PasswordPolicy, day02::Policy, day02::Rule};
+use adventofcode_2020::parameters::Parameters;
+use adventofcode_2020::Part;
+use std::process::Command;
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+fn reasons(name: &str, policy: &str) -> Vec<(usize, String)> {
+    let mut parameters = Parameters::new();
+    parameters.set(2, "part1_policy", policy);
+    let audits = days::audit(2, &fixture(name), &[Part::One], &parameters).unwrap();
+    let (_, audit) = &audits[0];
+    assert_eq!(audit.rules, format!("{} policy", policy));
+    audit
+        .violations
+        .iter()
+        .map(|violation| (violation.line, violation.reason.clone()))
+        .collect()
+}
+
+#[test]
+fn violations_explain_the_policy() {
+    assert_eq!(
+        reasons("day-02.txt", "count"),
+        [(2, "'b' appears 0 times, expected 1–3".to_owned())]
+    );
+    assert_eq!(
+        reasons("day-02.txt", "positions"),
+        [
+            (2, "neither position 1 nor 3 contains 'b'".to_owned()),
+            (3, "both positions 2 and 9 contain 'c'".to_owned()),
+        ]
+    );
+    assert_eq!(
+        reasons("day-02-unicode.txt", "forbidden"),
+        [
+            (
+                1,
+                "position 3 contains '1', forbidden at positions 1–3".to_owned()
+            ),
+            (
+                2,
+                "position 3 contains 'ö', forbidden at positions 2–4".to_owned()
+            ),
+            (
+                5,
+                "position 1 contains 'S', forbidden at positions 1–1".to_owned()
+            ),
+        ]
+    );
+    assert_eq!(
+        reasons("day-02-unicode.txt", "length")[0],
+        (1, "password has 7 characters, expected 1–3".to_owned())
+    );
+}
+
+/// Passwords reading the same backwards, ignoring their rule.
+struct Palindrome;
+
+impl PasswordPolicy for Palindrome {
+    fn name(&self) -> &'static str {
+        "palindrome"
+    }
+
+    fn violation(&self, _: &Rule, password: &str) -> Option<String> {
+        match password.chars().eq(password.chars().rev()) {
+            true => None,
+            false => Some(format!("'{}' reads differently backwards", password)),
+        }
+    }
+}
+
+#[test]
+fn registered_policies_are_chosen_by_name() {
+    Policy::register(&Palindrome).unwrap();
+    assert_eq!(
+        reasons("day-02.txt", "palindrome"),
+        [
+            (1, "'abcde' reads differently backwards".to_owned()),
+            (2, "'cdefg' reads differently backwards".to_owned()),
+        ]
+    );
+    assert_eq!(
+        Policy::register(&Palindrome),
+        Err("policy 'palindrome' is known already".to_owned())
+    );
+    let mut parameters = Parameters::new();
+    parameters.set(2, "part2_policy", "palindrome");
+    let answers = days::solve(2, &fixture("day-02.txt"), &[Part::Two], &parameters).unwrap();
+    assert_eq!(answers[0].1.value().to_string(), "1");
+}
+
+#[test]
+fn malformed_lines_are_reported_separately() {
+    let audits = days::audit(
+        2,
+        &fixture("day-02-malformed.txt"),
+        &Part::ALL,
+        &Parameters::new(),
+    )
+    .unwrap();
+    for (_, audit) in &audits {
+        assert_eq!(audit.checked, 0);
+        assert_eq!(audit.violations, Vec::<Violation>::new());
+        let lines: Vec<_> = audit.malformed.iter().map(|err| err.line()).collect();
+        assert_eq!(lines, [1, 2, 3, 4]);
+    }
+}
+
+#[test]
+fn days_without_audit_are_rejected() {
+    let err = days::audit(1, &fixture("day-01.txt"), &[Part::One], &Parameters::new()).unwrap_err();
+    assert_eq!(err.to_string(), "day 1 part 1 has no audit");
+}
+
+#[test]
+fn audit_lists_violations_and_malformed_lines() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["audit", "--day", "2", "--part", "1", "--input"])
+        .arg(fixture("day-02-malformed.txt"))
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("Part 1 (count policy): 0 of 0 record(s) invalid\n"));
+    assert!(stdout.contains("\n4 malformed line(s)\n"), "{}", stdout);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["audit", "--day", "2", "--input"])
+        .arg(fixture("day-02.txt"))
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(
+        stdout,
+        "Part 1 (count policy): 1 of 3 record(s) invalid\n\
+         line 2, \"1-3 b: cdefg\": 'b' appears 0 times, expected 1–3\n\
+         Part 2 (positions policy): 2 of 3 record(s) invalid\n\
+         line 2, \"1-3 b: cdefg\": neither position 1 nor 3 contains 'b'\n\
+         line 3, \"2-9 c: ccccccccc\": both positions 2 and 9 contain 'c'\n"
+    );
+}
@@ -0,0 +1,137 @@
+//! Timing statistics, their JSON form and the comparison with a baseline of `aoc bench`.
+
+use adventofcode_2020::bench::{self, format_duration, Benchmark, Phase, Stats};
+use adventofcode_2020::Part;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+fn millis(samples: &[u64]) -> Vec<Duration> {
+    samples
+        .iter()
+        .map(|&ms| Duration::from_millis(ms))
+        .collect()
+}
+
+fn median(ms: u64) -> Stats {
+    Stats::new(millis(&[ms]))
+}
+
+#[test]
+fn stats_of_odd_number_of_samples() {
+    let stats = Stats::new(millis(&[7, 1, 3, 9, 5]));
+    assert_eq!(stats.median, Duration::from_millis(5));
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.max, Duration::from_millis(9));
+}
+
+#[test]
+fn stats_of_even_number_of_samples() {
+    let stats = Stats::new(millis(&[4, 1, 3, 2]));
+    assert_eq!(stats.median, Duration::from_micros(2500));
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.max, Duration::from_millis(4));
+}
+
+#[test]
+fn stats_of_single_sample() {
+    let stats = Stats::new(millis(&[3]));
+    let three = Duration::from_millis(3);
+    assert_eq!((stats.median, stats.min, stats.max), (three, three, three));
+}
+
+#[test]
+fn json_round_trip() {
+    let benchmarks = vec![
+        Benchmark {
+            day: 1,
+            input: "inputs/day-01.txt".to_owned(),
+            fingerprint: "00000000deadbeef".to_owned(),
+            phases: vec![
+                (Phase::Parse, Stats::new(millis(&[1, 2, 3]))),
+                (Phase::Part(Part::One), Stats::new(millis(&[4, 5]))),
+                (Phase::Part(Part::Two), Stats::new(millis(&[6]))),
+            ],
+        },
+        Benchmark {
+            day: 23,
+            input: "-".to_owned(),
+            fingerprint: "cbf29ce484222325".to_owned(),
+            phases: vec![
+                (Phase::Parse, Stats::new(vec![Duration::from_nanos(17)])),
+                (Phase::Part(Part::Two), Stats::new(millis(&[2000, 3000]))),
+            ],
+        },
+    ];
+    let json = bench::to_json(&benchmarks, 3);
+    assert_eq!(bench::from_json(&json), Ok(benchmarks));
+}
+
+#[test]
+fn json_without_benchmarks_is_rejected() {
+    assert_eq!(
+        bench::from_json("{\"repeats\": 3}"),
+        Err("missing 'benchmarks' array".to_owned())
+    );
+    assert!(bench::from_json("{\"benchmarks\": [{\"day\": 1}]}").is_err());
+}
+
+#[test]
+fn threshold_at_limit_is_accepted() {
+    assert!(!median(110).slower_than(&median(100), 10.0));
+    assert!(!median(100).slower_than(&median(100), 0.0));
+}
+
+#[test]
+fn threshold_below_limit_is_accepted() {
+    assert!(!median(109).slower_than(&median(100), 10.0));
+    assert!(!median(50).slower_than(&median(100), 10.0));
+}
+
+#[test]
+fn threshold_above_limit_is_slower() {
+    assert!(median(111).slower_than(&median(100), 10.0));
+    assert!(median(101).slower_than(&median(100), 0.0));
+    assert!((median(111).change(&median(100)) - 11.0).abs() < 1e-9);
+}
+
+#[test]
+fn durations_are_formatted_in_readable_units() {
+    assert_eq!(format_duration(Duration::from_nanos(17)), "17ns");
+    assert_eq!(format_duration(Duration::from_micros(1500)), "1.5ms");
+    assert_eq!(format_duration(Duration::from_millis(2500)), "2.50s");
+}
+
+#[test]
+fn baselines_recognize_inputs_stored_elsewhere() {
+    let directory = std::env::temp_dir().join(format!("aoc-bench-{}", std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    let fixture = format!("{}/tests/fixtures/day-01.txt", env!("CARGO_MANIFEST_DIR"));
+    let moved = directory.join("moved.txt");
+    fs::copy(&fixture, &moved).unwrap();
+    let baseline = directory.join("baseline.json");
+    let bench = |input: &Path, options: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["bench", "--day", "1", "--repeat", "1", "--input"])
+            .arg(input)
+            .args(options)
+            .arg(&baseline)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{:?}", output);
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    bench(Path::new(&fixture), &["--output"]);
+    // a generous threshold for the timings of a single run
+    let compared = bench(&moved, &["--threshold", "1e9", "--baseline"]);
+    fs::remove_dir_all(&directory).unwrap();
+    let lines: Vec<_> = compared.lines().collect();
+    assert!(lines[0].ends_with("Baseline    Change"), "{}", compared);
+    assert!(
+        lines[1..].iter().all(|line| line.ends_with('%')),
+        "{}",
+        compared
+    );
+}
@@ -0,0 +1,229 @@
+//! Time budgets of the runner cancelling solvers stuck on inputs they cannot solve.
+
+use adventofcode_2020::cancel::{self, Token};
+use adventofcode_2020::days;
+use adventofcode_2020::parameters::Parameters;
+use adventofcode_2020::summary::{Outcome, Status};
+use adventofcode_2020::Part;
+use std::fs;
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Rules valid for every field, leaving the mapping of the fields ambiguous.
+const AMBIGUOUS_FIELDS: &str = "\
+class: 1-10 or 20-30
+row: 1-10 or 20-30
+
+your ticket:
+5,6
+
+nearby tickets:
+5,6
+";
+
+/// Two allergens which may be in either of two ingredients.
+const AMBIGUOUS_ALLERGENS: &str = "mxmxvkd kfcds (contains dairy, fish)\n";
+
+/// Moves of the cups of day 23 far beyond any budget.
+const ENDLESS_MOVES: u32 = 4_000_000_000;
+
+/// Bags containing each other.
+const CYCLIC_RULES: &str = "\
+shiny gold bags contain 1 dark red bag.
+dark red bags contain 2 shiny gold bags, 1 faded blue bag.
+faded blue bags contain no other bags.
+";
+
+fn solve(
+    day: u32,
+    input: &str,
+    parameters: &Parameters,
+    budget: Option<Duration>,
+) -> (Vec<Outcome>, Duration) {
+    let directory = std::env::temp_dir().join(format!("aoc-cancel-{}-{}", day, std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    let filename = directory.join(format!("day-{:02}.txt", day));
+    fs::write(&filename, input).unwrap();
+    let start = Instant::now();
+    let outcomes = days::solve_isolated(
+        day,
+        filename.to_str().unwrap(),
+        &Part::ALL,
+        parameters,
+        budget,
+    );
+    let elapsed = start.elapsed();
+    fs::remove_dir_all(&directory).unwrap();
+    (outcomes, elapsed)
+}
+
+/// Cups of day 23 moved far more often than could be done within any budget.
+fn endless_moves() -> Parameters {
+    let mut parameters = Parameters::new();
+    parameters
+        .assign(23, &format!("part1_moves={}", ENDLESS_MOVES))
+        .unwrap();
+    parameters
+}
+
+#[test]
+fn ambiguous_fields_are_reported() {
+    let (outcomes, _) = solve(16, AMBIGUOUS_FIELDS, &Parameters::new(), None);
+    assert_eq!(outcomes[0].status, Status::Solved);
+    assert_eq!(outcomes[1].status, Status::Solved);
+    let answer = outcomes[1].answer.as_ref().unwrap();
+    assert_eq!(answer.value().to_string(), "-");
+    assert!(answer.description().contains("Ambiguous rules"));
+}
+
+#[test]
+fn ambiguous_allergens_are_reported() {
+    let (outcomes, _) = solve(21, AMBIGUOUS_ALLERGENS, &Parameters::new(), None);
+    assert!(outcomes.iter().all(|outcome| {
+        let answer = outcome.answer.as_ref().unwrap();
+        answer.value().to_string() == "-" && answer.description().contains("Ambiguous foods")
+    }));
+}
+
+/// Number of threads of this process named after the solver of a day, where the system lists
+/// them.
+fn solver_threads(day: u32) -> Option<usize> {
+    let name = format!("day{:02}", day);
+    let tasks = fs::read_dir("/proc/self/task").ok()?;
+    let names = tasks.map(|task| fs::read_to_string(task.unwrap().path().join("comm")));
+    Some(
+        names
+            .filter(|comm| comm.as_ref().is_ok_and(|comm| comm.trim() == name))
+            .count(),
+    )
+}
+
+#[test]
+fn slow_solvers_time_out() {
+    let budget = Duration::from_millis(200);
+    let (outcomes, elapsed) = solve(23, "389125467\n", &endless_moves(), Some(budget));
+    assert_eq!(outcomes.len(), 2);
+    assert!(outcomes
+        .iter()
+        .all(|outcome| matches!(outcome.status, Status::TimedOut(_))));
+    assert_eq!(outcomes[0].answer, None);
+    assert_eq!(outcomes[0].time, budget);
+    assert_eq!(outcomes[0].status.to_string(), "TIMEOUT");
+    assert!(elapsed < Duration::from_secs(5), "{:?}", elapsed);
+
+    // the cancelled solver stops instead of running on detached
+    let start = Instant::now();
+    while solver_threads(23).unwrap_or(0) > 0 {
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "solver still running"
+        );
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+fn long_loops_stop_once_cancelled() {
+    let token = Token::new();
+    token.cancel();
+    let parts = [
+        (11, "day-11.txt", Part::One),
+        (15, "day-15.txt", Part::Two),
+        (17, "day-17.txt", Part::Two),
+        (23, "day-23.txt", Part::Two),
+        (24, "day-24.txt", Part::Two),
+    ];
+    for &(day, fixture, part) in parts.iter() {
+        let filename = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), fixture);
+        let answers = cancel::with_token(token.clone(), || {
+            days::solve(day, &filename, &[part], &Parameters::new()).unwrap()
+        });
+        let answer = &answers[0].1;
+        assert_eq!(
+            (answer.value().to_string().as_str(), answer.description()),
+            ("-", "Cancelled."),
+            "day {}",
+            day
+        );
+    }
+}
+
+#[test]
+fn cyclic_rules_are_detected() {
+    let (outcomes, _) = solve(7, CYCLIC_RULES, &Parameters::new(), None);
+    assert_eq!(outcomes[0].value().to_string(), "1");
+    assert_eq!(outcomes[1].status, Status::Solved);
+    let answer = outcomes[1].answer.as_ref().unwrap();
+    assert_eq!(answer.value().to_string(), "-");
+    assert!(answer.description().contains("contains itself"));
+}
+
+#[test]
+fn solvers_within_budget_are_not_affected() {
+    let input = fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/day-16-departure.txt"
+    ))
+    .unwrap();
+    let budget = Some(Duration::from_secs(10));
+    let (outcomes, _) = solve(16, &input, &Parameters::new(), budget);
+    assert!(outcomes.iter().all(|outcome| outcome.status.is_solved()));
+}
+
+#[test]
+fn cancellation_applies_to_the_thread_of_the_token() {
+    let token = Token::new();
+    assert!(!cancel::cancelled());
+    cancel::with_token(token.clone(), || {
+        assert!(!cancel::cancelled());
+        token.cancel();
+        assert!(cancel::cancelled());
+    });
+    assert!(!cancel::cancelled());
+}
+
+#[test]
+fn run_reports_timeouts() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "23", "--timeout", "0.2", "--format", "json"])
+        .args(["--param", &format!("part1_moves={}", ENDLESS_MOVES)])
+        .args([
+            "--input",
+            concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/day-23.txt"),
+        ])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.lines().count(), 2);
+    assert!(stdout.contains(r#""status":"timed_out""#), "{}", stdout);
+}
+
+#[test]
+fn timeouts_are_checked() {
+    let run = |timeout: &str| {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["run", "--day", "1", "--timeout", timeout, "--input"])
+            .arg(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/day-01.txt"
+            ))
+            .output()
+            .unwrap()
+    };
+    let output = run("1e30");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("for option '--timeout': expected at most 18446744073709551615 seconds"),
+        "{}",
+        stderr
+    );
+    let output = run("-1");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("expected a positive number"));
+    // budgets beyond the range of the clock are accepted as unlimited
+    let output = run("1e19");
+    assert!(output.status.success(), "{:?}", output);
+}
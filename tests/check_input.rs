@@ -0,0 +1,120 @@
+//! Checking inputs without solving, reporting every problem instead of only the first one.
+
+use adventofcode_2020::days::{self, NUMBER_OF_DAYS};
+use adventofcode_2020::parameters::Parameters;
+use adventofcode_2020::Part;
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+fn problems(day: u32, name: &str) -> Vec<(usize, String)> {
+    days::check_input(day, &fixture(name))
+        .unwrap()
+        .iter()
+        .map(|err| (err.line(), err.message().to_owned()))
+        .collect()
+}
+
+#[test]
+fn examples_have_no_problems() {
+    for day in 1..=NUMBER_OF_DAYS {
+        let name = format!("day-{:02}.txt", day);
+        assert_eq!(problems(day, &name), [], "{}", name);
+    }
+}
+
+#[test]
+fn day20_reports_all_tiles() {
+    assert_eq!(
+        problems(20, "day-20-malformed.txt"),
+        [
+            (3, "invalid character 'x'".to_owned()),
+            (6, "tile has 2 rows, expected 3 to 32".to_owned()),
+            (
+                10,
+                "invalid tile ID 'x': invalid digit found in string".to_owned()
+            ),
+            (14, "3 tiles cannot form a square image".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn day02_reports_rules_and_passwords() {
+    assert_eq!(
+        problems(2, "day-02-malformed.txt"),
+        [
+            (1, "invalid character range 'z-a'".to_owned()),
+            (2, "invalid password character ' '".to_owned()),
+            (3, "positions start at 1".to_owned()),
+            (4, "missing policy characters".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn day16_reports_sections_and_tickets() {
+    assert_eq!(
+        problems(16, "day-16-malformed.txt"),
+        [
+            (4, "expected 'your ticket:'".to_owned()),
+            (8, "ticket has 3 values, expected 2".to_owned()),
+            (
+                9,
+                "invalid value 'x': invalid digit found in string".to_owned()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn day08_reports_programs_leaving_their_end() {
+    let cases = [
+        ("day-08-empty.txt", (1, "missing instructions")),
+        (
+            "day-08-jump-forward.txt",
+            (
+                1,
+                "jump of +5 from instruction 1 leaves the program ending after instruction 1",
+            ),
+        ),
+        (
+            "day-08-jump-back.txt",
+            (
+                1,
+                "jump of -3 from instruction 1 leaves the program ending after instruction 1",
+            ),
+        ),
+    ];
+    for (name, (line, message)) in cases {
+        assert_eq!(problems(8, name), [(line, message.to_owned())], "{}", name);
+        assert!(
+            days::solve(8, &fixture(name), &Part::ALL, &Parameters::new()).is_err(),
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn day22_reports_decks() {
+    assert_eq!(
+        problems(22, "day-22-malformed.txt"),
+        [
+            (5, "expected 'Player 2:'".to_owned()),
+            (
+                6,
+                "invalid card 'x': invalid digit found in string".to_owned()
+            ),
+            (10, "found 3 players, expected 2".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn file_name_is_attached() {
+    let filename = fixture("day-22-malformed.txt");
+    let errors = days::check_input(22, &filename).unwrap();
+    assert_eq!(errors[0].file(), Some(filename.as_str()));
+}
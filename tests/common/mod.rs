@@ -0,0 +1,82 @@
+//! Local stand-in for the puzzle server.
+
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+pub const SESSION: &str = "53616c7465645f5f";
+
+/// Request received by the stand-in server.
+pub struct Request {
+    /// Request line, e.g. `GET /2020/day/1/input HTTP/1.1`.
+    pub line: String,
+    pub body: String,
+    /// Whether the session cookie was sent.
+    pub authorized: bool,
+    pub user_agent: Option<String>,
+}
+
+/// Serves the responses returned by `respond` as status and body, counting all requests.
+pub fn serve<F>(respond: F) -> (String, Arc<AtomicUsize>)
+where
+    F: Fn(&Request) -> (&'static str, String) + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&requests);
+    let cookie = format!("cookie: session={}", SESSION);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut headers = Vec::new();
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                    break;
+                }
+                headers.push(line.trim_end().to_owned());
+            }
+            let length = headers
+                .iter()
+                .find_map(|header| {
+                    let (name, value) = header.split_once(':')?;
+                    match name.eq_ignore_ascii_case("content-length") {
+                        true => value.trim().parse().ok(),
+                        false => None,
+                    }
+                })
+                .unwrap_or(0);
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            counter.fetch_add(1, Ordering::SeqCst);
+            let request = Request {
+                line: headers.remove(0),
+                body: String::from_utf8(body).unwrap(),
+                authorized: headers.iter().any(|h| h.eq_ignore_ascii_case(&cookie)),
+                user_agent: headers.iter().find_map(|header| {
+                    let (name, value) = header.split_once(':')?;
+                    match name.eq_ignore_ascii_case("user-agent") {
+                        true => Some(value.trim().to_owned()),
+                        false => None,
+                    }
+                }),
+            };
+            let (status, body) = respond(&request);
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+        }
+    });
+    (url, requests)
+}
@@ -0,0 +1,94 @@
+//! Solutions have to agree with their variants, disagreements being shrunk to small inputs.
+
+use adventofcode_2020::answer::{Answer, Value};
+use adventofcode_2020::days;
+use adventofcode_2020::differential::{self, SOLUTION};
+use adventofcode_2020::error::ParseError;
+use adventofcode_2020::generate;
+use adventofcode_2020::parameters::Parameters;
+use adventofcode_2020::solution::{Solution, Variant};
+use adventofcode_2020::Part;
+
+fn agree_on_generated_inputs(day: u32, parameters: &Parameters) {
+    let solution = days::lookup(day, parameters).unwrap();
+    assert!(!differential::parts(solution.as_ref()).is_empty());
+    for seed in 0..20 {
+        let input = generate::generate(day, seed, None).unwrap().input;
+        let divergences = differential::compare(solution.as_ref(), &input).unwrap();
+        assert_eq!(divergences, vec![], "day {} seed {}", day, seed);
+    }
+}
+
+#[test]
+fn variants_agree_on_generated_inputs() {
+    let mut parameters = Parameters::new();
+    parameters.set(23, "part2_moves", "10000");
+    parameters.set(23, "part2_cups", "1000");
+    for &day in [1, 9, 23].iter() {
+        agree_on_generated_inputs(day, &parameters);
+    }
+}
+
+#[test]
+fn variants_pick_the_same_of_several_combinations() {
+    let solution = days::lookup(1, &Parameters::new()).unwrap();
+    // 5 + 2015 and 1 + 2019 for two entries, 20 + 1000 + 1000 and 500 + 520 + 1000 for three
+    for input in ["5\n1\n2019\n2015\n", "20\n500\n520\n1000\n1000\n"].iter() {
+        let divergences = differential::compare(solution.as_ref(), input).unwrap();
+        assert_eq!(divergences, vec![], "{:?}", input);
+    }
+}
+
+/// Sums the numbers, with a variant miscounting multiples of seven.
+struct Sum;
+
+impl Solution for Sum {
+    type Input = Vec<u32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(|line| line.parse().unwrap()).collect())
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> Answer {
+        let sum: u32 = numbers.iter().sum();
+        Answer::new(sum, "sum")
+    }
+
+    fn part2(&self, _: &Self::Input) -> Answer {
+        Answer::empty("nothing")
+    }
+
+    fn variants(&self) -> Vec<Variant<Self>> {
+        vec![Variant {
+            name: "buggy",
+            part: Part::One,
+            solve: |_, numbers| {
+                let sum: u32 = numbers.iter().filter(|number| *number % 7 != 0).sum();
+                Answer::new(sum, "sum")
+            },
+        }]
+    }
+}
+
+#[test]
+fn disagreements_are_shrunk_to_smallest_input() {
+    let input = "1\n2\n3\n4\n5\n6\n14\n8\n9\n10\n11\n";
+    let divergences = differential::compare(&Sum, input).unwrap();
+    assert_eq!(divergences.len(), 1);
+    let divergence = &divergences[0];
+    assert_eq!(divergence.part, Part::One);
+    assert_eq!(divergence.input, "14\n");
+    assert_eq!(
+        divergence.outcomes,
+        vec![
+            (SOLUTION, Some(Value::from(14u32))),
+            ("buggy", Some(Value::from(0u32)))
+        ]
+    );
+}
+
+#[test]
+fn agreeing_variants_report_nothing() {
+    let divergences = differential::compare(&Sum, "1\n2\n3\n").unwrap();
+    assert_eq!(divergences, vec![]);
+}
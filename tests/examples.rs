@@ -0,0 +1,274 @@
+//! Worked examples from the puzzle texts, run through the same entry point as the binaries.
+
+use adventofcode_2020::answer::Value;
+use adventofcode_2020::days;
+use adventofcode_2020::parameters::Parameters;
+use adventofcode_2020::Part::{self, One, Two};
+
+fn answer(day: u32, fixture: &str, part: Part, parameters: &Parameters) -> Value {
+    let filename = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), fixture);
+    let answers = days::solve(day, &filename, &[part], parameters).unwrap();
+    answers[0].1.value().clone()
+}
+
+fn check(day: u32, fixture: &str, part: Part, expected: impl Into<Value>) {
+    check_with(day, fixture, part, &[], expected);
+}
+
+/// Checks an example using other values of the puzzle parameters than the puzzle input.
+fn check_with(
+    day: u32,
+    fixture: &str,
+    part: Part,
+    parameters: &[(&str, &str)],
+    expected: impl Into<Value>,
+) {
+    let mut values = Parameters::new();
+    for (name, value) in parameters {
+        values.set(day, name, value);
+    }
+    assert_eq!(
+        answer(day, fixture, part, &values),
+        expected.into(),
+        "{} part {} {:?}",
+        fixture,
+        part,
+        parameters
+    );
+}
+
+#[test]
+fn day01() {
+    check(1, "day-01.txt", One, 514579);
+    check(1, "day-01.txt", Two, 241861950);
+    // 1721 + 979 + 299 + 675, the only four entries summing to 3674
+    check_with(
+        1,
+        "day-01.txt",
+        Two,
+        &[("part2_entries", "4"), ("target", "3674")],
+        1721 * 979 * 299 * 675u64,
+    );
+    // refunds, with a product of the triple beyond all machine integers
+    check(1, "day-01-refunds.txt", One, -1260000);
+    check(
+        1,
+        "day-01-refunds.txt",
+        Two,
+        "-163620000000000000000000000000000000000000",
+    );
+}
+
+#[test]
+fn day02() {
+    check(2, "day-02.txt", One, 2);
+    check(2, "day-02.txt", Two, 1);
+    let policies = [
+        ("count", 4),
+        ("positions", 1),
+        ("forbidden", 2),
+        ("length", 1),
+    ];
+    for &(policy, valid) in policies.iter() {
+        check_with(
+            2,
+            "day-02-unicode.txt",
+            One,
+            &[("part1_policy", policy)],
+            valid,
+        );
+    }
+}
+
+#[test]
+fn day03() {
+    check(3, "day-03.txt", One, 7);
+    check(3, "day-03.txt", Two, 336);
+    check_with(3, "day-03.txt", One, &[("part1_slope", "-1,1")], 5);
+    check_with(
+        3,
+        "day-03.txt",
+        Two,
+        &[("part2_slopes", "1,1 -1,1 -3,2")],
+        10,
+    );
+    check_with(3, "day-03.txt", Two, &[("part2_search", "3,2")], 1);
+}
+
+#[test]
+fn day03_odd_height() {
+    // Going down two at a time still reaches the last row of a map with an odd height.
+    check(3, "day-03-odd-height.txt", One, 1);
+    check(3, "day-03-odd-height.txt", Two, 2);
+}
+
+#[test]
+fn day04() {
+    check(4, "day-04.txt", One, 2);
+    check(4, "day-04-invalid.txt", Two, 0);
+    check(4, "day-04-valid.txt", Two, 4);
+}
+
+#[test]
+fn day05() {
+    check(5, "day-05.txt", One, 820);
+    check(5, "day-05.txt", Two, Value::Empty);
+}
+
+#[test]
+fn day06() {
+    check(6, "day-06.txt", One, 11);
+    check(6, "day-06.txt", Two, 6);
+}
+
+#[test]
+fn day07() {
+    check(7, "day-07.txt", One, 4);
+    check(7, "day-07.txt", Two, 32);
+    check(7, "day-07-nested.txt", Two, 126);
+}
+
+#[test]
+fn day08() {
+    check(8, "day-08.txt", One, 5);
+    check(8, "day-08.txt", Two, 8);
+}
+
+#[test]
+fn day08_modified_jump_leaving_the_program() {
+    // Turning the first `nop +5` into a jump leaves the program, only the last change finishes.
+    check(8, "day-08-modified-jump.txt", One, 1);
+    check(8, "day-08-modified-jump.txt", Two, 1);
+}
+
+#[test]
+fn day09() {
+    // This fixture extends the puzzle's introductory example (numbers 1 to 25 as preamble) to
+    // the default preamble of 25, while the worked example uses a preamble of 5.
+    check(9, "day-09.txt", One, 100);
+    check(9, "day-09.txt", Two, 25);
+    let preamble = [("preamble", "5")];
+    check_with(9, "day-09-preamble-5.txt", One, &preamble, 127);
+    check_with(9, "day-09-preamble-5.txt", Two, &preamble, 62);
+}
+
+#[test]
+fn day10() {
+    check(10, "day-10.txt", One, 35);
+    check(10, "day-10.txt", Two, 8);
+    check(10, "day-10-larger.txt", One, 220);
+    check(10, "day-10-larger.txt", Two, 19208);
+}
+
+#[test]
+fn day11() {
+    check(11, "day-11.txt", One, 37);
+    check(11, "day-11.txt", Two, 26);
+}
+
+#[test]
+fn day12() {
+    check(12, "day-12.txt", One, 25);
+    check(12, "day-12.txt", Two, 286);
+}
+
+#[test]
+fn day13() {
+    check(13, "day-13.txt", One, 295);
+    check(13, "day-13.txt", Two, 1068781);
+    check(13, "day-13-second.txt", Two, 1202161486);
+}
+
+#[test]
+fn day14() {
+    // The first example has too many floating bits to be run with the second decoder.
+    check(14, "day-14.txt", One, 165);
+    check(14, "day-14-v2.txt", Two, 208);
+}
+
+#[test]
+fn day15() {
+    check(15, "day-15.txt", One, 436);
+    check(15, "day-15-second.txt", One, 1836);
+}
+
+#[test]
+#[ignore = "slow without optimizations, run with `cargo test --release -- --ignored`"]
+fn day15_part2() {
+    check(15, "day-15.txt", Two, 175594);
+    check(15, "day-15-second.txt", Two, 362);
+}
+
+#[test]
+fn day16() {
+    check(16, "day-16.txt", One, 71);
+    // Second example with the class and row fields renamed to departure fields.
+    check(16, "day-16-departure.txt", Two, 11 * 12);
+}
+
+#[test]
+fn day17() {
+    check(17, "day-17.txt", One, 112);
+    check(17, "day-17.txt", Two, 848);
+}
+
+#[test]
+fn day18() {
+    check(18, "day-18.txt", One, 26 + 437 + 12240 + 13632 + 71 + 51);
+    check(18, "day-18.txt", Two, 231 + 51 + 46 + 1445 + 669060 + 23340);
+}
+
+#[test]
+fn day19() {
+    check(19, "day-19.txt", One, 2);
+    check(19, "day-19-loops.txt", One, 3);
+    check(19, "day-19-loops.txt", Two, 12);
+}
+
+#[test]
+fn day20() {
+    check(20, "day-20.txt", One, 20899048083289u64);
+    check(20, "day-20.txt", Two, 273);
+}
+
+#[test]
+fn day21() {
+    check(21, "day-21.txt", One, 5);
+    check(21, "day-21.txt", Two, "mxmxvkd,sqjhc,fvjkl");
+}
+
+#[test]
+fn day22() {
+    check(22, "day-22.txt", One, 306);
+    check(22, "day-22.txt", Two, 291);
+}
+
+#[test]
+fn day23() {
+    check(23, "day-23.txt", One, "67384529");
+    check_with(23, "day-23.txt", One, &[("part1_moves", "10")], "92658374");
+    // After 10 moves cups 9 and 2 follow cup 1, as long as no cups are added.
+    let nine_cups = [("part2_moves", "10"), ("part2_cups", "9")];
+    check_with(23, "day-23.txt", Two, &nine_cups, 18);
+    let six_cups = [("part2_moves", "10"), ("part2_cups", "6")];
+    check_with(23, "day-23.txt", Two, &six_cups, Value::Empty);
+}
+
+#[test]
+#[ignore = "slow without optimizations, run with `cargo test --release -- --ignored`"]
+fn day23_part2() {
+    check(23, "day-23.txt", Two, 149245887792u64);
+}
+
+#[test]
+fn day24() {
+    check(24, "day-24.txt", One, 10);
+    check(24, "day-24.txt", Two, 2208);
+    check_with(24, "day-24.txt", Two, &[("days", "10")], 37);
+}
+
+#[test]
+fn day25() {
+    check(25, "day-25.txt", One, 14897079);
+    check(25, "day-25.txt", Two, Value::Empty);
+}
@@ -0,0 +1,136 @@
+//! Downloading puzzle inputs from a local stand-in for the puzzle server.
+
+mod common;
+
+use common::SESSION;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+const INPUT: &str = "1721\n979\n366\n299\n675\n1456\n";
+
+/// Serves the input of day 1 to requests carrying the session cookie.
+fn serve() -> (String, Arc<AtomicUsize>) {
+    common::serve(
+        |request| match (request.line.as_str(), request.authorized) {
+            (_, false) => (
+                "400 Bad Request",
+                "Puzzle inputs differ by user.  Please log in.".to_owned(),
+            ),
+            ("GET /2020/day/1/input HTTP/1.1", true) => ("200 OK", INPUT.to_owned()),
+            _ => ("404 Not Found", "404 Not Found".to_owned()),
+        },
+    )
+}
+
+fn fetch(config: &Path, day: &str) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["fetch", "--day", day])
+        .env("AOC_CONFIG", config)
+        .env_remove("AOC_CONTACT")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn inputs_are_fetched_once() {
+    let directory = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    let (url, requests) = serve();
+    let config = directory.join("aoc.conf");
+    let cache = directory.join("cache");
+    fs::write(
+        &config,
+        format!(
+            "# test account\nsession = {}\nbase_url = {}/\ncache_dir = {}\naccount = test\n",
+            SESSION,
+            url,
+            cache.display()
+        ),
+    )
+    .unwrap();
+
+    let first = fetch(&config, "1");
+    let second = fetch(&config, "1");
+    let missing = fetch(&config, "2");
+    let input = fs::read_to_string(cache.join("test/day-01.txt"));
+    let unavailable = cache.join("test/day-02.txt").exists();
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert!(first.status.success(), "{:?}", first);
+    assert!(String::from_utf8_lossy(&first.stdout).starts_with("Day 01: fetched "));
+    assert!(second.status.success(), "{:?}", second);
+    assert!(String::from_utf8_lossy(&second.stdout).starts_with("Day 01: cached "));
+    assert_eq!(input.unwrap(), INPUT);
+    assert!(!missing.status.success());
+    assert!(String::from_utf8_lossy(&missing.stderr).contains("status 404"));
+    assert!(!unavailable);
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn session_token_is_required() {
+    let directory = std::env::temp_dir().join(format!("aoc-fetch-session-{}", std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    let config = directory.join("aoc.conf");
+    fs::write(&config, "base_url = http://127.0.0.1:9\n").unwrap();
+    let output = fetch(&config, "1");
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no 'session' token configured"));
+}
+
+/// User agent sent when fetching with the given settings and `$AOC_CONTACT`.
+fn user_agent(name: &str, settings: &str, contact_variable: Option<&str>) -> String {
+    let directory = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    // serves the user agent as input, so that it ends up in the cache
+    let (url, _) = common::serve(|request| ("200 OK", request.user_agent.clone().unwrap()));
+    let config = directory.join("aoc.conf");
+    fs::write(
+        &config,
+        format!(
+            "session = {}\nbase_url = {}\ncache_dir = {}\naccount = test\n{}",
+            SESSION,
+            url,
+            directory.display(),
+            settings
+        ),
+    )
+    .unwrap();
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    command
+        .args(["fetch", "--day", "1"])
+        .env("AOC_CONFIG", &config)
+        .env_remove("AOC_CONTACT");
+    if let Some(contact) = contact_variable {
+        command.env("AOC_CONTACT", contact);
+    }
+    let output = command.output().unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let user_agent = fs::read_to_string(directory.join("test/day-01.txt")).unwrap();
+    fs::remove_dir_all(&directory).unwrap();
+    user_agent
+}
+
+#[test]
+fn contact_is_sent_only_if_given() {
+    let configured = user_agent("contact", "contact = someone@example.com\n", None);
+    assert!(
+        configured.contains(" by someone@example.com ("),
+        "{}",
+        configured
+    );
+    let variable = user_agent("contact-variable", "", Some("other@example.com"));
+    assert!(variable.contains(" by other@example.com ("), "{}", variable);
+    let anonymous = user_agent("contact-none", "", None);
+    assert!(!anonymous.contains(" by "), "{}", anonymous);
+    assert!(
+        anonymous.contains(env!("CARGO_PKG_VERSION")),
+        "{}",
+        anonymous
+    );
+}
@@ -0,0 +1,91 @@
+//! Generated inputs have to pass the input checks and yield the answers known by construction.
+
+use adventofcode_2020::days;
+use adventofcode_2020::generate;
+use adventofcode_2020::Part;
+
+const SEEDS: [u64; 4] = [0, 1, 2020, 0xdead_beef];
+
+fn verify(day: u32, seed: u64, size: usize) {
+    let generated = generate::generate(day, seed, Some(size)).unwrap();
+    let input = &generated.input;
+    let solution = days::solution(day).unwrap();
+    let context = format!("day {} seed {} size {}", day, seed, size);
+    let errors = solution.check(input);
+    assert!(errors.is_empty(), "{}: {:?}\n{}", context, errors, input);
+    let parsed = solution.parse(input).unwrap();
+    for &part in Part::ALL.iter() {
+        if let Some(expected) = generated.answer(part) {
+            let answer = solution.solve(part, parsed.as_ref());
+            assert_eq!(answer.value(), expected, "{} part {}", context, part);
+        }
+    }
+}
+
+fn verify_sizes(day: u32, sizes: &[usize]) {
+    for &seed in SEEDS.iter() {
+        for &size in sizes {
+            verify(day, seed, size);
+        }
+    }
+}
+
+#[test]
+fn generated_inputs_match_known_answers() {
+    let sizes: [&[usize]; 25] = [
+        &[5, 200],
+        &[1, 1000],
+        &[1, 2, 323],
+        &[1, 250],
+        &[2, 800],
+        &[1, 500],
+        &[2, 600],
+        &[4, 600],
+        &[100, 1000],
+        &[1, 100],
+        &[1, 20],
+        &[1, 780],
+        &[1, 9],
+        &[1, 100],
+        &[1, 6],
+        &[1, 240],
+        &[1, 3],
+        &[1, 370],
+        &[1, 400],
+        &[3, 4],
+        &[4, 40],
+        &[1, 25],
+        &[5, 9],
+        &[1, 400],
+        &[1, 10_000],
+    ];
+    for (day, sizes) in (1..).zip(sizes.iter()) {
+        verify_sizes(day, sizes);
+    }
+}
+
+#[test]
+fn same_seed_gives_same_input() {
+    for day in 1..=days::NUMBER_OF_DAYS {
+        let sizes = generate::generator(day).unwrap().sizes;
+        let size = 5.clamp(*sizes.start(), *sizes.end());
+        assert_eq!(
+            generate::generate(day, 7, Some(size)),
+            generate::generate(day, 7, Some(size)),
+            "day {}",
+            day
+        );
+    }
+}
+
+#[test]
+fn invalid_day_and_size_are_rejected() {
+    assert_eq!(
+        generate::generate(26, 0, None).unwrap_err(),
+        "invalid day 26, expected 1 to 25"
+    );
+    assert_eq!(
+        generate::generate(23, 0, Some(10)).unwrap_err(),
+        "invalid size 10 for day 23, expected 5 to 9 cups"
+    );
+}
@@ -0,0 +1,74 @@
+//! Indexing, neighborhoods, transformations and text round trips of the shared grid.
+
+use adventofcode_2020::grid::Grid;
+
+const TEXT: &str = "ab\ncd\nef\n";
+
+fn grid() -> Grid<char> {
+    Grid::parse(TEXT, Some).unwrap()
+}
+
+#[test]
+fn parse_and_render() {
+    let grid = grid();
+    assert_eq!((grid.width(), grid.height()), (2, 3));
+    assert_eq!(grid[(1, 2)], 'f');
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.to_string(), TEXT);
+    assert_eq!(grid.render(|c| c.to_ascii_uppercase()), "AB\nCD\nEF\n");
+}
+
+#[test]
+fn parse_errors() {
+    let digits = |c: char| c.to_digit(10);
+    let error = Grid::parse("12\n3x\n", digits).unwrap_err();
+    assert_eq!((error.line(), error.column()), (2, 2));
+    assert_eq!(error.message(), "invalid character 'x'");
+    let error = Grid::parse("12\n345\n", digits).unwrap_err();
+    assert_eq!(error.message(), "row has width 3, expected 2");
+    let error = Grid::parse("", digits).unwrap_err();
+    assert_eq!(error.message(), "empty grid");
+}
+
+#[test]
+fn wrapping() {
+    let grid = grid();
+    assert_eq!(*grid.get_wrapping((3, 4)), 'd');
+}
+
+#[test]
+fn neighbors() {
+    let grid = Grid::from_fn(3, 3, |position| position);
+    assert_eq!(
+        grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+        [(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbors8((0, 0)).count(), 3);
+    assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+    assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    assert_eq!(
+        grid.ray((0, 0), (1, 1)).collect::<Vec<_>>(),
+        [(1, 1), (2, 2)]
+    );
+    assert_eq!(grid.ray((2, 1), (1, 0)).count(), 0);
+}
+
+#[test]
+fn transformations() {
+    let grid = grid();
+    assert_eq!(grid.rotated_left().to_string(), "bdf\nace\n");
+    assert_eq!(grid.rotated_right().to_string(), "eca\nfdb\n");
+    assert_eq!(grid.rotated_left().rotated_right(), grid);
+    assert_eq!(grid.flipped_horizontally().to_string(), "ba\ndc\nfe\n");
+    assert_eq!(grid.flipped_vertically().to_string(), "ef\ncd\nab\n");
+    assert_eq!(grid.section((1, 1), 1, 2).to_string(), "d\nf\n");
+    assert_eq!(grid.column(1).rev().collect::<String>(), "fdb");
+}
+
+#[test]
+fn mutation() {
+    let mut grid = Grid::filled(2, 2, 0);
+    grid[(1, 0)] = 5;
+    assert_eq!(grid.map(|&n| n * 2).iter().sum::<i32>(), 10);
+    assert_eq!(grid.row(0), [0, 5]);
+}
@@ -0,0 +1,89 @@
+//! Selection of the puzzle input by path argument, stdin and environment variable.
+
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/day-15.txt");
+const EXPECTED: &str = "Part 1: Round 2020: 436\n";
+
+fn run(command: &mut Command, stdin: Option<&str>) -> Output {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut pipe = child.stdin.take().unwrap();
+    pipe.write_all(stdin.unwrap_or_default().as_bytes())
+        .unwrap();
+    drop(pipe);
+    child.wait_with_output().unwrap()
+}
+
+fn aoc() -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    command.env_remove("AOC_INPUT_DIR");
+    command
+}
+
+fn stdout(output: &Output) -> &str {
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn input_from_path() {
+    let output = run(
+        aoc().args(["run", "--day", "15", "--part", "1", "--input", FIXTURE]),
+        None,
+    );
+    assert_eq!(stdout(&output), EXPECTED);
+}
+
+#[test]
+fn input_from_stdin() {
+    let output = run(
+        aoc().args(["run", "--day", "15", "--part", "1", "--input", "-"]),
+        Some("0,3,6\n"),
+    );
+    assert_eq!(stdout(&output), EXPECTED);
+}
+
+#[test]
+fn input_from_environment() {
+    let directory = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    fs::copy(FIXTURE, directory.join("day-15.txt")).unwrap();
+    let output = run(
+        aoc()
+            .args(["run", "--day", "15", "--part", "1"])
+            .env("AOC_INPUT_DIR", &directory),
+        None,
+    );
+    fs::remove_dir_all(&directory).unwrap();
+    assert_eq!(stdout(&output), EXPECTED);
+}
+
+#[test]
+fn day_binary_reads_stdin() {
+    let output = run(
+        Command::new(env!("CARGO_BIN_EXE_day-25")).arg("-"),
+        Some("5764801\n17807724\n"),
+    );
+    assert!(stdout(&output).starts_with("Part 1: Encryption key 14897079\n"));
+}
+
+#[test]
+fn missing_input_fails() {
+    let output = run(
+        aoc().args(["run", "--day", "15", "--input", "does-not-exist.txt"]),
+        None,
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("Error: does-not-exist.txt: "));
+}
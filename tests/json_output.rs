@@ -0,0 +1,82 @@
+//! Machine-readable answers printed by `aoc run --format json`.
+
+use serde_json::{json, Value};
+use std::process::Command;
+
+const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/day-01.txt");
+
+#[test]
+fn one_object_per_part() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "1", "--input", FIXTURE, "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let objects: Vec<Value> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(objects.len(), 2);
+    assert_eq!(objects[0]["day"], 1);
+    assert_eq!(objects[0]["part"], 1);
+    assert_eq!(objects[0]["answer"], 514579);
+    assert_eq!(
+        objects[0]["details"],
+        json!({"entry1": 1721, "entry2": 299, "product": "514579"})
+    );
+    assert_eq!(objects[1]["answer"], 241861950);
+    assert_eq!(
+        objects[1]["details"],
+        json!({"entry1": 979, "entry2": 366, "entry3": 675, "product": "241861950"})
+    );
+    assert!(objects.iter().all(|object| object["status"] == "ok"));
+    assert!(objects.iter().all(|object| object["time_ns"].is_u64()));
+}
+
+#[test]
+fn products_are_exact_decimal_text() {
+    let refunds = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/day-01-refunds.txt"
+    );
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "1", "--input", refunds, "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let objects: Vec<Value> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(objects[0]["details"]["product"], "-1260000");
+    assert_eq!(
+        objects[1]["details"]["product"],
+        "-163620000000000000000000000000000000000000"
+    );
+}
+
+#[test]
+fn text_is_the_default() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "1", "--part", "1", "--input", FIXTURE])
+        .output()
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Part 1: 1721 * 299 = 514579\n"
+    );
+}
+
+#[test]
+fn unknown_format_is_rejected() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "1", "--input", FIXTURE, "--format", "xml"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with(
+        "Error: invalid value 'xml' for option '--format': expected 'text' or 'json'"
+    ));
+}
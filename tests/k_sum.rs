@@ -0,0 +1,118 @@
+//! Combinations of day 1 entries summing to a target, compared against trying all of them.
+
+use adventofcode_2020::days::day01::{find_k_sum, first_k_sum};
+
+/// Every combination of `k` indices in ascending order, in lexicographic order.
+fn brute_force(numbers: &[i64], k: usize, target: i64) -> Vec<Vec<usize>> {
+    fn extend(
+        numbers: &[i64],
+        k: usize,
+        target: i64,
+        combination: &mut Vec<usize>,
+        found: &mut Vec<Vec<usize>>,
+    ) {
+        if combination.len() == k {
+            let sum: i128 = combination.iter().map(|&i| i128::from(numbers[i])).sum();
+            if sum == i128::from(target) {
+                found.push(combination.clone());
+            }
+            return;
+        }
+        let start = combination.last().map_or(0, |&last| last + 1);
+        for i in start..numbers.len() {
+            combination.push(i);
+            extend(numbers, k, target, combination, found);
+            combination.pop();
+        }
+    }
+    let mut found = vec![];
+    extend(numbers, k, target, &mut vec![], &mut found);
+    found
+}
+
+const EXAMPLE: [i64; 6] = [1721, 979, 366, 299, 675, 1456];
+
+#[test]
+fn example_combinations_are_found() {
+    assert_eq!(find_k_sum(&EXAMPLE, 2, 2020), vec![vec![0, 3]]);
+    assert_eq!(find_k_sum(&EXAMPLE, 3, 2020), vec![vec![1, 2, 4]]);
+    assert_eq!(find_k_sum(&EXAMPLE, 1, 366), vec![vec![2]]);
+    assert_eq!(find_k_sum(&EXAMPLE, 0, 0), vec![Vec::<usize>::new()]);
+    assert!(find_k_sum(&EXAMPLE, 0, 2020).is_empty());
+    assert!(find_k_sum(&EXAMPLE, 7, 2020).is_empty());
+}
+
+#[test]
+fn first_combination_is_found_without_enumerating_all() {
+    assert_eq!(first_k_sum(&EXAMPLE, 2, 2020), Some(vec![0, 3]));
+    assert_eq!(first_k_sum(&EXAMPLE, 3, 2020), Some(vec![1, 2, 4]));
+    assert_eq!(first_k_sum(&EXAMPLE, 7, 2020), None);
+    // a thousand equal entries combine to more triples than could be enumerated in time
+    let numbers = vec![1; 1000];
+    assert_eq!(first_k_sum(&numbers, 3, 3), Some(vec![0, 1, 2]));
+}
+
+#[test]
+fn repeated_entries_are_distinct_combinations() {
+    let numbers = [5, 5, 5, 5, 10];
+    assert_eq!(find_k_sum(&numbers, 2, 10).len(), 6);
+    assert_eq!(find_k_sum(&numbers, 4, 20), vec![vec![0, 1, 2, 3]]);
+    assert_eq!(find_k_sum(&numbers, 3, 20).len(), 6);
+}
+
+#[test]
+fn all_combinations_match_brute_force() {
+    // small pseudo-random entries, so that many combinations share sums
+    let mut state = 12345u32;
+    let mut next = || {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        i64::from((state >> 16) % 40)
+    };
+    let positive: Vec<i64> = (0..24).map(|_| next()).collect();
+    let refunds: Vec<i64> = (0..20).map(|_| next() - 15).collect();
+    for (numbers, targets) in [
+        (positive, [0, 37, 60, 100, 150]),
+        (refunds, [-40, -3, 0, 12, 50]),
+    ]
+    .iter()
+    {
+        for k in 0..=6 {
+            for &target in targets.iter() {
+                let all = brute_force(numbers, k, target);
+                assert_eq!(
+                    find_k_sum(numbers, k, target),
+                    all,
+                    "k = {}, target = {}",
+                    k,
+                    target
+                );
+                match first_k_sum(numbers, k, target) {
+                    Some(first) => assert!(all.contains(&first), "{:?}", first),
+                    None => assert!(all.is_empty(), "k = {}, target = {}", k, target),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn large_sums_do_not_overflow() {
+    let numbers = [i64::MAX, i64::MAX, i64::MIN, 0, -1];
+    assert_eq!(
+        find_k_sum(&numbers, 2, -1),
+        vec![vec![0, 2], vec![1, 2], vec![3, 4]]
+    );
+    assert_eq!(
+        find_k_sum(&numbers, 2, i64::MAX),
+        vec![vec![0, 3], vec![1, 3]]
+    );
+    assert_eq!(
+        find_k_sum(&numbers, 3, i64::MAX - 1),
+        vec![vec![0, 1, 2], vec![0, 3, 4], vec![1, 3, 4]]
+    );
+    assert_eq!(
+        find_k_sum(&numbers, 4, -2),
+        vec![vec![0, 2, 3, 4], vec![1, 2, 3, 4]]
+    );
+    assert!(find_k_sum(&numbers, 5, -2).is_empty());
+}
@@ -0,0 +1,102 @@
+//! Changing puzzle parameters from the configuration file and the command line.
+
+use adventofcode_2020::days;
+use adventofcode_2020::parameters::Parameters;
+use std::fs;
+use std::process::{Command, Output};
+
+fn run(name: &str, config: &str, args: &[&str]) -> Output {
+    let directory = std::env::temp_dir().join(format!("aoc-parameters-{}", std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    let path = directory.join(format!("{}.conf", name));
+    fs::write(&path, config).unwrap();
+    let input = format!(
+        "{}/tests/fixtures/day-09-preamble-5.txt",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "9", "--part", "1", "--input", &input])
+        .arg("--config")
+        .arg(&path)
+        .args(args)
+        .output()
+        .unwrap();
+    fs::remove_file(&path).unwrap();
+    output
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    assert!(!output.status.success(), "{:?}", output);
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn parameters_are_taken_from_configuration_sections() {
+    let output = run("sections", "session = abc\n\n[day09]\npreamble = 5\n", &[]);
+    assert_eq!(
+        stdout(&output),
+        "Part 1: Invalid number: 127 not sum of previous 5 numbers.\n"
+    );
+}
+
+#[test]
+fn command_line_overrides_configuration() {
+    let output = run(
+        "override",
+        "[day09]\npreamble = 25\n",
+        &["--param", "preamble=5"],
+    );
+    assert!(stdout(&output).contains(": 127 "));
+    let output = run(
+        "unpadded",
+        "[day9]\npreamble = 5\n",
+        &["--param", "preamble=4"],
+    );
+    assert!(stdout(&output).contains(": 47 "));
+}
+
+#[test]
+fn invalid_parameters_are_rejected() {
+    let output = run("unknown", "", &["--param", "length=5"]);
+    assert_eq!(
+        stderr(&output),
+        "Error: unknown parameter 'length' of day 9, expected one of preamble\n"
+    );
+    let output = run("invalid", "", &["--param", "preamble=-1"]);
+    assert_eq!(
+        stderr(&output),
+        "Error: invalid value '-1' for parameter 'preamble' of day 9: invalid digit found in string\n"
+    );
+    let output = run("malformed", "[day09\n", &[]);
+    assert!(stderr(&output).contains("expected '[section]'"));
+}
+
+#[test]
+fn values_outside_their_range_are_rejected() {
+    let mut parameters = Parameters::new();
+    parameters.set(23, "part2_cups", "3");
+    assert_eq!(
+        days::lookup(23, &parameters).err(),
+        Some(
+            "invalid value '3' for parameter 'part2_cups' of day 23: expected at least 5 cups"
+                .to_owned()
+        )
+    );
+    for bound in ["0,2", "-3,1"].iter() {
+        let mut parameters = Parameters::new();
+        parameters.set(3, "part2_search", bound);
+        assert_eq!(
+            days::lookup(3, &parameters).err(),
+            Some(format!(
+                "invalid value '{0}' for parameter 'part2_search' of day 3: \
+                 invalid bound '{0}', expected to go right at least 1",
+                bound
+            ))
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! Registry of confirmed answers and the regressions reported by `aoc verify`.
+
+use adventofcode_2020::registry::{Key, Registry, Status};
+use adventofcode_2020::Part::{self, One, Two};
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/day-01.txt");
+
+fn directory(name: &str) -> PathBuf {
+    let directory =
+        std::env::temp_dir().join(format!("aoc-registry-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+fn fixture_key(part: Part) -> Key {
+    Key::new(1, part, &fs::read_to_string(FIXTURE).unwrap())
+}
+
+fn verify(input: &str, answers: &PathBuf) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["verify", "--day", "1", "--input", input, "--answers"])
+        .arg(answers)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn parse_skips_comments_and_blank_lines() {
+    let registry = Registry::parse(
+        "# comment\n\n1\t1\t0123456789abcdef\t514579\n1\t2\t0123456789ABCDEF\t241861950\n",
+    )
+    .unwrap();
+    let key = |part| Key {
+        day: 1,
+        part,
+        input: "0123456789abcdef".to_owned(),
+    };
+    assert_eq!(registry.get(&key(One)), Some("514579"));
+    assert_eq!(registry.get(&key(Two)), Some("241861950"));
+    assert_eq!(registry.check(&key(One), "514579"), Status::Match);
+    assert_eq!(
+        registry.check(&key(Two), "1"),
+        Status::Mismatch("241861950".to_owned())
+    );
+}
+
+#[test]
+fn parse_rejects_malformed_lines() {
+    let cases = [
+        ("1\t1\t514579\n", "found 3 tab-separated fields, expected 4"),
+        (
+            "x\t1\t0123456789abcdef\t514579\n",
+            "invalid day 'x': invalid digit found in string",
+        ),
+        (
+            "1\t1\tinputs/day-01.txt\t514579\n",
+            "invalid input fingerprint 'inputs/day-01.txt', expected 16 hexadecimal digits",
+        ),
+    ];
+    for (content, message) in cases {
+        let err = Registry::parse(content).unwrap_err();
+        assert_eq!((err.line(), err.message()), (1, message), "{:?}", content);
+    }
+}
+
+#[test]
+fn parse_rejects_duplicate_keys() {
+    let err = Registry::parse(
+        "1\t1\t0123456789abcdef\t514579\n1\t2\t0123456789abcdef\t241861950\n\
+         1\t1\t0123456789abcdef\t1\n",
+    )
+    .unwrap_err();
+    assert_eq!((err.line(), err.message()), (3, "duplicate answer"));
+}
+
+#[test]
+fn recorded_answers_survive_saving_and_loading() {
+    let directory = directory("round-trip");
+    let path = directory.join("answers.txt");
+    let path = path.to_str().unwrap();
+    let mut registry = Registry::load(path).unwrap();
+    assert_eq!(registry.check(&fixture_key(One), "514579"), Status::Missing);
+    registry.record(fixture_key(One), "514579");
+    registry.record(fixture_key(Two), "241861950");
+    registry.save(path).unwrap();
+
+    let loaded = Registry::load(path).unwrap();
+    assert_eq!(loaded.to_string(), registry.to_string());
+    assert_eq!(loaded.check(&fixture_key(One), "514579"), Status::Match);
+    assert_eq!(loaded.check(&fixture_key(Two), "241861950"), Status::Match);
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn keys_depend_on_the_content_only() {
+    assert_eq!(Key::new(1, One, "1721\n"), Key::new(1, One, "1721\n"));
+    assert_ne!(Key::new(1, One, "1721\n"), Key::new(1, One, "979\n"));
+    assert_ne!(Key::new(1, One, "1721\n"), Key::new(1, Two, "1721\n"));
+}
+
+#[test]
+fn verify_reports_regressions() {
+    let directory = directory("regression");
+    let answers = directory.join("answers.txt");
+    let mut registry = Registry::default();
+    registry.record(fixture_key(One), "1");
+    registry.record(fixture_key(Two), "241861950");
+    registry.save(answers.to_str().unwrap()).unwrap();
+
+    let output = verify(FIXTURE, &answers);
+    assert!(!output.status.success());
+    let stdout = stdout(&output);
+    assert!(
+        stdout.contains("01      1  MISMATCH  514579 (expected 1)"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("01      2  match     241861950"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("1 matching, 1 regression(s), 0 missing, 0 recorded"),
+        "{}",
+        stdout
+    );
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn verify_reports_new_answers_without_failing() {
+    let directory = directory("missing");
+    let answers = directory.join("answers.txt");
+
+    let output = verify(FIXTURE, &answers);
+    assert!(output.status.success());
+    let stdout = stdout(&output);
+    assert!(stdout.contains("01      1  missing   514579"), "{}", stdout);
+    assert!(
+        stdout.contains("0 matching, 0 regression(s), 2 missing, 0 recorded"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("Run with '--record'"), "{}", stdout);
+    assert!(!answers.exists());
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn verify_finds_the_answers_of_moved_inputs() {
+    let directory = directory("moved");
+    let answers = directory.join("answers.txt");
+    let mut registry = Registry::default();
+    registry.record(fixture_key(One), "514579");
+    registry.record(fixture_key(Two), "241861950");
+    registry.save(answers.to_str().unwrap()).unwrap();
+    let input = directory.join("day-01.txt");
+    fs::copy(FIXTURE, &input).unwrap();
+
+    let output = verify(input.to_str().unwrap(), &answers);
+    assert!(output.status.success(), "{}", stdout(&output));
+    assert!(
+        stdout(&output).contains("2 matching, 0 regression(s), 0 missing, 0 recorded"),
+        "{}",
+        stdout(&output)
+    );
+    fs::remove_dir_all(&directory).unwrap();
+}
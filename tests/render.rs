@@ -0,0 +1,153 @@
+//! Pictures of simulation frames and the images exported from them.
+
+use adventofcode_2020::animation::Frame;
+use adventofcode_2020::render::{self, Palette, Picture};
+use std::fs::{self, File};
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+fn frame(rows: &[&str]) -> Frame {
+    Frame {
+        generation: 0,
+        live: 0,
+        rows: rows.iter().map(|&row| row.to_owned()).collect(),
+        hexagonal: false,
+    }
+}
+
+#[test]
+fn palette_overrides_default_colors() {
+    let palette: Palette = "#=ffffff,x=#102030,background=000010".parse().unwrap();
+    let picture = Picture::draw(&frame(&["#x.?"]), &palette, 1, (4, 1));
+    assert_eq!(picture.color(&palette, 0, 0), [255, 255, 255]);
+    assert_eq!(picture.color(&palette, 1, 0), [16, 32, 48]);
+    assert_eq!(picture.color(&palette, 2, 0), [16, 24, 48]);
+    assert_eq!(picture.color(&palette, 3, 0), [0, 0, 16]);
+}
+
+#[test]
+fn invalid_palettes_are_rejected() {
+    for palette in ["#=fff", "#", "ab=ffffff", "#=gggggg"].iter() {
+        assert!(palette.parse::<Palette>().is_err(), "{}", palette);
+    }
+}
+
+#[test]
+fn cells_are_scaled_and_padded() {
+    let palette = Palette::default();
+    let picture = Picture::draw(&frame(&["#.", "O"]), &palette, 3, (3, 2));
+    assert_eq!((picture.width(), picture.height()), (9, 6));
+    assert_eq!(picture.color(&palette, 2, 2), [240, 200, 64]);
+    assert_eq!(picture.color(&palette, 3, 0), [16, 24, 48]);
+    assert_eq!(picture.color(&palette, 0, 5), [224, 48, 48]);
+    // missing cells and the padding are left to the background
+    assert_eq!(picture.color(&palette, 4, 4), [0, 0, 0]);
+    assert_eq!(picture.color(&palette, 8, 0), [0, 0, 0]);
+}
+
+#[test]
+fn blank_cells_join_shifted_columns_of_hexagonal_frames() {
+    let palette = Palette::default();
+    let hexagonal = Frame {
+        hexagonal: true,
+        ..frame(&["# .", " # "])
+    };
+    let picture = Picture::draw(&hexagonal, &palette, 2, (3, 2));
+    let row = |y| -> Vec<_> { (0..6).map(|x| picture.color(&palette, x, y)).collect() };
+    let (black, white, background) = ([240, 200, 64], [16, 24, 48], [0, 0, 0]);
+    assert_eq!(row(0), [black, black, black, white, white, white]);
+    assert_eq!(row(2), [background, black, black, black, black, background]);
+}
+
+#[test]
+fn blank_cells_of_other_frames_have_their_own_color() {
+    let mut palette = Palette::default();
+    let picture = Picture::draw(&frame(&["# ."]), &palette, 2, (3, 1));
+    assert_eq!(picture.color(&palette, 2, 0), [0, 0, 0]);
+    assert_eq!(picture.color(&palette, 3, 0), [0, 0, 0]);
+
+    palette.set(' ', [255, 0, 255]);
+    let picture = Picture::draw(&frame(&["# ."]), &palette, 2, (3, 1));
+    assert_eq!(picture.color(&palette, 2, 0), [255, 0, 255]);
+    assert_eq!(picture.color(&palette, 3, 0), [255, 0, 255]);
+}
+
+#[test]
+fn size_fits_all_frames() {
+    let frames = [frame(&["##"]), frame(&["#", "###", "#"])];
+    assert_eq!(render::size(&frames), (3, 3));
+    assert_eq!(render::size(&[frame(&[])]), (1, 1));
+}
+
+#[test]
+fn pictures_are_written_as_png_and_gif() {
+    let directory = std::env::temp_dir().join(format!("aoc-render-{}", std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    let palette = Palette::default();
+    let pictures = [
+        Picture::draw(&frame(&["#."]), &palette, 2, (2, 1)),
+        Picture::draw(&frame(&[".#"]), &palette, 2, (2, 1)),
+    ];
+
+    let png_path = directory.join("frame.png");
+    pictures[0].write_png(&palette, &png_path).unwrap();
+    let mut reader = png::Decoder::new(File::open(&png_path).unwrap())
+        .read_info()
+        .unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+    assert_eq!((info.width, info.height), (4, 2));
+
+    let gif_path = directory.join("frames.gif");
+    render::write_gif(&pictures, &palette, Duration::from_millis(100), &gif_path).unwrap();
+    let mut decoder = gif::DecodeOptions::new()
+        .read_info(File::open(&gif_path).unwrap())
+        .unwrap();
+    let mut delays = vec![];
+    while let Some(frame) = decoder.read_next_frame().unwrap() {
+        delays.push(frame.delay);
+    }
+    fs::remove_dir_all(&directory).unwrap();
+    assert_eq!(delays, vec![10, 10]);
+}
+
+fn exported(directory: &Path, args: &[&str]) -> Vec<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("export")
+        .args(args)
+        .arg("--output")
+        .arg(directory)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let mut names: Vec<_> = fs::read_dir(directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn export_writes_every_generation() {
+    let directory = std::env::temp_dir().join(format!("aoc-export-{}", std::process::id()));
+    let fixture = |name| format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+    let day11 = exported(
+        &directory,
+        &["--day", "11", "--input", &fixture("day-11.txt")],
+    );
+    fs::remove_dir_all(&directory).unwrap();
+    let day20 = exported(
+        &directory,
+        &["--day", "20", "--input", &fixture("day-20.txt")],
+    );
+    fs::remove_dir_all(&directory).unwrap();
+
+    let mut expected: Vec<_> = (0..=5)
+        .map(|generation| format!("day-11-part-1-{:04}.png", generation))
+        .collect();
+    expected.push("day-11-part-1.gif".to_owned());
+    assert_eq!(day11, expected);
+    assert_eq!(day20, vec!["day-20-part-2-0000.png"]);
+}
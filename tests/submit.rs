@@ -0,0 +1,224 @@
+//! Submitting answers to a local stand-in for the puzzle server.
+
+mod common;
+
+use adventofcode_2020::submission::{self, Submission, SubmissionLog, Verdict};
+use adventofcode_2020::Part;
+use common::SESSION;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::atomic::Ordering;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+const CORRECT: &str = "<article><p>That's the right answer!  You are one gold star closer \
+to saving your vacation.</p></article>";
+const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  \
+If you're stuck, make sure you're using the full input data.  Please wait one minute before \
+trying again.</p></article>";
+const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait after \
+submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>";
+
+/// Temporary directory holding the configuration of a test account and its cache.
+fn account(name: &str, url: &str) -> PathBuf {
+    let directory =
+        std::env::temp_dir().join(format!("aoc-submit-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    fs::write(
+        directory.join("aoc.conf"),
+        format!(
+            "session = {}\nbase_url = {}\ncache_dir = {}\naccount = test\n",
+            SESSION,
+            url,
+            directory.join("cache").display()
+        ),
+    )
+    .unwrap();
+    directory
+}
+
+fn submit(directory: &Path, part: &str) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["submit", "--day", "1", "--part", part, "--input"])
+        .arg(format!("{}/day-01.txt", FIXTURES))
+        .env("AOC_CONFIG", directory.join("aoc.conf"))
+        .output()
+        .unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn responses_are_parsed() {
+    assert_eq!(
+        submission::parse_response(CORRECT),
+        Some((Verdict::Correct, None))
+    );
+    assert_eq!(
+        submission::parse_response(TOO_HIGH),
+        Some((Verdict::TooHigh, Some(60)))
+    );
+    assert_eq!(
+        submission::parse_response(TOO_RECENT),
+        Some((Verdict::RateLimited, Some(65)))
+    );
+    assert_eq!(
+        submission::parse_response(
+            "That's not the right answer.  Please wait 5 minutes before trying again."
+        ),
+        Some((Verdict::Incorrect, Some(300)))
+    );
+    assert_eq!(
+        submission::parse_response("You don't seem to be solving the right level."),
+        Some((Verdict::WrongLevel, None))
+    );
+    assert_eq!(submission::parse_response("<html></html>"), None);
+}
+
+#[test]
+fn pointless_submissions_are_refused() {
+    let mut log = SubmissionLog::default();
+    let submission = |part, verdict, answer: &str| Submission {
+        time: 1000,
+        day: 1,
+        part,
+        verdict,
+        wait: None,
+        answer: answer.to_owned(),
+    };
+    log.record(submission(Part::One, Verdict::TooHigh, "500"));
+    log.record(submission(Part::One, Verdict::TooLow, "100"));
+    log.record(submission(Part::Two, Verdict::Correct, "42"));
+
+    assert!(log.check(1, Part::One, "300", 1000).is_ok());
+    assert!(log.check(1, Part::One, "500", 1000).is_err());
+    assert!(log.check(1, Part::One, "600", 1000).is_err());
+    assert!(log.check(1, Part::One, "99", 1000).is_err());
+    assert!(log.check(1, Part::Two, "42", 1000).is_err());
+    assert!(log.check(1, Part::Two, "43", 1000).is_err());
+    assert!(log.check(2, Part::One, "500", 1000).is_ok());
+
+    log.record(Submission {
+        wait: Some(60),
+        ..submission(Part::One, Verdict::Incorrect, "200")
+    });
+    assert!(log.check(2, Part::One, "1", 1059).is_err());
+    assert!(log.check(2, Part::One, "1", 1060).is_ok());
+    assert_eq!(
+        SubmissionLog::parse(&log.to_string())
+            .unwrap()
+            .submissions(),
+        log.submissions()
+    );
+}
+
+#[test]
+fn wrong_answers_are_submitted_once() {
+    let (url, requests) = common::serve(|request| {
+        assert!(request.authorized);
+        assert_eq!(request.line, "POST /2020/day/1/answer HTTP/1.1");
+        match request.body.as_str() {
+            "level=1&answer=514579" => ("200 OK", TOO_HIGH.to_owned()),
+            _ => ("400 Bad Request", String::new()),
+        }
+    });
+    let directory = account("wrong", &url);
+
+    let first = submit(&directory, "1");
+    let second = submit(&directory, "1");
+    let log = fs::read_to_string(directory.join("cache/test/submissions.txt")).unwrap();
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert!(!first.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&first.stdout),
+        "Day 01 part 1: 514579 is too-high, 60s to wait before the next submission\n"
+    );
+    assert!(!second.status.success());
+    assert!(
+        stderr(&second).contains("not submitting 514579: "),
+        "{}",
+        stderr(&second)
+    );
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+    let entry: Vec<_> = log.lines().nth(1).unwrap().split('\t').collect();
+    assert_eq!(entry[1..], ["1", "1", "too-high", "60", "514579"]);
+}
+
+#[test]
+fn correct_answers_are_submitted_once() {
+    let (url, requests) = common::serve(|request| match request.body.as_str() {
+        "level=2&answer=241861950" => ("200 OK", CORRECT.to_owned()),
+        _ => ("400 Bad Request", String::new()),
+    });
+    let directory = account("correct", &url);
+
+    let first = submit(&directory, "2");
+    let second = submit(&directory, "2");
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert!(first.status.success(), "{}", stderr(&first));
+    assert_eq!(
+        String::from_utf8_lossy(&first.stdout),
+        "Day 01 part 2: 241861950 is correct\n"
+    );
+    assert!(stderr(&second).contains("answer is already confirmed as correct"));
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn rate_limits_are_respected() {
+    let (url, requests) = common::serve(|_| ("200 OK", TOO_RECENT.to_owned()));
+    let directory = account("rate-limit", &url);
+
+    let first = submit(&directory, "1");
+    let second = submit(&directory, "2");
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert!(String::from_utf8_lossy(&first.stdout).contains("514579 is rate-limited, 65s"));
+    assert!(
+        stderr(&second).contains("rate-limited, "),
+        "{}",
+        stderr(&second)
+    );
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn the_cached_input_of_the_account_is_solved_by_default() {
+    let (url, requests) = common::serve(|request| match request.body.as_str() {
+        "level=1&answer=514579" => ("200 OK", CORRECT.to_owned()),
+        _ => ("400 Bad Request", String::new()),
+    });
+    let directory = account("cached", &url);
+    let submit = || {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["submit", "--day", "1", "--part", "1"])
+            .env("AOC_CONFIG", directory.join("aoc.conf"))
+            .env("AOC_INPUT_DIR", FIXTURES)
+            .output()
+            .unwrap()
+    };
+
+    let missing = submit();
+    fs::create_dir_all(directory.join("cache/test")).unwrap();
+    fs::copy(
+        format!("{}/day-01.txt", FIXTURES),
+        directory.join("cache/test/day-01.txt"),
+    )
+    .unwrap();
+    let cached = submit();
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert!(!missing.status.success());
+    assert!(
+        stderr(&missing).contains("no cached input of day 1 for this account"),
+        "{}",
+        stderr(&missing)
+    );
+    assert!(cached.status.success(), "{}", stderr(&cached));
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,94 @@
+//! Running all days concurrently, isolating failures and panics of single days.
+
+use adventofcode_2020::days;
+use adventofcode_2020::parameters::Parameters;
+use adventofcode_2020::summary::{self, Status};
+use adventofcode_2020::Part;
+use std::fs;
+use std::process::Command;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+/// Tiles without any matching edges, which pass parsing but cannot be assembled.
+const UNMATCHED_TILES: &str = "\
+Tile 1:\n#..\n...\n..#\n\n\
+Tile 2:\n.#.\n#..\n...\n\n\
+Tile 3:\n##.\n..#\n#..\n\n\
+Tile 4:\n...\n.##\n###\n";
+
+#[test]
+fn panics_are_isolated() {
+    let directory = std::env::temp_dir().join(format!("aoc-summary-{}", std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    let filename = directory.join("day-20.txt");
+    fs::write(&filename, UNMATCHED_TILES).unwrap();
+    let outcomes = days::solve_isolated(
+        20,
+        filename.to_str().unwrap(),
+        &Part::ALL,
+        &Parameters::new(),
+        None,
+    );
+    fs::remove_dir_all(&directory).unwrap();
+    assert_eq!(outcomes.len(), 2);
+    assert!(outcomes
+        .iter()
+        .all(|outcome| matches!(outcome.status, Status::Panicked(_))));
+}
+
+#[test]
+fn errors_are_reported_per_part() {
+    let outcomes = days::solve_isolated(
+        1,
+        "does-not-exist.txt",
+        &[Part::One],
+        &Parameters::new(),
+        None,
+    );
+    assert_eq!(outcomes[0].answer, None);
+    assert!(matches!(&outcomes[0].status, Status::Failed(message)
+        if message.starts_with("does-not-exist.txt: ")));
+}
+
+#[test]
+fn run_all_writes_summary() {
+    let directory = std::env::temp_dir().join(format!("aoc-run-all-{}", std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    fs::copy(
+        format!("{}/day-01.txt", FIXTURES),
+        directory.join("day-01.txt"),
+    )
+    .unwrap();
+    fs::write(directory.join("day-20.txt"), UNMATCHED_TILES).unwrap();
+    let csv = directory.join("summary.csv");
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--all", "--part", "1", "--jobs", "4", "--output"])
+        .arg(&csv)
+        .env("AOC_INPUT_DIR", &directory)
+        .output()
+        .unwrap();
+    let csv = fs::read_to_string(&csv).unwrap();
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("1 part(s) solved, 24 failed"), "{}", stdout);
+    let rows: Vec<_> = csv.lines().collect();
+    assert_eq!(rows.len(), 26);
+    assert_eq!(rows[0], "day,part,answer,time_ns,status,error");
+    assert!(rows[1].starts_with("1,1,514579,"));
+    assert!(rows[1].ends_with(",ok,"));
+    assert!(rows[2].starts_with("2,1,,0,failed,"));
+    assert!(rows[20].starts_with("20,1,,"));
+    assert!(rows[20].contains(",panicked,"));
+}
+
+#[test]
+fn csv_fields_are_quoted() {
+    let outcomes = days::solve_isolated(0, "unused.txt", &[Part::Two], &Parameters::new(), None);
+    let csv = summary::to_csv(&outcomes);
+    assert_eq!(
+        csv.lines().nth(1),
+        Some("0,2,,0,failed,\"invalid day 0, expected 1 to 25\"")
+    );
+}
@@ -0,0 +1,126 @@
+//! Tracing the solvers with `-v` and `-vv`, the latter reproducing the puzzle walkthroughs.
+
+use std::process::Command;
+
+fn trace(options: &[&str], day: &str, part: &str) -> String {
+    let input = format!(
+        "{}/tests/fixtures/day-{}.txt",
+        env!("CARGO_MANIFEST_DIR"),
+        day
+    );
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(options)
+        .args(["run", "--day", day, "--part", part, "--input", &input])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stderr).unwrap()
+}
+
+/// Trace without the event lines, which `-vv` interleaves with the walkthrough.
+fn walkthrough(day: &str, part: &str) -> String {
+    let is_event = |line: &str| {
+        line.strip_prefix("day")
+            .and_then(|rest| rest.get(..3))
+            .is_some_and(|source| source.ends_with(' ') && source[..2].parse::<u8>().is_ok())
+    };
+    trace(&["-vv"], day, part)
+        .lines()
+        .filter(|line| !is_event(line))
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+#[test]
+fn nothing_is_traced_by_default() {
+    assert_eq!(trace(&[], "22", "1"), "");
+}
+
+#[test]
+fn events_are_traced_one_per_line() {
+    let events = trace(&["-v"], "24", "2");
+    let lines: Vec<_> = events.lines().collect();
+    assert_eq!(lines[0], "day24 day day=1 black=15");
+    assert_eq!(lines[99], "day24 day day=100 black=2208");
+    assert!(lines[100].starts_with("day24 solved part=2 answer=2208 time="));
+
+    let seats = trace(&["-v"], "11", "1");
+    let lines: Vec<_> = seats.lines().collect();
+    assert_eq!(lines[0], "day11 round round=1 occupied=71");
+    assert_eq!(lines[4], "day11 round round=5 occupied=37");
+}
+
+#[test]
+fn walkthroughs_include_the_events() {
+    let floor = trace(&["-vv"], "24", "2");
+    assert!(floor.starts_with("day24 day day=1 black=15\nDay 1: 15\nday24 day day=2"));
+}
+
+#[test]
+fn walkthroughs_match_the_puzzle_texts() {
+    let cups = walkthrough("23", "1");
+    assert!(cups.starts_with(
+        "-- move 1 --\n\
+         cups: (3) 8  9  1  2  5  4  6  7 \n\
+         pick up: 8, 9, 1\n\
+         destination: 2\n\
+         \n\
+         -- move 2 --\n\
+         cups:  3 (2) 8  9  1  5  4  6  7 \n\
+         pick up: 8, 9, 1\n\
+         destination: 7\n"
+    ));
+
+    let seats = walkthrough("11", "1");
+    assert!(seats.starts_with(
+        "L.LL.LL.LL\n\
+         LLLLLLL.LL\n\
+         L.L.L..L..\n\
+         LLLL.LL.LL\n\
+         L.LL.LL.LL\n\
+         L.LLLLL.LL\n\
+         ..L.L.....\n\
+         LLLLLLLLLL\n\
+         L.LLLLLL.L\n\
+         L.LLLLL.LL\n\
+         \n\
+         #.##.##.##\n"
+    ));
+
+    let toboggan = walkthrough("03", "1");
+    assert!(toboggan.starts_with(
+        "Right 3, down 1.\n\
+         \n\
+         O.##.........##.........##.......\n\
+         #..O#...#..#...#...#..#...#...#..\n\
+         .#....X..#..#....#..#..#....#..#.\n"
+    ));
+
+    let combat = walkthrough("22", "2");
+    assert!(combat.contains(
+        "Player 1 plays: 4\n\
+         Player 2 plays: 3\n\
+         Playing a sub-game to determine the winner...\n\
+         \n\
+         === Game 2 ===\n\
+         \n\
+         -- Round 1 (Game 2) --\n\
+         Player 1's deck: 9, 8, 5, 2\n\
+         Player 2's deck: 10, 1, 7\n"
+    ));
+    assert!(combat.ends_with(
+        "== Post-game results ==\n\
+         Player 1's deck: \n\
+         Player 2's deck: 7, 5, 6, 2, 4, 1, 10, 8, 9, 3\n"
+    ));
+}
+
+#[test]
+fn trace_is_written_to_log_file() {
+    let path = std::env::temp_dir().join(format!("aoc-trace-{}.log", std::process::id()));
+    let stderr = trace(&["-v", "--log", path.to_str().unwrap()], "17", "1");
+    let log = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(stderr, "");
+    assert!(log.starts_with("day17 cycle cycle=1 active=11\n"));
+}